@@ -64,6 +64,8 @@ impl GrpcLockClient {
             deadlock_detection: false,
             suppress_contention_logs: false,
             refresh_interval: None,
+            epoch: 0,
+            weight: 1,
         }
     }
 
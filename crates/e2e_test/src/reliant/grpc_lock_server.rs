@@ -30,6 +30,7 @@ use std::pin::Pin;
 use std::sync::Arc;
 use tokio::net::TcpListener;
 use tokio_stream::wrappers::TcpListenerStream;
+use tonic::transport::{Certificate, Identity, ServerTlsConfig};
 use tonic::{Request, Response, Status};
 use tracing::debug;
 
@@ -872,3 +873,40 @@ pub async fn spawn_lock_server(
 
     Ok((addr_str, handle))
 }
+
+/// Like [`spawn_lock_server`] but serving TLS with `cert_pem`/`key_pem`,
+/// requiring client certificates signed by `client_ca_pem` when given.
+///
+/// The returned address names `localhost` so clients verify it against the
+/// server certificate's SAN.
+pub async fn spawn_tls_lock_server(
+    lock_client: Arc<dyn LockClient>,
+    cert_pem: &[u8],
+    key_pem: &[u8],
+    client_ca_pem: Option<&[u8]>,
+) -> std::result::Result<(String, tokio::task::JoinHandle<()>), Box<dyn std::error::Error>> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let addr = listener.local_addr()?;
+    let addr_str = format!("https://localhost:{}", addr.port());
+
+    let mut tls = ServerTlsConfig::new().identity(Identity::from_pem(cert_pem, key_pem));
+    if let Some(client_ca_pem) = client_ca_pem {
+        tls = tls.client_ca_root(Certificate::from_pem(client_ca_pem));
+    }
+
+    let service = MinimalLockNodeService::new(lock_client);
+    let server = tonic::transport::Server::builder()
+        .tls_config(tls)?
+        .add_service(rustfs_protos::proto_gen::node_service::node_service_server::NodeServiceServer::new(
+            service,
+        ))
+        .serve_with_incoming(TcpListenerStream::new(listener));
+
+    let handle = tokio::spawn(async move {
+        if let Err(e) = server.await {
+            eprintln!("gRPC TLS server error: {}", e);
+        }
+    });
+
+    Ok((addr_str, handle))
+}
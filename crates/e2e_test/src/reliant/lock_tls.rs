@@ -0,0 +1,193 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Remote lock RPCs over TLS.
+//!
+//! Each `RemoteClient` is given its own CA and client certificate through
+//! `with_tls`, so these tests leave the global outbound TLS state alone.
+
+#![cfg(test)]
+
+use super::grpc_lock_server::spawn_tls_lock_server;
+use crate::storage_api::RemoteClient;
+use crate::tls_gen::{self, Args};
+use rcgen::generate_simple_self_signed;
+use rustfs_common::MtlsIdentityPem;
+use rustfs_lock::client::{LockClient, local::LocalClient};
+use rustfs_lock::{GlobalLockManager, LockRequest, LockType, ObjectKey};
+use rustfs_tls_runtime::OutboundTlsMaterial;
+use std::fs;
+use std::sync::Arc;
+use std::time::Duration;
+
+struct TlsBundle {
+    ca_pem: Vec<u8>,
+    server_cert_pem: Vec<u8>,
+    server_key_pem: Vec<u8>,
+    client_cert_pem: Vec<u8>,
+    client_key_pem: Vec<u8>,
+}
+
+impl TlsBundle {
+    /// Client TLS that trusts the bundle's CA, presenting its client certificate when `mutual`
+    fn client_tls(&self, mutual: bool) -> OutboundTlsMaterial {
+        OutboundTlsMaterial {
+            root_ca_pem: self.ca_pem.clone(),
+            mtls_identity: mutual.then(|| MtlsIdentityPem {
+                cert_pem: self.client_cert_pem.clone(),
+                key_pem: self.client_key_pem.clone(),
+            }),
+        }
+    }
+}
+
+/// A CA with a `localhost` server leaf and a client leaf, from the `tls_gen` generator
+fn generate_tls_bundle() -> TlsBundle {
+    let out_dir = std::env::temp_dir().join(format!("rustfs-lock-tls-{}", uuid::Uuid::new_v4()));
+    tls_gen::run(Args {
+        out_dir: out_dir.clone(),
+        days: 1,
+        force: true,
+    })
+    .expect("Failed to generate TLS bundle");
+
+    let read = |name: &str| fs::read(out_dir.join(name)).expect("Failed to read generated TLS file");
+    let bundle = TlsBundle {
+        ca_pem: read("ca.crt"),
+        server_cert_pem: read("rustfs_cert.pem"),
+        server_key_pem: read("rustfs_key.pem"),
+        client_cert_pem: read("client_cert.pem"),
+        client_key_pem: read("client_key.pem"),
+    };
+    let _ = fs::remove_dir_all(&out_dir);
+    bundle
+}
+
+/// Install the crypto provider and the RPC secret `RemoteClient` signs requests with
+fn init_remote_client_env() {
+    let _ = rustls::crypto::aws_lc_rs::default_provider().install_default();
+    let _ = rustfs_credentials::set_global_rpc_secret("rustfs-lock-tls-test-secret".to_string());
+}
+
+fn test_request(object: &str) -> LockRequest {
+    LockRequest::new(ObjectKey::new("tls-bucket", object), LockType::Exclusive, "owner-a")
+        .with_acquire_timeout(Duration::from_secs(2))
+}
+
+fn offline_failure_threshold() -> u32 {
+    rustfs_utils::get_env_u32(
+        rustfs_config::ENV_INTERNODE_OFFLINE_FAILURE_THRESHOLD,
+        rustfs_config::DEFAULT_INTERNODE_OFFLINE_FAILURE_THRESHOLD,
+    )
+}
+
+#[tokio::test]
+async fn test_remote_client_round_trips_over_mutual_tls() {
+    init_remote_client_env();
+    let bundle = generate_tls_bundle();
+    let local_client = Arc::new(LocalClient::with_manager(Arc::new(GlobalLockManager::new())));
+    let (addr, handle) = spawn_tls_lock_server(
+        local_client.clone(),
+        &bundle.server_cert_pem,
+        &bundle.server_key_pem,
+        Some(&bundle.ca_pem),
+    )
+    .await
+    .expect("Failed to spawn mTLS server");
+
+    let client = RemoteClient::new(addr).with_tls(bundle.client_tls(true));
+    assert!(client.is_online().await, "a peer reached over mTLS is online");
+
+    let request = test_request("round-trip");
+    let response = client
+        .acquire_lock(&request)
+        .await
+        .expect("acquire over mTLS should reach the server");
+    assert!(response.success, "acquire over mTLS should succeed: {:?}", response.error);
+    assert!(local_client.check_status(&request.lock_id).await.unwrap().is_some());
+
+    assert!(
+        client
+            .release(&request.lock_id)
+            .await
+            .expect("release over mTLS should succeed")
+    );
+    assert!(local_client.check_status(&request.lock_id).await.unwrap().is_none());
+
+    handle.abort();
+}
+
+#[tokio::test]
+async fn test_remote_client_rejects_untrusted_server_and_reports_it_offline() {
+    init_remote_client_env();
+    let bundle = generate_tls_bundle();
+    let local_client = Arc::new(LocalClient::with_manager(Arc::new(GlobalLockManager::new())));
+    let (addr, handle) = spawn_tls_lock_server(local_client.clone(), &bundle.server_cert_pem, &bundle.server_key_pem, None)
+        .await
+        .expect("Failed to spawn TLS server");
+
+    // The server's certificate does not chain to the CA the client trusts.
+    let unrelated_ca = generate_simple_self_signed(vec!["localhost".to_string()]).expect("Failed to generate unrelated CA");
+    let client = RemoteClient::new(addr).with_tls(OutboundTlsMaterial {
+        root_ca_pem: unrelated_ca.cert.pem().into_bytes(),
+        mtls_identity: None,
+    });
+
+    // Every failed handshake counts toward the offline threshold.
+    let threshold = offline_failure_threshold();
+    for attempt in 1..=threshold {
+        assert_eq!(
+            client.is_online().await,
+            attempt < threshold,
+            "handshake failure {attempt} of {threshold} should only go offline at the threshold"
+        );
+    }
+
+    let request = test_request("untrusted-server");
+    let result = client.acquire_lock(&request).await;
+    assert!(
+        !matches!(result, Ok(ref response) if response.success),
+        "acquire should fail against an untrusted server certificate: {result:?}"
+    );
+    assert!(local_client.check_status(&request.lock_id).await.unwrap().is_none());
+
+    handle.abort();
+}
+
+#[tokio::test]
+async fn test_remote_client_without_client_certificate_is_rejected_by_mtls_server() {
+    init_remote_client_env();
+    let bundle = generate_tls_bundle();
+    let local_client = Arc::new(LocalClient::with_manager(Arc::new(GlobalLockManager::new())));
+    let (addr, handle) = spawn_tls_lock_server(
+        local_client.clone(),
+        &bundle.server_cert_pem,
+        &bundle.server_key_pem,
+        Some(&bundle.ca_pem),
+    )
+    .await
+    .expect("Failed to spawn mTLS server");
+
+    // The server is trusted, but the client presents no certificate.
+    let client = RemoteClient::new(addr).with_tls(bundle.client_tls(false));
+    let request = test_request("missing-client-cert");
+    let result = client.acquire_lock(&request).await;
+    assert!(
+        !matches!(result, Ok(ref response) if response.success),
+        "acquire should fail without a client certificate: {result:?}"
+    );
+    assert!(local_client.check_status(&request.lock_id).await.unwrap().is_none());
+
+    handle.abort();
+}
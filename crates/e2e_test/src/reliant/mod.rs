@@ -20,6 +20,7 @@ mod head_deleted_object_versioning_test;
 mod head_tls_bodyless_test;
 mod lifecycle;
 mod lock;
+mod lock_tls;
 mod node_interact_test;
 mod sql;
 mod tiering;
@@ -18,12 +18,13 @@ use crate::disk::error::{DiskError, Error as DiskErrorType};
 use crate::runtime::sources as runtime_sources;
 use http::Uri;
 use rustfs_protos::{
-    ChannelClass, create_new_channel, get_channel_for_class,
+    ChannelClass, create_new_channel, get_channel_for_class, get_channel_with_tls,
     proto_gen::node_service::{
         heal_control_service_client::HealControlServiceClient, node_service_client::NodeServiceClient,
         tier_mutation_control_service_client::TierMutationControlServiceClient,
     },
 };
+use rustfs_tls_runtime::OutboundTlsMaterial;
 use std::{error::Error, io::ErrorKind};
 use tonic::{service::interceptor::InterceptedService, transport::Channel};
 use tracing::debug;
@@ -101,6 +102,21 @@ pub async fn node_service_time_out_client_for_class(
         .max_encoding_message_size(max_message_size))
 }
 
+/// Build a `NodeServiceClient` for `addr` over a control channel that trusts `tls.root_ca_pem`
+/// and presents `tls.mtls_identity`, instead of the global outbound TLS state.
+pub async fn node_service_time_out_client_with_tls(
+    addr: &String,
+    interceptor: TonicInterceptor,
+    tls: &OutboundTlsMaterial,
+) -> Result<NodeServiceClient<InterceptedService<Channel, TonicInterceptor>>, Box<dyn Error>> {
+    let interceptor = interceptor.with_rpc_audience(addr)?;
+    let channel = get_channel_with_tls(addr, tls).await?;
+    let max_message_size = rustfs_protos::internode_rpc_max_message_size();
+    Ok(NodeServiceClient::with_interceptor(channel, interceptor)
+        .max_decoding_message_size(max_message_size)
+        .max_encoding_message_size(max_message_size))
+}
+
 pub async fn node_service_time_out_client_no_auth(
     addr: &String,
 ) -> Result<NodeServiceClient<InterceptedService<Channel, TonicInterceptor>>, Box<dyn Error>> {
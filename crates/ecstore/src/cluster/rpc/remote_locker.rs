@@ -12,7 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::cluster::rpc::client::{TonicInterceptor, gen_tonic_signature_interceptor, node_service_time_out_client};
+use crate::cluster::rpc::client::{
+    TonicInterceptor, gen_tonic_signature_interceptor, node_service_time_out_client, node_service_time_out_client_with_tls,
+};
 use async_trait::async_trait;
 use bytes::Bytes;
 use rustfs_lock::{
@@ -21,9 +23,11 @@ use rustfs_lock::{
 };
 use rustfs_protos::proto_gen::node_service::{BatchGenerallyLockRequest, GenerallyLockRequest, PingRequest};
 use rustfs_protos::{
-    ConnectionEvictionLogLevel, evict_failed_connection_with_log_level, models::PingBodyBuilder,
-    proto_gen::node_service::node_service_client::NodeServiceClient,
+    ConnectionEvictionLogLevel, evict_failed_connection_with_log_level, evict_failed_tls_connection_with_log_level,
+    models::PingBodyBuilder, proto_gen::node_service::node_service_client::NodeServiceClient,
 };
+use rustfs_tls_runtime::OutboundTlsMaterial;
+use std::sync::Arc;
 use std::time::Duration;
 use tokio::time::timeout;
 use tonic::Request;
@@ -32,18 +36,50 @@ use tonic::transport::Channel;
 use tracing::{debug, info, warn};
 
 /// Remote lock client implementation
-#[derive(Debug, Clone)]
+///
+/// Lock RPCs share the internode gRPC channel, so an `https://` peer is
+/// dialed with the outbound TLS state: the published root CA and, when one
+/// is configured, the mTLS client identity. [`RemoteClient::with_tls`] gives
+/// the client its own CA and client certificate instead. A failed TLS
+/// handshake fails the dial and counts toward the peer's offline threshold
+/// like any other dial failure, so `is_online()` reports the peer offline
+/// once it is crossed.
+#[derive(Clone)]
 pub struct RemoteClient {
     addr: String,
+    tls: Option<Arc<OutboundTlsMaterial>>,
+}
+
+impl std::fmt::Debug for RemoteClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // The TLS material carries a private key, so only its presence is shown.
+        f.debug_struct("RemoteClient")
+            .field("addr", &self.addr)
+            .field("tls", &self.tls.is_some())
+            .finish()
+    }
 }
 
 impl RemoteClient {
     pub fn new(endpoint: String) -> Self {
-        Self { addr: endpoint }
+        Self {
+            addr: endpoint,
+            tls: None,
+        }
     }
 
     pub fn from_url(url: url::Url) -> Self {
-        Self { addr: url.to_string() }
+        Self::new(url.to_string())
+    }
+
+    /// Verify the peer against `tls.root_ca_pem` and authenticate with
+    /// `tls.mtls_identity` rather than the global outbound TLS state.
+    ///
+    /// Only applies to `https://` peers. The client gets a channel of its
+    /// own, cached separately from the shared internode channel.
+    pub fn with_tls(mut self, tls: OutboundTlsMaterial) -> Self {
+        self.tls = Some(Arc::new(tls));
+        self
     }
 
     fn build_ping_request() -> PingRequest {
@@ -74,6 +110,8 @@ impl RemoteClient {
             deadlock_detection: false,
             suppress_contention_logs: false,
             refresh_interval: None,
+            epoch: 0,
+            weight: 1,
         }
     }
 
@@ -84,9 +122,12 @@ impl RemoteClient {
         if let Some(reason) = crate::cluster::rpc::remote_disk::internode_offline_bypass_reason(&self.addr) {
             return Err(LockError::internal(reason));
         }
-        node_service_time_out_client(&self.addr, TonicInterceptor::Signature(gen_tonic_signature_interceptor()))
-            .await
-            .map_err(|err| LockError::internal(format!("can not get client, err: {err}")))
+        let interceptor = TonicInterceptor::Signature(gen_tonic_signature_interceptor());
+        let client = match &self.tls {
+            Some(tls) => node_service_time_out_client_with_tls(&self.addr, interceptor, tls).await,
+            None => node_service_time_out_client(&self.addr, interceptor).await,
+        };
+        client.map_err(|err| LockError::internal(format!("can not get client, err: {err}")))
     }
 
     fn is_scanner_leader_lock(resource_summary: &str) -> bool {
@@ -136,7 +177,10 @@ impl RemoteClient {
             );
             ConnectionEvictionLogLevel::Warn
         };
-        evict_failed_connection_with_log_level(&self.addr, log_level).await;
+        match &self.tls {
+            Some(tls) => evict_failed_tls_connection_with_log_level(&self.addr, tls, log_level).await,
+            None => evict_failed_connection_with_log_level(&self.addr, log_level).await,
+        }
     }
 
     fn summarize_resources(requests: &[LockRequest]) -> String {
@@ -162,6 +206,14 @@ impl RemoteClient {
         )
     }
 
+    /// Consecutive failures before the peer is reported offline; shared with the dial path
+    fn offline_failure_threshold() -> u32 {
+        rustfs_utils::get_env_u32(
+            rustfs_config::ENV_INTERNODE_OFFLINE_FAILURE_THRESHOLD,
+            rustfs_config::DEFAULT_INTERNODE_OFFLINE_FAILURE_THRESHOLD,
+        )
+    }
+
     async fn execute_rpc<T, F>(&self, op: &'static str, resource_summary: &str, future: F) -> std::result::Result<T, LockError>
     where
         F: std::future::Future<Output = std::result::Result<T, tonic::Status>>,
@@ -224,6 +276,8 @@ impl RemoteClient {
                         "Remote lock RPC timed out"
                     );
                 }
+                // Eviction also counts the timeout toward the peer's offline threshold, so the
+                // quorum orchestrator stops waiting on a peer that stopped answering.
                 self.evict_connection(op, &reason, resource_summary).await;
                 Err(LockError::timeout(format!("remote lock RPC {op} on {}", self.addr), lock_timeout))
             }
@@ -536,26 +590,38 @@ impl LockClient for RemoteClient {
         Ok(())
     }
 
+    /// Ping the peer, reporting it offline only after repeated failures.
+    ///
+    /// Failed pings count against the same consecutive-failure threshold as
+    /// dial failures and RPC-triggered evictions
+    /// (`RUSTFS_INTERNODE_OFFLINE_FAILURE_THRESHOLD`), so a single dropped ping
+    /// does not take the peer out of quorum. A successful ping brings it back
+    /// online immediately.
     async fn is_online(&self) -> bool {
-        // Use Ping interface to test if remote service is online
+        use rustfs_io_metrics::internode_metrics::{cluster_peer_is_offline, record_peer_reachable, record_peer_unreachable};
+
+        // Dial failures and the offline bypass have already been counted by the dial path.
         let mut client = match self.get_client().await {
             Ok(client) => client,
             Err(_) => {
                 info!("remote client {} connection failed", self.addr);
-                return false;
+                return !cluster_peer_is_offline(&self.addr);
             }
         };
 
         let ping_req = Request::new(Self::build_ping_request());
 
-        match client.ping(ping_req).await {
-            Ok(_) => {
+        // Bounded like every other lock RPC so a hung peer cannot stall the probe.
+        match timeout(Self::rpc_timeout(), client.ping(ping_req)).await {
+            Ok(Ok(_)) => {
                 info!("remote client {} is online", self.addr);
+                record_peer_reachable(&self.addr);
                 true
             }
-            Err(_) => {
+            Ok(Err(_)) | Err(_) => {
                 info!("remote client {} ping failed", self.addr);
-                false
+                record_peer_unreachable(&self.addr, Self::offline_failure_threshold());
+                !cluster_peer_is_offline(&self.addr)
             }
         }
     }
@@ -855,6 +921,71 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    #[serial_test::serial]
+    async fn test_remote_client_is_online_flips_after_consecutive_ping_failures() {
+        ensure_test_rpc_secret();
+        let Some(addr) = closed_listener_addr().await else {
+            return;
+        };
+        cache_lazy_channel(&addr).await;
+
+        temp_env::async_with_vars([(rustfs_config::ENV_INTERNODE_OFFLINE_FAILURE_THRESHOLD, Some("2"))], async {
+            let client = RemoteClient::new(addr.clone());
+            assert!(client.is_online().await, "one failed ping stays under the threshold");
+            assert!(!client.is_online().await, "second consecutive failure takes the peer offline");
+            assert!(!client.is_online().await, "offline peers stay offline while unreachable");
+
+            // A successful dial resets the failure streak, so the next lone failure is tolerated again.
+            rustfs_io_metrics::internode_metrics::record_peer_reachable(&addr);
+            cache_lazy_channel(&addr).await;
+            assert!(client.is_online().await);
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    #[serial_test::serial]
+    async fn test_remote_client_rpc_timeout_marks_slow_peer_offline() {
+        ensure_test_rpc_secret();
+        let Some((addr, accept_task)) = spawn_hanging_listener().await else {
+            return;
+        };
+        temp_env::async_with_vars(
+            [
+                (rustfs_config::ENV_OBJECT_LOCK_RPC_TIMEOUT_MS, Some("50")),
+                (rustfs_config::ENV_INTERNODE_OFFLINE_FAILURE_THRESHOLD, Some("2")),
+            ],
+            async {
+                let client = RemoteClient::new(addr.clone());
+                let request = test_lock_request(Duration::from_millis(5));
+
+                for attempt in 1..=2 {
+                    // Each timeout evicts the channel; re-cache it so the next call reaches the hung peer again.
+                    cache_lazy_channel(&addr).await;
+                    let started_at = tokio::time::Instant::now();
+                    let err = client.refresh(&request.lock_id).await.expect_err("refresh should time out");
+                    assert!(matches!(err, LockError::Timeout { .. }), "expected remote refresh timeout, got {err:?}");
+                    assert!(
+                        started_at.elapsed() < Duration::from_secs(1),
+                        "the call should return within the configured bound, got {:?}",
+                        started_at.elapsed()
+                    );
+                    assert_eq!(
+                        rustfs_io_metrics::internode_metrics::cluster_peer_is_offline(&addr),
+                        attempt == 2,
+                        "each timeout counts once toward the threshold of two (attempt {attempt})"
+                    );
+                }
+                assert!(!client.is_online().await, "a peer that timed out past the threshold is offline");
+            },
+        )
+        .await;
+
+        rustfs_io_metrics::internode_metrics::record_peer_reachable(&addr);
+        accept_task.abort();
+    }
+
     #[test]
     #[serial_test::serial]
     fn test_remote_client_rpc_timeout_honors_configured_deadline() {
@@ -66,6 +66,14 @@ impl SetDisks {
                 current_owner,
                 current_mode,
             } => format!("{mode} lock conflicted on {bucket}/{object}: held by {current_owner} as {current_mode:?}"),
+            LockResult::ReentrancyLimitExceeded { owner, depth } => {
+                format!("{mode} lock reentrancy limit reached on {bucket}/{object}: {owner} already holds it {depth} times")
+            }
+            LockResult::Cancelled => format!("{mode} lock acquisition on {bucket}/{object} was cancelled"),
+            LockResult::BatchLimitExceeded { requested, max } => {
+                format!("{mode} lock batch for {bucket}/{object} names {requested} objects, above the limit of {max}")
+            }
+            LockResult::InvalidKey { reason } => format!("{mode} lock on {bucket}/{object} rejected: {reason}"),
             LockResult::Acquired => format!("unexpected lock state while acquiring {mode} lock on {bucket}/{object}"),
         }
     }
@@ -0,0 +1,82 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! All-or-nothing batch of 1000 write locks that conflicts on one object.
+//!
+//! The batch stops at the first key it cannot lock, so a conflict on the
+//! first key in lock order should cost almost nothing, while a conflict on the
+//! last key pays for locking and rolling back the other 999.
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use rustfs_lock::fast_lock::DEFAULT_SHARD_COUNT;
+use rustfs_lock::{BatchLockRequest, FastObjectLockManager, ObjectKey, ObjectLockRequest};
+use std::hint::black_box;
+use std::time::Duration;
+
+const BATCH_SIZE: usize = 1000;
+
+/// Keys in the (shard, key) order batches acquire them in
+fn keys_in_lock_order() -> Vec<ObjectKey> {
+    let mut keys: Vec<_> = (0..BATCH_SIZE)
+        .map(|i| ObjectKey::new("bench-bucket", format!("object-{i}")))
+        .collect();
+    keys.sort_unstable_by(|a, b| {
+        let shard_mask = DEFAULT_SHARD_COUNT - 1;
+        a.shard_index(shard_mask)
+            .cmp(&b.shard_index(shard_mask))
+            .then_with(|| a.cmp(b))
+    });
+    keys
+}
+
+fn conflicting_batch(keys: &[ObjectKey]) -> BatchLockRequest {
+    let mut batch = BatchLockRequest::new("bench-batch");
+    batch.requests = keys
+        .iter()
+        .map(|key| ObjectLockRequest::new_write(key.clone(), "bench-batch").with_acquire_timeout(Duration::ZERO))
+        .collect();
+    batch
+}
+
+fn bench_batch_conflict(c: &mut Criterion) {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("build tokio runtime for lock benchmark");
+    let keys = keys_in_lock_order();
+
+    let mut group = c.benchmark_group("batch_1000_conflict");
+    for (name, contended) in [
+        ("first_key_locked", keys[0].clone()),
+        ("last_key_locked", keys[BATCH_SIZE - 1].clone()),
+    ] {
+        let manager = FastObjectLockManager::new();
+        let holder = runtime
+            .block_on(manager.acquire_write_lock(contended, "bench-holder"))
+            .expect("lock contended key");
+
+        group.bench_function(name, |b| {
+            b.iter(|| {
+                let result = runtime.block_on(manager.acquire_locks_batch(conflicting_batch(&keys)));
+                assert!(!result.all_acquired);
+                black_box(result.failed_locks.len())
+            })
+        });
+        drop(holder);
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_batch_conflict);
+criterion_main!(benches);
@@ -0,0 +1,117 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Inspecting a 100k-entry lock table: materialized copy vs borrowing visitor.
+//!
+//! `list_locks` allocates an owned `ObjectLockInfo` (plus its key and owner
+//! reference counts) for every holder, and `iter_locks` does the same one
+//! shard at a time; `for_each_lock` hands out borrowed views and allocates
+//! nothing per entry. A counting allocator reports each strategy's
+//! allocations per pass before timing starts.
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use rustfs_lock::{FastLockGuard, FastObjectLockManager, ObjectKey};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+const TABLE_SIZE: usize = 100_000;
+
+/// System allocator that counts every allocation it serves
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+#[allow(unsafe_code)]
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Allocations made by one run of `pass`
+fn allocations_during(pass: impl FnOnce() -> usize) -> usize {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    black_box(pass());
+    ALLOCATIONS.load(Ordering::Relaxed) - before
+}
+
+fn bench_lock_inspection(c: &mut Criterion) {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("build tokio runtime for lock benchmark");
+
+    let manager = FastObjectLockManager::new();
+    let guards: Vec<FastLockGuard> = runtime.block_on(async {
+        let mut guards = Vec::with_capacity(TABLE_SIZE);
+        for i in 0..TABLE_SIZE {
+            let guard = manager
+                .acquire_read_lock(ObjectKey::new("bench-bucket", format!("object-{i}")), "bench-owner")
+                .await
+                .expect("populate lock table");
+            guards.push(guard);
+        }
+        guards
+    });
+
+    let list_locks = || black_box(manager.list_locks()).len();
+    let iter_locks = || {
+        manager
+            .iter_locks()
+            .fold(0usize, |count, info| count + black_box(info).key.object.len())
+    };
+    let for_each_lock = || {
+        let mut count = 0usize;
+        manager.for_each_lock(|holder| {
+            black_box(holder.owner);
+            count += 1;
+        });
+        count
+    };
+
+    for (name, allocations) in [
+        ("list_locks", allocations_during(list_locks)),
+        ("iter_locks", allocations_during(iter_locks)),
+        ("for_each_lock", allocations_during(for_each_lock)),
+    ] {
+        eprintln!("{name}: {allocations} allocations per pass over {TABLE_SIZE} locks");
+    }
+    assert_eq!(allocations_during(for_each_lock), 0, "for_each_lock must not allocate");
+
+    let mut group = c.benchmark_group("inspect_100k_locks");
+    group.sample_size(20);
+    group.bench_function("list_locks", |b| b.iter(list_locks));
+    group.bench_function("iter_locks", |b| b.iter(iter_locks));
+    group.bench_function("for_each_lock", |b| b.iter(for_each_lock));
+
+    group.finish();
+    drop(guards);
+}
+
+criterion_group!(benches, bench_lock_inspection);
+criterion_main!(benches);
@@ -0,0 +1,182 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Baseline timings for the hot lock paths.
+//!
+//! - an uncontended write lock and unlock on one object
+//! - an all-or-nothing batch of 100 write locks
+//! - many tasks read-locking the same object at once
+//! - a `LocalClient` expiry sweep over 50k expired leases
+//! - one reader releasing and re-taking its lock among 250 on a hot object
+
+use criterion::{BatchSize, Criterion, Throughput, criterion_group, criterion_main};
+use rustfs_lock::client::local::LocalClient;
+use rustfs_lock::clock::ManualClock;
+use rustfs_lock::{BatchLockRequest, FastObjectLockManager, GlobalLockManager, LockClient, LockRequest, LockType, ObjectKey};
+use std::hint::black_box;
+use std::sync::Arc;
+use std::time::Duration;
+
+const GROUP_SIZE: usize = 100;
+const READERS: usize = 64;
+const SWEEP_TABLE_SIZE: usize = 50_000;
+/// Just under the 255 concurrent shared holds an object supports
+const HOT_READERS: usize = 250;
+
+fn bench_single_lock_unlock(c: &mut Criterion, runtime: &tokio::runtime::Runtime) {
+    let manager = FastObjectLockManager::new();
+    let key = ObjectKey::new("bench-bucket", "single-object");
+
+    let mut group = c.benchmark_group("single_lock_unlock");
+    group.throughput(Throughput::Elements(1));
+    group.bench_function("write", |b| {
+        b.iter(|| {
+            let guard = runtime
+                .block_on(manager.acquire_write_lock(key.clone(), "bench-owner"))
+                .expect("uncontended write lock");
+            drop(black_box(guard));
+        })
+    });
+    group.finish();
+}
+
+fn bench_group_lock(c: &mut Criterion, runtime: &tokio::runtime::Runtime) {
+    let manager = FastObjectLockManager::new();
+    let keys: Vec<_> = (0..GROUP_SIZE)
+        .map(|i| ObjectKey::new("bench-bucket", format!("group-object-{i}")))
+        .collect();
+
+    let mut group = c.benchmark_group("group_lock");
+    group.throughput(Throughput::Elements(GROUP_SIZE as u64));
+    group.bench_function("write_100", |b| {
+        b.iter(|| {
+            let batch = keys
+                .iter()
+                .fold(BatchLockRequest::new("bench-owner"), |batch, key| batch.add_write_lock(key.clone()));
+            let result = runtime.block_on(manager.acquire_locks_batch(batch));
+            assert!(result.all_acquired);
+            drop(black_box(result));
+        })
+    });
+    group.finish();
+}
+
+fn bench_read_fan_out(c: &mut Criterion) {
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(8)
+        .enable_all()
+        .build()
+        .expect("build tokio runtime for lock benchmark");
+    let manager = Arc::new(FastObjectLockManager::new());
+    let key = ObjectKey::new("bench-bucket", "fan-out-object");
+
+    let mut group = c.benchmark_group("read_fan_out");
+    group.throughput(Throughput::Elements(READERS as u64));
+    group.bench_function("64_readers", |b| {
+        b.iter(|| {
+            runtime.block_on(async {
+                let readers: Vec<_> = (0..READERS)
+                    .map(|reader| {
+                        let manager = manager.clone();
+                        let key = key.clone();
+                        tokio::spawn(async move {
+                            let guard = manager
+                                .acquire_read_lock(key, format!("bench-reader-{reader}"))
+                                .await
+                                .expect("readers never conflict");
+                            black_box(&guard);
+                        })
+                    })
+                    .collect();
+                for reader in readers {
+                    reader.await.expect("bench reader panicked");
+                }
+            })
+        })
+    });
+    group.finish();
+}
+
+fn bench_expiry_sweep(c: &mut Criterion, runtime: &tokio::runtime::Runtime) {
+    let populated_client = || {
+        let clock = Arc::new(ManualClock::new());
+        let client = LocalClient::with_manager(Arc::new(GlobalLockManager::new())).with_clock(clock.clone());
+        runtime.block_on(async {
+            for i in 0..SWEEP_TABLE_SIZE {
+                let request =
+                    LockRequest::new(ObjectKey::new("bench-bucket", format!("leased-{i}")), LockType::Shared, "bench-owner")
+                        .with_ttl(Duration::from_secs(1));
+                assert!(client.acquire_lock(&request).await.expect("populate lock table").success);
+            }
+        });
+        clock.advance(Duration::from_secs(2));
+        client
+    };
+
+    let mut group = c.benchmark_group("expiry_sweep");
+    group.sample_size(10);
+    group.throughput(Throughput::Elements(SWEEP_TABLE_SIZE as u64));
+    group.bench_function("reclaim_50k_expired", |b| {
+        b.iter_batched(
+            populated_client,
+            |client| {
+                assert_eq!(runtime.block_on(client.reclaim_expired()), SWEEP_TABLE_SIZE);
+                client
+            },
+            BatchSize::PerIteration,
+        )
+    });
+    group.finish();
+}
+
+fn bench_hot_reader_release(c: &mut Criterion, runtime: &tokio::runtime::Runtime) {
+    let client = LocalClient::with_manager(Arc::new(GlobalLockManager::new()));
+    let key = ObjectKey::new("bench-bucket", "hot-read-object");
+    let readers: Vec<_> = (0..HOT_READERS)
+        .map(|reader| LockRequest::new(key.clone(), LockType::Shared, format!("bench-reader-{reader}")))
+        .collect();
+    runtime.block_on(async {
+        for reader in &readers {
+            assert!(client.acquire_lock(reader).await.expect("fill hot object").success);
+        }
+    });
+    let cycling = &readers[HOT_READERS / 2];
+
+    let mut group = c.benchmark_group("hot_reader_release");
+    group.throughput(Throughput::Elements(1));
+    group.bench_function("250_readers", |b| {
+        b.iter(|| {
+            runtime.block_on(async {
+                assert!(client.release(&cycling.lock_id).await.expect("release reader"));
+                assert!(client.acquire_lock(cycling).await.expect("re-take reader").success);
+            })
+        })
+    });
+    group.finish();
+}
+
+fn bench_lock_paths(c: &mut Criterion) {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("build tokio runtime for lock benchmark");
+    bench_single_lock_unlock(c, &runtime);
+    bench_group_lock(c, &runtime);
+    bench_read_fan_out(c);
+    bench_expiry_sweep(c, &runtime);
+    bench_hot_reader_release(c, &runtime);
+}
+
+criterion_group!(benches, bench_lock_paths);
+criterion_main!(benches);
@@ -0,0 +1,91 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Compares a single lock map against the sharded default when concurrent
+//! tasks lock disjoint objects.
+
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use rustfs_lock::fast_lock::{DEFAULT_SHARD_COUNT, LockConfig};
+use rustfs_lock::{FastObjectLockManager, ObjectKey};
+use std::hint::black_box;
+use std::sync::Arc;
+
+const TASKS: usize = 8;
+const OPS_PER_TASK: usize = 256;
+
+fn run_disjoint_workload(
+    runtime: &tokio::runtime::Runtime,
+    manager: &Arc<FastObjectLockManager>,
+    keys: &Arc<Vec<Vec<ObjectKey>>>,
+) {
+    runtime.block_on(async {
+        let handles: Vec<_> = (0..TASKS)
+            .map(|task| {
+                let manager = manager.clone();
+                let keys = keys.clone();
+                tokio::spawn(async move {
+                    let owner: Arc<str> = Arc::from(format!("bench-owner-{task}"));
+                    for key in &keys[task] {
+                        let guard = manager
+                            .acquire_write_lock(key.clone(), owner.clone())
+                            .await
+                            .expect("disjoint keys never conflict");
+                        black_box(&guard);
+                    }
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.await.expect("bench task panicked");
+        }
+    });
+}
+
+fn bench_shard_contention(c: &mut Criterion) {
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(TASKS)
+        .enable_all()
+        .build()
+        .expect("build tokio runtime for lock benchmark");
+
+    let keys: Arc<Vec<Vec<ObjectKey>>> = Arc::new(
+        (0..TASKS)
+            .map(|task| {
+                (0..OPS_PER_TASK)
+                    .map(|op| ObjectKey::new("bench-bucket", format!("task-{task}/object-{op}")))
+                    .collect()
+            })
+            .collect(),
+    );
+
+    let mut group = c.benchmark_group("disjoint_write_locks");
+    group.throughput(Throughput::Elements((TASKS * OPS_PER_TASK) as u64));
+
+    for shard_count in [1, DEFAULT_SHARD_COUNT] {
+        let config = LockConfig {
+            shard_count,
+            ..LockConfig::default()
+        };
+        let manager = Arc::new(FastObjectLockManager::with_config(config));
+        group.bench_with_input(BenchmarkId::from_parameter(shard_count), &manager, |b, manager| {
+            b.iter(|| run_disjoint_workload(&runtime, manager, &keys))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_shard_contention);
+criterion_main!(benches);
@@ -0,0 +1,93 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Audit trail of lock lifecycle events
+//!
+//! A client configured with a [`LockAudit`] reports every acquisition,
+//! refresh, release and expiry it performs as a [`LockEvent`]. Hooks run
+//! inline on the lock path, so implementations should hand events off (to a
+//! channel or a buffered writer) rather than block.
+
+use std::fmt::Debug;
+use std::time::SystemTime;
+
+use crate::{LockId, LockType, ObjectKey};
+
+/// What happened to a lock
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LockEventKind {
+    /// Exclusive lock acquired
+    Lock,
+    /// Shared lock acquired
+    RLock,
+    /// Exclusive lock released by its holder
+    Unlock,
+    /// Shared lock released by its holder
+    RUnlock,
+    /// Lease refreshed
+    Refresh,
+    /// Lock released by an administrative path rather than its holder
+    ForceUnlock,
+    /// Lease ran out without a refresh and the lock was reclaimed
+    Expire,
+}
+
+impl LockEventKind {
+    /// Acquisition event for a lock of `lock_type`
+    pub fn acquired(lock_type: LockType) -> Self {
+        match lock_type {
+            LockType::Exclusive => Self::Lock,
+            LockType::Shared => Self::RLock,
+        }
+    }
+
+    /// Holder-initiated release event for a lock of `lock_type`
+    pub fn released(lock_type: LockType) -> Self {
+        match lock_type {
+            LockType::Exclusive => Self::Unlock,
+            LockType::Shared => Self::RUnlock,
+        }
+    }
+}
+
+/// One lifecycle event reported to a [`LockAudit`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LockEvent {
+    pub kind: LockEventKind,
+    /// Lock uid, shared by every event of the same lock
+    pub uid: String,
+    pub owner: String,
+    /// Requesting node or client, when the request named one
+    pub source: Option<String>,
+    pub resources: Vec<ObjectKey>,
+    pub timestamp: SystemTime,
+}
+
+impl LockEvent {
+    pub fn new(kind: LockEventKind, lock_id: &LockId, owner: impl Into<String>, source: Option<String>) -> Self {
+        Self {
+            kind,
+            uid: lock_id.uuid.clone(),
+            owner: owner.into(),
+            source,
+            resources: vec![lock_id.resource.clone()],
+            timestamp: SystemTime::now(),
+        }
+    }
+}
+
+/// Receiver of lock lifecycle events
+pub trait LockAudit: Send + Sync + Debug {
+    fn on_event(&self, event: LockEvent);
+}
@@ -0,0 +1,227 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Fault-injecting lock client for resilience tests
+//!
+//! [`FaultyClient`] wraps another client and deterministically fails,
+//! delays, or reports itself offline, so quorum handling in
+//! `DistributedLock` can be exercised against partial failures.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::time::Duration;
+
+use async_trait::async_trait;
+
+use crate::client::LockClient;
+use crate::{LockError, LockId, LockInfo, LockRequest, LockResponse, LockStats, Result};
+
+/// Lock client wrapper that injects failures into an inner client
+#[derive(Debug)]
+pub struct FaultyClient {
+    inner: Arc<dyn LockClient>,
+    /// Operations seen so far, counting the one in progress
+    calls: AtomicUsize,
+    /// 1-based call that fails; 0 never fails
+    fail_on_call: usize,
+    /// Added before every operation reaches the inner client
+    delay: Duration,
+    offline: AtomicBool,
+}
+
+impl FaultyClient {
+    /// Wrap `inner` with no faults configured
+    pub fn new(inner: Arc<dyn LockClient>) -> Self {
+        Self {
+            inner,
+            calls: AtomicUsize::new(0),
+            fail_on_call: 0,
+            delay: Duration::ZERO,
+            offline: AtomicBool::new(false),
+        }
+    }
+
+    /// Fail the `n`th operation (1-based) with an internal error
+    pub fn with_fail_on_call(mut self, n: usize) -> Self {
+        self.fail_on_call = n;
+        self
+    }
+
+    /// Delay every operation by `delay` before forwarding it
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Make `is_online` report false, or true again; can flap at any time
+    pub fn set_offline(&self, offline: bool) {
+        self.offline.store(offline, Ordering::Release);
+    }
+
+    /// Number of operations attempted through this client
+    pub fn call_count(&self) -> usize {
+        self.calls.load(Ordering::Acquire)
+    }
+
+    async fn before_call(&self, operation: &str) -> Result<()> {
+        let call = self.calls.fetch_add(1, Ordering::AcqRel) + 1;
+        if !self.delay.is_zero() {
+            tokio::time::sleep(self.delay).await;
+        }
+        if call == self.fail_on_call {
+            return Err(LockError::internal(format!("injected fault in {operation} on call {call}")));
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl LockClient for FaultyClient {
+    async fn acquire_lock(&self, request: &LockRequest) -> Result<LockResponse> {
+        self.before_call("acquire_lock").await?;
+        self.inner.acquire_lock(request).await
+    }
+
+    async fn release(&self, lock_id: &LockId) -> Result<bool> {
+        self.before_call("release").await?;
+        self.inner.release(lock_id).await
+    }
+
+    async fn refresh(&self, lock_id: &LockId) -> Result<bool> {
+        self.before_call("refresh").await?;
+        self.inner.refresh(lock_id).await
+    }
+
+    async fn force_release(&self, lock_id: &LockId) -> Result<bool> {
+        self.before_call("force_release").await?;
+        self.inner.force_release(lock_id).await
+    }
+
+    async fn check_status(&self, lock_id: &LockId) -> Result<Option<LockInfo>> {
+        self.before_call("check_status").await?;
+        self.inner.check_status(lock_id).await
+    }
+
+    async fn get_stats(&self) -> Result<LockStats> {
+        self.before_call("get_stats").await?;
+        self.inner.get_stats().await
+    }
+
+    async fn close(&self) -> Result<()> {
+        self.inner.close().await
+    }
+
+    async fn is_online(&self) -> bool {
+        !self.offline.load(Ordering::Acquire) && self.inner.is_online().await
+    }
+
+    async fn is_local(&self) -> bool {
+        self.inner.is_local().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::local::LocalClient;
+    use crate::{GlobalLockManager, LockType, ObjectKey};
+    use std::time::Instant;
+
+    fn faulty_local() -> FaultyClient {
+        FaultyClient::new(Arc::new(LocalClient::with_manager(Arc::new(GlobalLockManager::new()))))
+    }
+
+    fn request(object: &str) -> LockRequest {
+        LockRequest::new(ObjectKey::new("bucket", object), LockType::Exclusive, "owner")
+    }
+
+    #[tokio::test]
+    async fn test_fails_only_on_configured_call() {
+        let client = faulty_local().with_fail_on_call(2);
+        let first = request("first");
+        let second = request("second");
+
+        assert!(client.acquire_lock(&first).await.unwrap().success);
+        assert!(matches!(client.acquire_lock(&second).await, Err(LockError::Internal { .. })));
+        // The failed call never reached the inner client.
+        assert!(client.check_status(&second.lock_id).await.unwrap().is_none());
+        assert!(client.release(&first.lock_id).await.unwrap());
+        assert_eq!(client.call_count(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_delays_every_call() {
+        let delay = Duration::from_millis(50);
+        let client = faulty_local().with_delay(delay);
+
+        let started = Instant::now();
+        assert!(client.acquire_lock(&request("delayed")).await.unwrap().success);
+        assert!(started.elapsed() >= delay);
+    }
+
+    #[tokio::test]
+    async fn test_offline_flag_flaps() {
+        let client = faulty_local();
+        assert!(client.is_online().await);
+
+        client.set_offline(true);
+        assert!(!client.is_online().await);
+
+        client.set_offline(false);
+        assert!(client.is_online().await);
+    }
+
+    #[tokio::test]
+    async fn test_distributed_lock_survives_one_failed_participant() {
+        let healthy: Vec<Arc<LocalClient>> = (0..2)
+            .map(|_| Arc::new(LocalClient::with_manager(Arc::new(GlobalLockManager::new()))))
+            .collect();
+        let clients = |fail_on_call: usize| -> Vec<Arc<dyn LockClient>> {
+            let faulty = FaultyClient::new(Arc::new(LocalClient::with_manager(Arc::new(GlobalLockManager::new()))))
+                .with_fail_on_call(fail_on_call);
+            vec![healthy[0].clone(), healthy[1].clone(), Arc::new(faulty)]
+        };
+        let timeout = Duration::from_millis(200);
+        let ttl = Duration::from_secs(30);
+
+        // Two of three participants still reach a quorum of two.
+        let lock = crate::distributed_lock::DistributedLock::new("test".to_string(), clients(1), 2);
+        let guard = lock
+            .lock_guard(ObjectKey::new("bucket", "quorum"), "owner", timeout, ttl)
+            .await
+            .unwrap();
+        assert!(guard.is_some());
+        drop(guard);
+
+        // Requiring all three fails, and the healthy participants are rolled back.
+        let lock = crate::distributed_lock::DistributedLock::new("test".to_string(), clients(1), 3);
+        let result = lock.lock_guard(ObjectKey::new("bucket", "all"), "owner", timeout, ttl).await;
+        assert!(matches!(
+            result,
+            Err(LockError::QuorumNotReached {
+                required: 3,
+                achieved: 2
+            })
+        ));
+        // Rollback runs in the background.
+        let rolled_back = async {
+            while healthy[0].held_count().await + healthy[1].held_count().await > 0 {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        };
+        tokio::time::timeout(Duration::from_secs(2), rolled_back)
+            .await
+            .expect("healthy participants should be rolled back");
+    }
+}
@@ -12,20 +12,35 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::hash::{Hash, Hasher};
 use std::sync::Arc;
-use std::time::{Duration, SystemTime};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::time::{Duration, Instant, SystemTime};
 use tokio::sync::RwLock;
+use tracing::Instrument;
 
+use crate::audit::{LockAudit, LockEvent, LockEventKind};
+use crate::clock::{Clock, system_clock};
+use crate::snapshot::{LockLogRecord, LockSnapshot, LockSnapshotEntry, LockStore};
 use crate::{
-    FastLockGuard, GlobalLockManager, LockClient, LockId, LockInfo, LockManager, LockMetadata, LockPriority, LockRequest,
-    LockResponse, LockStats, LockStatus, LockType, Result,
+    FastLockGuard, FastObjectLockManager, GlobalLockManager, HoldKind, LockClient, LockError, LockHolder, LockId, LockInfo,
+    LockManager, LockMetadata, LockPriority, LockRequest, LockResponse, LockStats, LockStatus, LockType, ResourceState, Result,
 };
 
 /// Default shard count for guard storage (must be power of 2)
 const DEFAULT_GUARD_SHARD_COUNT: usize = 64;
 
+/// Default cadence of [`LocalClient::spawn_checkpointer`]
+pub const DEFAULT_CHECKPOINT_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Events buffered per subscriber of [`LocalClient::subscribe_events`]
+pub const DEFAULT_EVENT_CAPACITY: usize = 1024;
+
+/// How long a restored lock may wait for its resource
+const RESTORE_ACQUIRE_TIMEOUT: Duration = Duration::from_secs(1);
+
 /// Local lock client using FastLock with sharded guard storage for better concurrency
 #[derive(Debug)]
 pub struct LocalClient {
@@ -35,90 +50,2002 @@ pub struct LocalClient {
     shard_mask: usize,
     /// Optional lock manager (if None, uses global singleton)
     manager: Option<Arc<GlobalLockManager>>,
+    /// Time source for lease expiry
+    clock: Arc<dyn Clock>,
+    /// Set by `close()`; a closed client rejects new acquisitions and refreshes
+    closed: AtomicBool,
+    /// Set by `begin_drain()`; a draining client rejects new acquisitions only
+    draining: AtomicBool,
+    /// Wakes acquisitions parked in the manager when draining begins
+    drain_signal: tokio::sync::Notify,
+    /// Acquisition outcome counters behind `contention_report()`
+    contention: ContentionCounters,
+    /// How long released and expired locks were held
+    hold_times: DurationBuckets,
+    /// How long acquisitions waited before being granted or timing out
+    wait_times: DurationBuckets,
+    /// Longest wait recorded in `wait_times`, in microseconds
+    max_wait_micros: AtomicU64,
+    /// Optional per-owner acquisition budget
+    rate_limiter: Option<OwnerRateLimiter>,
+    /// Optional cap on held locks and what to do when it is reached
+    capacity: Option<(usize, CapacityPolicy)>,
+    /// Optional cap on the summed weight of each owner's held locks
+    weight_budget: Option<u64>,
+    /// Reserved lock count and per-owner weight checked against `capacity` and `weight_budget`
+    hold_usage: Arc<HoldUsage>,
+    /// How `release_as` treats a release naming the wrong lock type
+    release_type_policy: ReleaseTypePolicy,
+    /// Checks owner-scoped operations, if set
+    owner_validator: Option<Arc<dyn OwnerValidator>>,
+    /// Receiver of lock lifecycle events, if auditing is enabled
+    audit: Option<Arc<dyn LockAudit>>,
+    /// Publishes lifecycle events to `subscribe_events()` receivers
+    events: tokio::sync::broadcast::Sender<LockEvent>,
+    /// Where `checkpoint()` persists the lock table, if anywhere
+    store: Option<Arc<dyn LockStore>>,
+    /// Cadence of the background checkpointer
+    checkpoint_interval: Duration,
+    /// Changes waiting to be logged between full checkpoints, if logging is enabled
+    checkpoint_log: Option<CheckpointLog>,
+    /// Called for each lock reclaimed after its lease expired
+    on_expire: Option<ExpireHook>,
+    /// Highest epoch seen from each owner; older epochs are rejected
+    owner_epochs: parking_lot::Mutex<HashMap<String, u64>>,
+    /// Rewrites resource names before they reach the lock table, if set
+    key_normalization: Option<KeyNormalization>,
+}
+
+#[derive(Debug, Default)]
+struct ContentionCounters {
+    acquired_shared: AtomicU64,
+    acquired_exclusive: AtomicU64,
+    rejected_shared: AtomicU64,
+    rejected_exclusive: AtomicU64,
+    rejected_by_resource: parking_lot::Mutex<HashMap<crate::ObjectKey, u64>>,
+}
+
+impl ContentionCounters {
+    fn record(&self, request: &LockRequest, acquired: bool) {
+        let counter = match (request.lock_type, acquired) {
+            (LockType::Shared, true) => &self.acquired_shared,
+            (LockType::Exclusive, true) => &self.acquired_exclusive,
+            (LockType::Shared, false) => &self.rejected_shared,
+            (LockType::Exclusive, false) => &self.rejected_exclusive,
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        if !acquired {
+            *self.rejected_by_resource.lock().entry(request.resource.clone()).or_default() += 1;
+        }
+    }
+
+    fn reset(&self) {
+        self.acquired_shared.store(0, Ordering::Relaxed);
+        self.acquired_exclusive.store(0, Ordering::Relaxed);
+        self.rejected_shared.store(0, Ordering::Relaxed);
+        self.rejected_exclusive.store(0, Ordering::Relaxed);
+        self.rejected_by_resource.lock().clear();
+    }
+}
+
+/// Upper bounds of the duration histogram buckets, Prometheus `le` style
+const DURATION_BUCKET_BOUNDS: [Duration; 8] = [
+    Duration::from_millis(1),
+    Duration::from_millis(10),
+    Duration::from_millis(100),
+    Duration::from_secs(1),
+    Duration::from_secs(10),
+    Duration::from_secs(60),
+    Duration::from_secs(300),
+    Duration::from_secs(3600),
+];
+
+/// Lock-free duration recorder behind [`DurationHistogram`]
+#[derive(Debug, Default)]
+struct DurationBuckets {
+    /// Per-bucket (non-cumulative) counts; the last slot is the `+Inf` overflow
+    counts: [AtomicU64; DURATION_BUCKET_BOUNDS.len() + 1],
+    sum_micros: AtomicU64,
+}
+
+impl DurationBuckets {
+    fn record(&self, duration: Duration) {
+        let bucket = DURATION_BUCKET_BOUNDS
+            .iter()
+            .position(|bound| duration <= *bound)
+            .unwrap_or(DURATION_BUCKET_BOUNDS.len());
+        self.counts[bucket].fetch_add(1, Ordering::Relaxed);
+        self.sum_micros
+            .fetch_add(u64::try_from(duration.as_micros()).unwrap_or(u64::MAX), Ordering::Relaxed);
+    }
+
+    fn histogram(&self) -> DurationHistogram {
+        let mut cumulative = 0;
+        let buckets = DURATION_BUCKET_BOUNDS
+            .iter()
+            .zip(&self.counts)
+            .map(|(bound, count)| {
+                cumulative += count.load(Ordering::Relaxed);
+                (*bound, cumulative)
+            })
+            .collect();
+        DurationHistogram {
+            buckets,
+            count: cumulative + self.counts[DURATION_BUCKET_BOUNDS.len()].load(Ordering::Relaxed),
+            sum: Duration::from_micros(self.sum_micros.load(Ordering::Relaxed)),
+        }
+    }
+
+    fn reset(&self) {
+        for count in &self.counts {
+            count.store(0, Ordering::Relaxed);
+        }
+        self.sum_micros.store(0, Ordering::Relaxed);
+    }
+}
+
+/// Distribution of recorded durations, laid out like a Prometheus histogram
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DurationHistogram {
+    /// Cumulative count of durations at or below each upper bound, ascending
+    pub buckets: Vec<(Duration, u64)>,
+    /// Every recorded duration, including those above the last bound (`+Inf`)
+    pub count: u64,
+    pub sum: Duration,
+}
+
+/// Acquisition wait times reported by [`LocalClient::wait_time_report`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WaitTimeReport {
+    pub histogram: DurationHistogram,
+    /// Longest single wait since the client was created or last reset
+    pub max: Duration,
+}
+
+/// Result of a non-blocking [`LocalClient::try_lock`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LockOutcome {
+    Acquired,
+    /// The resource is read-locked by this many distinct owners; only
+    /// reported for write attempts
+    BlockedByReaders(usize),
+    /// The resource is write-locked by `owner`
+    BlockedByWriter {
+        owner: String,
+    },
+    /// No holder was visible: the resource changed hands during the attempt,
+    /// or a higher-priority waiter is first in line for it
+    Queued,
+}
+
+/// Whether a lock a client believes it holds is still its own, as reported by [`LocalClient::verify_ownership`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OwnershipStatus {
+    /// Every resource is still held under the uid, with a live lease
+    Held,
+    /// The lease ran out or the lock was released, and nobody else holds it yet
+    Expired,
+    /// Another holder has the resource now, typically after the lease expired
+    HeldByOther { owner: String },
+}
+
+/// How much of a lock group [`LocalClient::refresh_group`] kept alive
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RefreshOutcome {
+    /// Every requested lock was still held and is refreshed
+    Full,
+    /// Some locks are refreshed; `lost` lists the uids no longer held
+    Partial { lost: Vec<String> },
+    /// None of the requested locks is held any more
+    Lost,
+}
+
+/// Fixes applied by [`LocalClient::repair`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RepairReport {
+    /// Entries whose guard had already been released
+    pub released: usize,
+    /// Entries the lock manager no longer records as held, e.g. after an
+    /// administrative force-unlock
+    pub stale: usize,
+}
+
+impl RepairReport {
+    pub fn total(&self) -> usize {
+        self.released + self.stale
+    }
+}
+
+/// Per-owner acquisition budget for [`LocalClient::with_rate_limit`]
+///
+/// Reads and writes draw from the same bucket: the limit guards against an
+/// owner flooding the client, which costs the same whichever mode it asks for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    /// Sustained acquisitions per second
+    pub locks_per_second: u32,
+    /// Acquisitions allowed back to back before the sustained rate applies
+    pub burst: u32,
+}
+
+/// What [`LocalClient::with_max_locks`] does with an acquisition once the table is full
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CapacityPolicy {
+    /// Fail the new acquisition with [`LockError::Capacity`]. Held locks are
+    /// never disturbed, so this is the safe default.
+    #[default]
+    Reject,
+    /// Force-release the least recently refreshed lock to make room. Its
+    /// holder silently loses mutual exclusion, so only use this where a lost
+    /// lock is cheaper than a refused one.
+    EvictOldest,
+}
+
+/// What [`LocalClient::release_as`] does when the lock is held in the other mode
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ReleaseTypePolicy {
+    /// Log the mismatch and release anyway, as a plain `release` would
+    #[default]
+    Lenient,
+    /// Fail with [`LockError::InvalidRequest`] naming the resource, and keep the lock
+    Strict,
+}
+
+/// How [`LocalClient::with_key_normalization`] rewrites resource names
+///
+/// Runs of `/` in the object name are always collapsed and a leading `/`
+/// stripped, so `a//b` and `/a/b` both lock `a/b`. A trailing `/` is kept:
+/// `a/b/` is a different object from `a/b`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct KeyNormalization {
+    /// Also lowercase the bucket and object name
+    pub fold_case: bool,
+}
+
+/// A lock reclaimed because its lease ran out, as passed to [`LocalClient::with_on_expire`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExpiredLock {
+    pub resource: crate::ObjectKey,
+    pub uid: String,
+    pub owner: String,
+    /// Time from acquisition to reclaim
+    pub age: Duration,
+}
+
+struct ExpireHook(Box<dyn Fn(&ExpiredLock) + Send + Sync>);
+
+impl std::fmt::Debug for ExpireHook {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("ExpireHook")
+    }
+}
+
+/// Decides whether a claimed owner may act on its locks, for multi-tenant isolation
+///
+/// Consulted by owner-scoped operations such as
+/// [`LocalClient::force_release_owned`] before they touch the lock table.
+pub trait OwnerValidator: Send + Sync + std::fmt::Debug {
+    /// Whether `owner` is allowed to act on its locks on `resource`
+    fn authorize(&self, owner: &str, resource: &crate::ObjectKey) -> bool;
+}
+
+/// Refresh cadence of one held lock, as reported by [`LocalClient::refresh_health`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RefreshHealth {
+    pub uid: String,
+    pub resource: crate::ObjectKey,
+    pub owner: String,
+    pub ttl: Duration,
+    /// Time between the last two refreshes (or the acquisition and the first refresh)
+    pub last_interval: Duration,
+    /// How much sooner than the TTL the last refresh landed; zero means
+    /// the lease had already run out or was about to
+    pub margin: Duration,
+}
+
+/// A lock removed by [`LocalClient::force_unlock_reporting`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForceUnlocked {
+    pub lock_id: LockId,
+    pub lock_type: LockType,
+    pub owner: String,
+    pub source: Option<String>,
+    /// Time from acquisition to removal
+    pub age: Duration,
+}
+
+/// Incremental checkpoint state behind [`LocalClient::with_checkpoint_log`]
+#[derive(Debug)]
+struct CheckpointLog {
+    /// Records the store may hold past its snapshot before the next checkpoint compacts
+    compact_after: usize,
+    /// Changes since the last checkpoint, oldest first
+    pending: parking_lot::Mutex<Vec<LockLogRecord>>,
+    /// Records appended to the store since its snapshot
+    logged: AtomicUsize,
+}
+
+/// Locks held or being acquired, as counted by [`HoldReservation`]s
+#[derive(Debug, Default)]
+struct HoldUsage {
+    locks: AtomicUsize,
+    /// Summed weight per owner, tracked only under a weight budget
+    weights: parking_lot::Mutex<HashMap<String, u64>>,
+}
+
+/// One lock's share of [`HoldUsage`], taken before acquiring and given back on drop.
+///
+/// A failed acquisition drops it straight away; a granted one moves it into
+/// the lock's entry, so whichever path removes the entry returns it.
+#[derive(Debug)]
+struct HoldReservation {
+    usage: Arc<HoldUsage>,
+    /// Owner and weight added to `HoldUsage::weights`, if any
+    weight: Option<(String, u64)>,
+}
+
+impl Drop for HoldReservation {
+    fn drop(&mut self) {
+        self.usage.locks.fetch_sub(1, Ordering::AcqRel);
+        if let Some((owner, weight)) = self.weight.take() {
+            let mut weights = self.usage.weights.lock();
+            if let Some(held) = weights.get_mut(&owner) {
+                *held = held.saturating_sub(weight);
+                if *held == 0 {
+                    weights.remove(&owner);
+                }
+            }
+        }
+    }
+}
+
+/// Owners tracked before buckets that have refilled are pruned
+const RATE_LIMIT_PRUNE_THRESHOLD: usize = 1024;
+
+#[derive(Debug, Clone, Copy)]
+struct TokenBucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+#[derive(Debug)]
+struct OwnerRateLimiter {
+    limit: RateLimit,
+    buckets: parking_lot::Mutex<HashMap<String, TokenBucket>>,
+}
+
+impl OwnerRateLimiter {
+    fn new(limit: RateLimit) -> Self {
+        Self {
+            limit,
+            buckets: parking_lot::Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn refill(&self, bucket: &mut TokenBucket, now: Instant) {
+        let elapsed = now.saturating_duration_since(bucket.updated).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * f64::from(self.limit.locks_per_second)).min(f64::from(self.limit.burst));
+        bucket.updated = now;
+    }
+
+    /// Take one token for `owner`, returning false if its bucket is empty
+    fn try_acquire(&self, owner: &str, now: Instant) -> bool {
+        let mut buckets = self.buckets.lock();
+        if buckets.len() >= RATE_LIMIT_PRUNE_THRESHOLD && !buckets.contains_key(owner) {
+            let burst = f64::from(self.limit.burst);
+            buckets.retain(|_, bucket| {
+                self.refill(bucket, now);
+                bucket.tokens < burst
+            });
+        }
+        let bucket = buckets.entry(owner.to_string()).or_insert(TokenBucket {
+            tokens: f64::from(self.limit.burst),
+            updated: now,
+        });
+        self.refill(bucket, now);
+        if bucket.tokens < 1.0 {
+            return false;
+        }
+        bucket.tokens -= 1.0;
+        true
+    }
+}
+
+/// Acquisition outcomes seen by a [`LocalClient`] since it was created or last reset
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ContentionReport {
+    pub acquired_shared: u64,
+    pub acquired_exclusive: u64,
+    pub rejected_shared: u64,
+    pub rejected_exclusive: u64,
+    /// Rejections per resource, hottest first
+    pub rejected_by_resource: Vec<(crate::ObjectKey, u64)>,
+}
+
+impl ContentionReport {
+    pub fn acquired(&self) -> u64 {
+        self.acquired_shared + self.acquired_exclusive
+    }
+
+    pub fn rejected(&self) -> u64 {
+        self.rejected_shared + self.rejected_exclusive
+    }
 }
 
-#[derive(Debug)]
-struct LocalGuardEntry {
-    guard: FastLockGuard,
-    expires_at: SystemTime,
-    ttl: Duration,
-    /// Owner recorded at acquire time; used only for reclaim diagnostics (#899).
-    owner: String,
-}
+#[derive(Debug)]
+struct LocalGuardEntry {
+    /// Shared by every uid of a shared-write group; the lock is released
+    /// when the last of them lets go
+    guard: Arc<FastLockGuard>,
+    /// Whether cooperating uids of the same owner may join this write hold
+    shared_write: bool,
+    /// When this uid took the lock, for hold-time metrics
+    acquired_at: Instant,
+    /// Wall-clock time of the same moment, for display and persistence only;
+    /// leases are tracked on `acquired_at` and `expires_at`
+    acquired_wall: SystemTime,
+    expires_at: Instant,
+    ttl: Duration,
+    /// Time granted by `extend()` on top of `ttl`, until the next refresh
+    extension: Duration,
+    /// Owner recorded at acquire time; used only for reclaim diagnostics (#899).
+    owner: String,
+    /// Request origin (`LockMetadata::client_info`), reported by holder queries
+    source: Option<String>,
+    /// Opaque caller state kept with the lock, changed only through `cas_metadata()`
+    data: Option<Vec<u8>>,
+    /// Counted against the owner's weight budget while held
+    weight: u32,
+    /// Gap between the last two lease grants, once refreshed at least once
+    last_refresh_interval: Option<Duration>,
+    /// This entry's share of the client's capacity and weight budget
+    reservation: Option<HoldReservation>,
+}
+
+/// `at` on the monotonic clock, given it reads `now`, as a wall-clock time
+fn wall_clock(at: Instant, now: Instant) -> SystemTime {
+    let wall_now = SystemTime::now();
+    if at >= now {
+        wall_now + (at - now)
+    } else {
+        wall_now - (now - at)
+    }
+}
+
+impl LocalGuardEntry {
+    fn new(guard: Arc<FastLockGuard>, ttl: Duration, owner: String, source: Option<String>, now: Instant) -> Self {
+        Self {
+            guard,
+            shared_write: false,
+            acquired_at: now,
+            acquired_wall: SystemTime::now(),
+            expires_at: now + ttl,
+            ttl,
+            extension: Duration::ZERO,
+            owner,
+            source,
+            data: None,
+            weight: 1,
+            last_refresh_interval: None,
+            reservation: None,
+        }
+    }
+
+    /// Whether the manager no longer records the hold this entry's guard still claims
+    fn is_stale(&self, manager: Option<&FastObjectLockManager>) -> bool {
+        let guard = &self.guard;
+        !guard.is_released()
+            && !guard.is_disabled()
+            && manager.is_some_and(|manager| !manager.is_held_by(guard.key(), guard.owner(), guard.mode()))
+    }
+
+    fn hold_kind(&self) -> HoldKind {
+        match (self.guard.mode(), self.shared_write) {
+            (crate::LockMode::Shared, _) => HoldKind::Read,
+            (crate::LockMode::Exclusive, true) => HoldKind::SharedWrite,
+            (crate::LockMode::Exclusive, false) => HoldKind::ExclusiveWrite,
+        }
+    }
+
+    fn lock_type(&self) -> LockType {
+        match self.guard.mode() {
+            crate::LockMode::Exclusive => LockType::Exclusive,
+            crate::LockMode::Shared => LockType::Shared,
+        }
+    }
+
+    fn is_expired(&self, now: Instant) -> bool {
+        self.expires_at <= now
+    }
+
+    fn refresh(&mut self, now: Instant) {
+        self.last_refresh_interval = Some(now.saturating_duration_since(self.last_refreshed()));
+        self.extension = Duration::ZERO;
+        self.expires_at = now + self.ttl;
+    }
+
+    /// Push expiry out to `additional` past a fresh lease taken at `now`
+    fn extend(&mut self, now: Instant, additional: Duration) {
+        self.extension = additional;
+        self.expires_at = now + self.ttl + additional;
+    }
+
+    /// When the lease was last granted, refreshed or extended
+    fn last_refreshed(&self) -> Instant {
+        self.expires_at
+            .checked_sub(self.ttl + self.extension)
+            .unwrap_or(self.expires_at)
+    }
+
+    fn snapshot_entry(&self, lock_id: &LockId, now: Instant) -> LockSnapshotEntry {
+        LockSnapshotEntry {
+            lock_id: lock_id.clone(),
+            lock_type: self.lock_type(),
+            owner: self.owner.clone(),
+            source: self.source.clone(),
+            ttl: self.ttl,
+            remaining: self.expires_at.saturating_duration_since(now),
+            age: now.saturating_duration_since(self.last_refreshed()),
+            acquired_at: Some(self.acquired_wall),
+        }
+    }
+
+    /// Status report for this entry, as returned by `check_status`
+    fn lock_info(&self, lock_id: &LockId, now: Instant) -> LockInfo {
+        LockInfo {
+            id: lock_id.clone(),
+            resource: lock_id.resource.clone(),
+            lock_type: self.lock_type(),
+            status: if self.is_expired(now) {
+                LockStatus::Expired
+            } else {
+                LockStatus::Acquired
+            },
+            owner: self.guard.owner().to_string(),
+            acquired_at: self.acquired_wall,
+            expires_at: self.wall_clock_expiry(now),
+            last_refreshed: self.wall_clock_refresh(now),
+            metadata: LockMetadata {
+                client_info: self.source.clone(),
+                ..LockMetadata::default()
+            },
+            priority: LockPriority::Normal,
+            wait_start_time: None,
+        }
+    }
+
+    /// Lease expiry translated to wall-clock time for reporting
+    fn wall_clock_expiry(&self, now: Instant) -> SystemTime {
+        wall_clock(self.expires_at, now)
+    }
+
+    /// Last refresh translated to wall-clock time for reporting
+    fn wall_clock_refresh(&self, now: Instant) -> SystemTime {
+        wall_clock(self.last_refreshed(), now)
+    }
+}
+
+impl LocalClient {
+    /// Create new local client with default shard count
+    pub fn new() -> Self {
+        Self::with_shard_count(DEFAULT_GUARD_SHARD_COUNT)
+    }
+
+    /// Create new local client with custom shard count
+    /// Shard count must be a power of 2 for efficient masking
+    pub fn with_shard_count(shard_count: usize) -> Self {
+        assert!(shard_count.is_power_of_two(), "Shard count must be power of 2");
+
+        let guard_storage: Vec<Arc<RwLock<HashMap<LockId, LocalGuardEntry>>>> =
+            (0..shard_count).map(|_| Arc::new(RwLock::new(HashMap::new()))).collect();
+
+        Self {
+            guard_storage,
+            shard_mask: shard_count - 1,
+            manager: None,
+            clock: system_clock(),
+            closed: AtomicBool::new(false),
+            draining: AtomicBool::new(false),
+            drain_signal: tokio::sync::Notify::new(),
+            contention: ContentionCounters::default(),
+            hold_times: DurationBuckets::default(),
+            wait_times: DurationBuckets::default(),
+            max_wait_micros: AtomicU64::new(0),
+            rate_limiter: None,
+            capacity: None,
+            weight_budget: None,
+            hold_usage: Arc::default(),
+            release_type_policy: ReleaseTypePolicy::Lenient,
+            owner_validator: None,
+            audit: None,
+            events: tokio::sync::broadcast::Sender::new(DEFAULT_EVENT_CAPACITY),
+            store: None,
+            checkpoint_interval: DEFAULT_CHECKPOINT_INTERVAL,
+            checkpoint_log: None,
+            on_expire: None,
+            owner_epochs: parking_lot::Mutex::new(HashMap::new()),
+            key_normalization: None,
+        }
+    }
+
+    /// Create new local client with a specific lock manager
+    /// This allows simulating multi-node environments where each node has its own lock backend
+    pub fn with_manager(manager: Arc<GlobalLockManager>) -> Self {
+        Self {
+            manager: Some(manager),
+            ..Self::with_shard_count(DEFAULT_GUARD_SHARD_COUNT)
+        }
+    }
+
+    /// Track lease expiry against `clock` instead of the system clock
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Reject acquisitions beyond `limit` per owner with [`LockError::RateLimited`]
+    pub fn with_rate_limit(mut self, limit: RateLimit) -> Self {
+        self.rate_limiter = Some(OwnerRateLimiter::new(limit));
+        self
+    }
+
+    /// Hold at most `max_locks` locks, applying `policy` to acquisitions beyond that.
+    ///
+    /// Every uid counts, including each member of a shared-write group.
+    /// Acquisitions still in flight count too: each reserves its slot before
+    /// the lock is taken and gives it back if it fails.
+    pub fn with_max_locks(mut self, max_locks: usize, policy: CapacityPolicy) -> Self {
+        self.capacity = Some((max_locks, policy));
+        self
+    }
+
+    /// Reject acquisitions that would bring an owner's held locks above
+    /// `budget` in summed weight with [`LockError::WeightExceeded`].
+    ///
+    /// Each lock weighs its request's `weight`. As with `with_max_locks`, the
+    /// weight is reserved before the lock is taken, so concurrent acquisitions
+    /// by one owner cannot overshoot the budget.
+    pub fn with_weight_budget(mut self, budget: u64) -> Self {
+        self.weight_budget = Some(budget);
+        self
+    }
+
+    /// Decide what [`Self::release_as`] does with an unlock of a read lock, or the reverse
+    pub fn with_release_type_policy(mut self, policy: ReleaseTypePolicy) -> Self {
+        self.release_type_policy = policy;
+        self
+    }
+
+    /// Refuse owner-scoped operations that `validator` does not authorize
+    /// with [`LockError::PermissionDenied`]
+    pub fn with_owner_validator(mut self, validator: Arc<dyn OwnerValidator>) -> Self {
+        self.owner_validator = Some(validator);
+        self
+    }
+
+    /// Report every acquisition, refresh and release to `audit`
+    pub fn with_audit(mut self, audit: Arc<dyn LockAudit>) -> Self {
+        self.audit = Some(audit);
+        self
+    }
+
+    /// Buffer up to `capacity` events per [`Self::subscribe_events`] receiver
+    pub fn with_event_capacity(mut self, capacity: usize) -> Self {
+        self.events = tokio::sync::broadcast::Sender::new(capacity.max(1));
+        self
+    }
+
+    /// Receive every lifecycle event from now on as it happens.
+    ///
+    /// Acquisitions, refreshes, releases, force-releases and expirations are
+    /// published as they occur, in the order this client performed them.
+    /// Sending never blocks the lock path: a receiver that falls more than
+    /// the event capacity behind gets [`RecvError::Lagged`] with the number of
+    /// events it missed, then resumes from the oldest event still buffered.
+    ///
+    /// [`RecvError::Lagged`]: tokio::sync::broadcast::error::RecvError::Lagged
+    pub fn subscribe_events(&self) -> tokio::sync::broadcast::Receiver<LockEvent> {
+        self.events.subscribe()
+    }
+
+    fn audit(&self, kind: LockEventKind, lock_id: &LockId, owner: &str, source: Option<&String>) {
+        if matches!(
+            kind,
+            LockEventKind::Unlock | LockEventKind::RUnlock | LockEventKind::ForceUnlock | LockEventKind::Expire
+        ) {
+            self.log_released(lock_id);
+        }
+        let subscribed = self.events.receiver_count() > 0;
+        if self.audit.is_none() && !subscribed {
+            return;
+        }
+        let event = LockEvent::new(kind, lock_id, owner, source.cloned());
+        if subscribed {
+            // Fails only if every receiver was dropped meanwhile.
+            let _ = self.events.send(event.clone());
+        }
+        if let Some(audit) = &self.audit {
+            audit.on_event(event);
+        }
+    }
+
+    fn audit_entry(&self, kind: LockEventKind, lock_id: &LockId, entry: &LocalGuardEntry) {
+        self.audit(kind, lock_id, &entry.owner, entry.source.as_ref());
+    }
+
+    /// Checkpoint the lock table to `store`, every `interval` once
+    /// [`Self::spawn_checkpointer`] runs
+    pub fn with_store(mut self, store: Arc<dyn LockStore>, interval: Duration) -> Self {
+        self.store = Some(store);
+        self.checkpoint_interval = interval;
+        self
+    }
+
+    /// Normalize every resource name per `normalization` before locking it.
+    ///
+    /// Normalization only makes spellings of a name conflict through clients
+    /// that apply it: every client locking the same namespace, local or
+    /// remote, must be configured with the same normalization, or two
+    /// spellings of one object can be locked at once. Lock ids returned by
+    /// this client carry the normalized name, but ids built from the
+    /// caller's original spelling are accepted too.
+    pub fn with_key_normalization(mut self, normalization: KeyNormalization) -> Self {
+        self.key_normalization = Some(normalization);
+        self
+    }
+
+    fn normalize_key<'a>(&self, resource: &'a crate::ObjectKey) -> Cow<'a, crate::ObjectKey> {
+        match self.key_normalization {
+            Some(normalization) => Cow::Owned(resource.normalized(normalization.fold_case)),
+            None => Cow::Borrowed(resource),
+        }
+    }
+
+    fn normalize_id<'a>(&self, lock_id: &'a LockId) -> Cow<'a, LockId> {
+        match self.normalize_key(&lock_id.resource) {
+            Cow::Borrowed(_) => Cow::Borrowed(lock_id),
+            Cow::Owned(resource) => Cow::Owned(LockId {
+                resource,
+                uuid: lock_id.uuid.clone(),
+            }),
+        }
+    }
+
+    fn normalize_request<'a>(&self, request: &'a LockRequest) -> Cow<'a, LockRequest> {
+        if self.key_normalization.is_none() {
+            return Cow::Borrowed(request);
+        }
+        let mut normalized = request.clone();
+        normalized.resource = self.normalize_key(&request.resource).into_owned();
+        normalized.lock_id = self.normalize_id(&request.lock_id).into_owned();
+        Cow::Owned(normalized)
+    }
+
+    /// Call `on_expire` for every lock reclaimed after its lease expired.
+    ///
+    /// Meant for reclaim logic, such as rolling back an operation whose lock
+    /// vanished, rather than auditing. It runs synchronously within the
+    /// sweep, once the lock is out of the table but before it is released in
+    /// the lock manager, so keep it short and do not block on this client.
+    pub fn with_on_expire(mut self, on_expire: impl Fn(&ExpiredLock) + Send + Sync + 'static) -> Self {
+        self.on_expire = Some(ExpireHook(Box::new(on_expire)));
+        self
+    }
+
+    /// Checkpoint by appending changes to the store's log instead of
+    /// rewriting the whole table each time.
+    ///
+    /// A checkpoint writes a full snapshot, compacting the log away, once
+    /// the log would grow past `compact_after` records; [`Self::compact`]
+    /// forces one. The store must implement [`LockStore::append`].
+    pub fn with_checkpoint_log(mut self, compact_after: usize) -> Self {
+        self.checkpoint_log = Some(CheckpointLog {
+            compact_after,
+            pending: parking_lot::Mutex::new(Vec::new()),
+            logged: AtomicUsize::new(0),
+        });
+        self
+    }
+
+    fn log_change(&self, record: impl FnOnce() -> LockLogRecord) {
+        if let Some(log) = &self.checkpoint_log {
+            log.pending.lock().push(record());
+        }
+    }
+
+    /// Log that `lock_id` left the lock table; every path removing an entry goes through here
+    fn log_released(&self, lock_id: &LockId) {
+        self.log_change(|| LockLogRecord::Released(lock_id.clone()));
+    }
+
+    /// Get the lock manager (injected manager if available, otherwise global singleton)
+    pub fn get_lock_manager(&self) -> Arc<GlobalLockManager> {
+        self.manager.clone().unwrap_or_else(crate::get_global_lock_manager)
+    }
+
+    /// Get the shard index for a given lock ID
+    fn get_shard_index(&self, lock_id: &LockId) -> usize {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        lock_id.hash(&mut hasher);
+        (hasher.finish() as usize) & self.shard_mask
+    }
+
+    /// Get the shard for a given lock ID
+    fn get_shard(&self, lock_id: &LockId) -> &Arc<RwLock<HashMap<LockId, LocalGuardEntry>>> {
+        let index = self.get_shard_index(lock_id);
+        &self.guard_storage[index]
+    }
+
+    /// Release every guard whose lease expired without being refreshed.
+    ///
+    /// Expired guards are otherwise only reclaimed when another request
+    /// contends for the same resource. Returns the number reclaimed.
+    pub async fn reclaim_expired(&self) -> usize {
+        self.reclaim_expired_guards(None).await
+    }
+
+    /// Acquisition successes and contended rejections, for alerting on hot resources.
+    ///
+    /// A rejection is an acquisition that returned `success: false`, whether
+    /// from a conflict or a timeout; calls that error out are not counted.
+    pub fn contention_report(&self) -> ContentionReport {
+        let counters = &self.contention;
+        let mut rejected_by_resource: Vec<_> = counters
+            .rejected_by_resource
+            .lock()
+            .iter()
+            .map(|(resource, count)| (resource.clone(), *count))
+            .collect();
+        rejected_by_resource.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        ContentionReport {
+            acquired_shared: counters.acquired_shared.load(Ordering::Relaxed),
+            acquired_exclusive: counters.acquired_exclusive.load(Ordering::Relaxed),
+            rejected_shared: counters.rejected_shared.load(Ordering::Relaxed),
+            rejected_exclusive: counters.rejected_exclusive.load(Ordering::Relaxed),
+            rejected_by_resource,
+        }
+    }
+
+    /// How long locks were held before being released or expiring.
+    ///
+    /// Measured from acquisition to release by the holder (including
+    /// `force_release` and [`Self::release_owner`]) or to reclaim of an
+    /// expired lease, so short transactional locks and leaked ones land in
+    /// different buckets.
+    pub fn hold_duration_histogram(&self) -> DurationHistogram {
+        self.hold_times.histogram()
+    }
+
+    /// How long acquisitions waited before they were granted or gave up.
+    ///
+    /// Covers every acquisition that reached the lock manager, whether it
+    /// succeeded or timed out; an uncontended lock records a near-zero wait.
+    /// Requests refused up front, e.g. by rate limiting or draining, are left
+    /// out. A histogram skewed towards the acquire timeout means contention
+    /// is adding latency.
+    pub fn wait_time_report(&self) -> WaitTimeReport {
+        WaitTimeReport {
+            histogram: self.wait_times.histogram(),
+            max: Duration::from_micros(self.max_wait_micros.load(Ordering::Relaxed)),
+        }
+    }
+
+    /// Zero the cumulative counters behind [`Self::contention_report`],
+    /// [`Self::hold_duration_histogram`] and [`Self::wait_time_report`].
+    ///
+    /// Starts a fresh measurement window without touching held locks.
+    /// Gauge-style values such as [`Self::held_count`] and
+    /// [`Self::count_locks_prefix`] are computed from the guard table on each
+    /// call, so there is nothing to reset for them.
+    pub fn reset_stats(&self) {
+        self.contention.reset();
+        self.hold_times.reset();
+        self.wait_times.reset();
+        self.max_wait_micros.store(0, Ordering::Relaxed);
+    }
+
+    /// Copy of every lock this client holds, with lease times relative to now
+    pub async fn snapshot(&self) -> LockSnapshot {
+        let now = self.clock.now();
+        let mut entries = Vec::new();
+        for shard in &self.guard_storage {
+            let guards = shard.read().await;
+            entries.extend(guards.iter().map(|(lock_id, entry)| entry.snapshot_entry(lock_id, now)));
+        }
+        entries.sort_by(|a, b| (&a.lock_id.resource, &a.lock_id.uuid).cmp(&(&b.lock_id.resource, &b.lock_id.uuid)));
+        LockSnapshot {
+            taken_at: SystemTime::now(),
+            entries,
+        }
+    }
+
+    /// Re-acquire the locks recorded in `snapshot`, keeping their lock ids.
+    ///
+    /// Each lease resumes with the time it had left, less the time since
+    /// the snapshot was taken; entries whose lease has run out by now are
+    /// skipped. Returns how many locks were restored.
+    pub async fn restore(&self, snapshot: &LockSnapshot) -> Result<usize> {
+        let elapsed = SystemTime::now().duration_since(snapshot.taken_at).unwrap_or(Duration::ZERO);
+        let mut restored = 0;
+        for entry in &snapshot.entries {
+            let remaining = entry.remaining.saturating_sub(elapsed);
+            if remaining.is_zero() {
+                continue;
+            }
+            let metadata = LockMetadata {
+                client_info: entry.source.clone(),
+                ..LockMetadata::default()
+            };
+            let request = LockRequest::new(entry.lock_id.resource.clone(), entry.lock_type, entry.owner.clone())
+                .with_lock_id(entry.lock_id.clone())
+                .with_ttl(remaining)
+                .with_acquire_timeout(RESTORE_ACQUIRE_TIMEOUT)
+                .with_metadata(metadata);
+            if !self.acquire_lock_untraced(&request, false).await?.success {
+                tracing::warn!(lock_id = %entry.lock_id, owner = %entry.owner, "could not restore lock from snapshot");
+                continue;
+            }
+            // The lease resumes where it left off but refreshes at the full ttl.
+            if let Some(restored_entry) = self.get_shard(&entry.lock_id).write().await.get_mut(&entry.lock_id) {
+                restored_entry.ttl = entry.ttl;
+                if let Some(acquired_at) = entry.acquired_at {
+                    restored_entry.acquired_wall = acquired_at;
+                }
+            }
+            restored += 1;
+        }
+        Ok(restored)
+    }
+
+    /// Re-acquire the locks left by replaying `log` over `snapshot`
+    pub async fn restore_from(&self, snapshot: &LockSnapshot, log: &[LockLogRecord]) -> Result<usize> {
+        self.restore(&snapshot.replay(log)).await
+    }
+
+    /// [`Self::restore_from`] a snapshot in the on-disk form written by
+    /// [`LockSnapshot::encode`], refusing it without restoring anything if
+    /// its checksum does not match
+    pub async fn restore_from_encoded(&self, encoded: &[u8], log: &[LockLogRecord]) -> Result<usize> {
+        self.restore_from(&LockSnapshot::decode(encoded)?, log).await
+    }
+
+    /// Persist the lock table to the configured store.
+    ///
+    /// With [`Self::with_checkpoint_log`] this appends the changes since the
+    /// last checkpoint, or compacts into a full snapshot once the log is long
+    /// enough; otherwise it always writes a full snapshot. Returns false
+    /// without doing anything when no store is configured.
+    pub async fn checkpoint(&self) -> Result<bool> {
+        let Some(store) = &self.store else {
+            return Ok(false);
+        };
+        if let Some(log) = &self.checkpoint_log {
+            let pending = std::mem::take(&mut *log.pending.lock());
+            if log.logged.load(Ordering::Relaxed).saturating_add(pending.len()) <= log.compact_after {
+                if pending.is_empty() {
+                    return Ok(true);
+                }
+                if let Err(err) = store.append(&pending).await {
+                    // The drained records are lost; make the next checkpoint a full snapshot.
+                    log.logged.store(usize::MAX, Ordering::Relaxed);
+                    return Err(err);
+                }
+                log.logged.fetch_add(pending.len(), Ordering::Relaxed);
+                return Ok(true);
+            }
+        }
+        self.compact().await
+    }
+
+    /// Write a full snapshot to the configured store, replacing its log.
+    ///
+    /// Returns false without doing anything when no store is configured.
+    pub async fn compact(&self) -> Result<bool> {
+        let Some(store) = &self.store else {
+            return Ok(false);
+        };
+        if let Some(log) = &self.checkpoint_log {
+            // Changes from here on are covered by the snapshot or logged after it.
+            log.pending.lock().clear();
+        }
+        let persisted = store.persist(&self.snapshot().await).await;
+        if let Some(log) = &self.checkpoint_log {
+            // After a failure the cleared changes are only recoverable by another full snapshot.
+            log.logged
+                .store(if persisted.is_ok() { 0 } else { usize::MAX }, Ordering::Relaxed);
+        }
+        persisted.map(|()| true)
+    }
+
+    /// Restore the snapshot last persisted to the configured store, and the
+    /// log appended after it, if any
+    pub async fn load_from_store(&self) -> Result<usize> {
+        let Some(store) = &self.store else {
+            return Ok(0);
+        };
+        let log = store.load_log().await?;
+        match store.load().await? {
+            Some(snapshot) => self.restore_from(&snapshot, &log).await,
+            None if !log.is_empty() => {
+                // Date the empty base at the oldest logged grant, so every lease ages from its own.
+                let taken_at = log
+                    .iter()
+                    .filter_map(|record| match record {
+                        LockLogRecord::Acquired(entry) => entry.acquired_at,
+                        LockLogRecord::Released(_) => None,
+                    })
+                    .min()
+                    .unwrap_or_else(SystemTime::now);
+                let empty = LockSnapshot {
+                    taken_at,
+                    entries: Vec::new(),
+                };
+                self.restore_from(&empty, &log).await
+            }
+            None => Ok(0),
+        }
+    }
+
+    /// Checkpoint to the configured store on its cadence in the background.
+    ///
+    /// The task stops once the client is closed or dropped. Returns `None`
+    /// when no store is configured.
+    pub fn spawn_checkpointer(self: &Arc<Self>) -> Option<tokio::task::JoinHandle<()>> {
+        self.store.as_ref()?;
+        let client = Arc::downgrade(self);
+        let interval = self.checkpoint_interval;
+        Some(tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(interval).await;
+                let Some(client) = client.upgrade() else {
+                    return;
+                };
+                if client.is_closed() {
+                    return;
+                }
+                if let Err(err) = client.checkpoint().await {
+                    tracing::warn!(error = %err, "lock table checkpoint failed");
+                }
+            }
+        }))
+    }
+
+    /// Take a cooperative write lock that other uids of the same owner can share.
+    ///
+    /// Succeeds if `request.resource` is free, or if `request.owner` already
+    /// holds it in shared-write mode through this client, in which case the
+    /// new uid joins that hold. Readers, other owners and ordinary exclusive
+    /// writers are kept out as with any write lock. Each uid is released,
+    /// refreshed and expires on its own; the resource is freed once the last
+    /// member of the group lets go. `request.lock_type` must be exclusive.
+    pub async fn acquire_shared_write(&self, request: &LockRequest) -> Result<LockResponse> {
+        if request.lock_type != LockType::Exclusive {
+            return Err(LockError::invalid_request("shared-write locks must be requested as exclusive"));
+        }
+        self.acquire_traced(request, true).await
+    }
+
+    /// Take a write lock unless its owner already holds one on the resource under `expected_uid`.
+    ///
+    /// Lets a caller re-issue a lock request after a lost response without
+    /// failing on its own lock: if the resource is write-locked through this
+    /// client by `request.owner` under `expected_uid` with an unexpired
+    /// lease, this succeeds without touching that lock. Otherwise `request`
+    /// is acquired as usual and the result reports whether it was granted.
+    /// `request.lock_type` must be exclusive.
+    pub async fn acquire_lock_if_not_held_by(&self, request: &LockRequest, expected_uid: &str) -> Result<bool> {
+        if request.lock_type != LockType::Exclusive {
+            return Err(LockError::invalid_request("conditional locks must be requested as exclusive"));
+        }
+        if self.is_closed() {
+            return Err(LockError::client_closed());
+        }
+        let request = &*self.normalize_request(request);
+        let held = LockId {
+            resource: request.resource.clone(),
+            uuid: expected_uid.to_string(),
+        };
+        let now = self.clock.now();
+        let already_held = self.get_shard(&held).read().await.get(&held).is_some_and(|entry| {
+            entry.owner == request.owner && entry.lock_type() == LockType::Exclusive && !entry.is_expired(now)
+        });
+        if already_held {
+            return Ok(true);
+        }
+        Ok(self.acquire_traced(request, false).await?.success)
+    }
+
+    /// Try once for a write lock and, if it is not free, say who is in the way.
+    ///
+    /// Unlike [`LockClient::acquire_lock`], which reports every conflict as
+    /// `success: false`, this tells readers apart from a writer so callers
+    /// can pick a retry strategy: readers tend to clear quickly, a writer
+    /// may not. `request.acquire_timeout` is ignored; the attempt never
+    /// waits. `request.lock_type` must be exclusive.
+    pub async fn try_lock(&self, request: &LockRequest) -> Result<LockOutcome> {
+        if request.lock_type != LockType::Exclusive {
+            return Err(LockError::invalid_request("try_lock takes an exclusive request"));
+        }
+        self.try_acquire_classified(request).await
+    }
+
+    /// Try once for a read lock and, if it is not free, say who is in the way.
+    ///
+    /// The read counterpart of [`Self::try_lock`]. Reads only conflict with
+    /// writers, so the outcome is `Acquired`, `BlockedByWriter` naming the
+    /// holder, or `Queued`. `request.lock_type` must be shared.
+    pub async fn try_rlock(&self, request: &LockRequest) -> Result<LockOutcome> {
+        if request.lock_type != LockType::Shared {
+            return Err(LockError::invalid_request("try_rlock takes a shared request"));
+        }
+        self.try_acquire_classified(request).await
+    }
+
+    /// Release `lock_id`, which the caller believes is held as `lock_type`.
+    ///
+    /// Lets unlock and runlock paths say which they meant. When the lock is
+    /// held in the other mode, [`ReleaseTypePolicy::Lenient`] releases it
+    /// anyway with a warning, while [`ReleaseTypePolicy::Strict`] fails and
+    /// keeps it, catching code that calls unlock where it meant runlock.
+    /// Returns `false` if the lock is not held.
+    pub async fn release_as(&self, lock_id: &LockId, lock_type: LockType) -> Result<bool> {
+        let lock_id = &*self.normalize_id(lock_id);
+        let held_type = self
+            .get_shard(lock_id)
+            .read()
+            .await
+            .get(lock_id)
+            .map(LocalGuardEntry::lock_type);
+        if let Some(held_type) = held_type
+            && held_type != lock_type
+        {
+            if self.release_type_policy == ReleaseTypePolicy::Strict {
+                return Err(LockError::invalid_request(format!(
+                    "{} is held {held_type:?}, not {lock_type:?}",
+                    lock_id.resource
+                )));
+            }
+            tracing::warn!(lock_uid = %lock_id.uuid, resource = %lock_id.resource, held = ?held_type, requested = ?lock_type, "releasing a lock held in a different mode than requested");
+        }
+        self.release(lock_id).await
+    }
+
+    /// Release `lock_id` on behalf of its owner at `epoch`.
+    ///
+    /// Fails with [`LockError::StaleEpoch`] and keeps the lock when the owner
+    /// has since been seen at a newer epoch, so a node cut off by a partition
+    /// cannot release a lock taken over by its successor. Epoch 0 skips the check.
+    pub async fn release_with_epoch(&self, lock_id: &LockId, epoch: u64) -> Result<bool> {
+        let lock_id = &*self.normalize_id(lock_id);
+        let owner = match self.get_shard(lock_id).read().await.get(lock_id) {
+            Some(entry) => entry.owner.clone(),
+            None => return Ok(false),
+        };
+        self.observe_epoch(&owner, epoch)?;
+        self.release(lock_id).await
+    }
+
+    /// Force-release `lock_id` only if `owner` holds it.
+    ///
+    /// The strict counterpart of [`LockClient::force_release`] for operators
+    /// acting on behalf of one tenant: a lock held by anyone else is left in
+    /// place and reported as `false`, as is a lock that is not held at all.
+    /// With an [`OwnerValidator`] configured, an owner it does not authorize
+    /// for the resource gets [`LockError::PermissionDenied`] instead.
+    pub async fn force_release_owned(&self, lock_id: &LockId, owner: &str) -> Result<bool> {
+        let lock_id = &*self.normalize_id(lock_id);
+        self.authorize_owner(owner, &lock_id.resource)?;
+        let removed = {
+            let mut guards = self.get_shard(lock_id).write().await;
+            match guards.get(lock_id) {
+                Some(entry) if entry.owner == owner => guards.remove(lock_id),
+                Some(entry) => {
+                    tracing::warn!(
+                        lock_uid = %lock_id.uuid,
+                        resource = %lock_id.resource,
+                        owner,
+                        holder = %entry.owner,
+                        "refusing owner-scoped force-release of another owner's lock"
+                    );
+                    None
+                }
+                None => None,
+            }
+        };
+        let Some(entry) = removed else {
+            return Ok(false);
+        };
+        self.audit_entry(LockEventKind::ForceUnlock, lock_id, &entry);
+        self.record_hold(&entry);
+        drop(entry.guard);
+        Ok(true)
+    }
+
+    /// Replace the opaque metadata stored with `lock_id` if it still equals `expected`.
+    ///
+    /// Lets a holder keep small state, such as a progress cursor, under the
+    /// lock's protection. `expected` is `None` for a lock with no metadata
+    /// yet. Returns `false` without changing anything when the value differs
+    /// or the lock is no longer held under this uid with a live lease.
+    pub async fn cas_metadata(&self, lock_id: &LockId, expected: Option<&[u8]>, new: Vec<u8>) -> Result<bool> {
+        if self.is_closed() {
+            return Err(LockError::client_closed());
+        }
+        let lock_id = &*self.normalize_id(lock_id);
+        let now = self.clock.now();
+        let mut guards = self.get_shard(lock_id).write().await;
+        match guards.get_mut(lock_id) {
+            Some(entry) if !entry.is_expired(now) && entry.data.as_deref() == expected => {
+                entry.data = Some(new);
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    /// Opaque metadata last stored with `lock_id` by [`Self::cas_metadata`]
+    pub async fn lock_metadata(&self, lock_id: &LockId) -> Option<Vec<u8>> {
+        let lock_id = &*self.normalize_id(lock_id);
+        self.get_shard(lock_id)
+            .read()
+            .await
+            .get(lock_id)
+            .and_then(|entry| entry.data.clone())
+    }
+
+    /// Whether `close()` has been called on this client
+    pub fn is_closed(&self) -> bool {
+        self.closed.load(Ordering::Acquire)
+    }
+
+    /// Stop taking new locks ahead of a shutdown, without dropping held ones.
+    ///
+    /// New acquisitions fail with [`LockError::Draining`], and acquisitions
+    /// already blocked waiting on a held object return that error at once
+    /// instead of waiting out their timeout. Holders can still refresh and
+    /// release, so in-flight work finishes before `close()`.
+    pub fn begin_drain(&self) {
+        self.draining.store(true, Ordering::Release);
+        self.drain_signal.notify_waiters();
+    }
+
+    /// Hand every held lock off for another node to take over.
+    ///
+    /// Begins draining, so no new lock is granted, then empties the lock
+    /// table shard by shard and returns what was in it with each lease's
+    /// remaining time. Holds are released here as they are taken out;
+    /// restoring the snapshot on the replacement (see [`Self::restore`])
+    /// re-acquires them there with the same lock ids.
+    pub async fn drain_into(&self) -> LockSnapshot {
+        self.begin_drain();
+        let now = self.clock.now();
+        let mut entries = Vec::new();
+        for shard in &self.guard_storage {
+            let drained = std::mem::take(&mut *shard.write().await);
+            for (lock_id, entry) in drained {
+                entries.push(entry.snapshot_entry(&lock_id, now));
+                self.audit_entry(LockEventKind::ForceUnlock, &lock_id, &entry);
+                self.record_hold(&entry);
+                drop(entry.guard);
+            }
+        }
+        entries.sort_by(|a, b| (&a.lock_id.resource, &a.lock_id.uuid).cmp(&(&b.lock_id.resource, &b.lock_id.uuid)));
+        tracing::info!(locks = entries.len(), "drained lock table for handoff");
+        LockSnapshot {
+            taken_at: SystemTime::now(),
+            entries,
+        }
+    }
+
+    /// Whether `begin_drain()` has been called on this client
+    pub fn is_draining(&self) -> bool {
+        self.draining.load(Ordering::Acquire)
+    }
+
+    /// Number of guards this client currently holds
+    pub async fn held_count(&self) -> usize {
+        let mut count = 0usize;
+        for shard in &self.guard_storage {
+            count += shard.read().await.len();
+        }
+        count
+    }
+
+    /// Rough heap footprint of the lock table, in bytes.
+    ///
+    /// An estimate for capacity planning, not an exact measurement: counts
+    /// each entry's inline size plus the strings and data it owns, and the
+    /// shared guard, but not map overhead or allocator slack. Useful for
+    /// relating lock counts to memory when tuning [`Self::with_max_locks`].
+    pub async fn estimated_memory_bytes(&self) -> usize {
+        let per_entry = std::mem::size_of::<(LockId, LocalGuardEntry)>() + std::mem::size_of::<FastLockGuard>();
+        let mut bytes = 0usize;
+        for shard in &self.guard_storage {
+            for (lock_id, entry) in shard.read().await.iter() {
+                let resource = &lock_id.resource;
+                bytes += per_entry
+                    + resource.bucket.len()
+                    + resource.object.len()
+                    + resource.version.as_deref().map_or(0, str::len)
+                    + lock_id.uuid.len()
+                    + entry.owner.len()
+                    + entry.source.as_deref().map_or(0, str::len)
+                    + entry.data.as_deref().map_or(0, <[u8]>::len);
+            }
+        }
+        bytes
+    }
+
+    /// Number of held locks per requesting source, for finding the code path
+    /// behind a pile-up of locks.
+    ///
+    /// The source is the `client_info` of the request's metadata. Locks
+    /// acquired without one are not counted.
+    pub async fn locks_by_source(&self) -> HashMap<String, usize> {
+        let mut by_source = HashMap::new();
+        for shard in &self.guard_storage {
+            for entry in shard.read().await.values() {
+                if let Some(source) = &entry.source {
+                    *by_source.entry(source.clone()).or_default() += 1;
+                }
+            }
+        }
+        by_source
+    }
+
+    /// Summed weight of the locks each owner holds through this client
+    pub async fn owner_weights(&self) -> HashMap<String, u64> {
+        let mut by_owner = HashMap::new();
+        for shard in &self.guard_storage {
+            for entry in shard.read().await.values() {
+                *by_owner.entry(entry.owner.clone()).or_default() += u64::from(entry.weight);
+            }
+        }
+        by_owner
+    }
+
+    /// Force-release this client's locks on `resource` not refreshed within `age`.
+    ///
+    /// Unlike `force_release`, holders that are still being refreshed are left
+    /// in place, so operators can clear stale locks without disturbing live
+    /// ones. Returns the number of locks released.
+    pub async fn force_unlock_older_than(&self, resource: &crate::ObjectKey, age: Duration) -> usize {
+        let resource = &*self.normalize_key(resource);
+        let now = self.clock.now();
+        let stale = self
+            .remove_guards_where(|lock_id, entry| {
+                &lock_id.resource == resource && now.saturating_duration_since(entry.last_refreshed()) > age
+            })
+            .await;
+
+        let released = stale.len();
+        for (lock_id, entry) in stale {
+            self.audit_entry(LockEventKind::ForceUnlock, &lock_id, &entry);
+            self.record_hold(&entry);
+            tracing::info!(
+                owner = %entry.owner,
+                resource = %resource,
+                age_ms = now.saturating_duration_since(entry.last_refreshed()).as_millis() as u64,
+                "force-releasing stale lock guard"
+            );
+            drop(entry.guard);
+        }
+        released
+    }
+
+    /// Force-release locks on any resource not refreshed within the age for their kind.
+    ///
+    /// Read locks go stale after `read_age` and write locks, including
+    /// shared-write groups, after `write_age`, so short-lived readers can be
+    /// reaped aggressively without cutting long writes short. Returns the
+    /// number of locks released.
+    pub async fn expire_older_than(&self, read_age: Duration, write_age: Duration) -> usize {
+        let now = self.clock.now();
+        let stale = self
+            .remove_guards_where(|_, entry| {
+                let age = match entry.lock_type() {
+                    LockType::Shared => read_age,
+                    LockType::Exclusive => write_age,
+                };
+                now.saturating_duration_since(entry.last_refreshed()) > age
+            })
+            .await;
+
+        let released = stale.len();
+        for (lock_id, entry) in stale {
+            self.audit_entry(LockEventKind::ForceUnlock, &lock_id, &entry);
+            self.record_hold(&entry);
+            drop(entry.guard);
+        }
+        if released > 0 {
+            tracing::info!(released, "force-released stale lock guards");
+        }
+        released
+    }
+
+    /// Force-release every lock this client holds on `resource`, describing each one removed.
+    ///
+    /// Gives the operator clearing a stuck resource an immediate record of
+    /// what it broke, ordered by uid; the audit hook still sees a
+    /// force-unlock event per lock.
+    pub async fn force_unlock_reporting(&self, resource: &crate::ObjectKey) -> Vec<ForceUnlocked> {
+        let resource = &*self.normalize_key(resource);
+        let now = self.clock.now();
+        let removed = self.remove_guards_where(|lock_id, _| &lock_id.resource == resource).await;
+        let mut report = Vec::with_capacity(removed.len());
+        for (lock_id, entry) in removed {
+            self.audit_entry(LockEventKind::ForceUnlock, &lock_id, &entry);
+            self.record_hold(&entry);
+            report.push(ForceUnlocked {
+                lock_type: entry.lock_type(),
+                owner: entry.owner.clone(),
+                source: entry.source.clone(),
+                age: now.saturating_duration_since(entry.acquired_at),
+                lock_id,
+            });
+            drop(entry.guard);
+        }
+        if !report.is_empty() {
+            tracing::info!(resource = %resource, released = report.len(), "force-released lock guards on resource");
+        }
+        report.sort_by(|a, b| a.lock_id.uuid.cmp(&b.lock_id.uuid));
+        report
+    }
+
+    /// Force-release every lock held under any of `uids`, on any resource,
+    /// in a single pass over the lock table.
+    ///
+    /// With `owner` set, locks of other owners are left alone even if their
+    /// uid matches. Returns the number of locks released.
+    pub async fn force_unlock_uids(&self, uids: &[String], owner: Option<&str>) -> usize {
+        let wanted: std::collections::HashSet<&str> = uids.iter().map(String::as_str).collect();
+        let removed = self
+            .remove_guards_where(|lock_id, entry| {
+                wanted.contains(lock_id.uuid.as_str()) && owner.is_none_or(|owner| entry.owner == owner)
+            })
+            .await;
+        let released = removed.len();
+        for (lock_id, entry) in removed {
+            self.audit_entry(LockEventKind::ForceUnlock, &lock_id, &entry);
+            self.record_hold(&entry);
+            drop(entry.guard);
+        }
+        if released > 0 {
+            tracing::info!(uids = uids.len(), released, "force-released lock guards by uid");
+        }
+        released
+    }
+
+    /// Refresh several of `owner`'s locks in one call, by lock uid.
+    ///
+    /// Returns the uids that were still held and are now refreshed, in the
+    /// order given. Unknown uids, and uids held by a different owner, are
+    /// left out rather than treated as errors. Entries whose hold was
+    /// force-released in the manager are dropped instead of refreshed.
+    pub async fn refresh_owner(&self, owner: &str, uids: &[String]) -> Result<Vec<String>> {
+        if self.is_closed() {
+            return Err(LockError::client_closed());
+        }
+        let manager = self.get_lock_manager().as_fast_lock_manager();
+        let wanted: std::collections::HashSet<&str> = uids.iter().map(String::as_str).collect();
+        let now = self.clock.now();
+        let mut refreshed = std::collections::HashSet::new();
+        for shard in &self.guard_storage {
+            let stale: Vec<_> = {
+                let mut guards = shard.write().await;
+                let stale: Vec<_> = guards
+                    .extract_if(|lock_id, entry| {
+                        entry.owner == owner && wanted.contains(lock_id.uuid.as_str()) && entry.is_stale(manager.as_deref())
+                    })
+                    .collect();
+                for (lock_id, _) in &stale {
+                    self.log_released(lock_id);
+                }
+                for (lock_id, entry) in guards.iter_mut() {
+                    if entry.owner == owner && wanted.contains(lock_id.uuid.as_str()) {
+                        entry.refresh(now);
+                        self.audit_entry(LockEventKind::Refresh, lock_id, entry);
+                        refreshed.insert(lock_id.uuid.clone());
+                    }
+                }
+                stale
+            };
+            drop(stale);
+        }
+        Ok(uids.iter().filter(|uid| refreshed.contains(*uid)).cloned().collect())
+    }
+
+    /// Extend the lease on `lock_id` by `additional` beyond its usual TTL.
+    ///
+    /// The new expiry is `now + ttl + additional`, so the lock survives
+    /// [`Self::reclaim_expired`] sweeps until then even if it is not refreshed.
+    /// The extension is one-off: the next [`LockClient::refresh`] resets the
+    /// lease to the lock's own TTL, and a later `extend` replaces rather than
+    /// adds to an earlier one. Returns `false` if the lock is not held, or
+    /// was force-released in the manager.
+    pub async fn extend(&self, lock_id: &LockId, additional: Duration) -> Result<bool> {
+        if self.is_closed() {
+            return Err(LockError::client_closed());
+        }
+        let lock_id = &*self.normalize_id(lock_id);
+        let mut guards = self.get_shard(lock_id).write().await;
+        let manager = self.get_lock_manager().as_fast_lock_manager();
+        if guards.get(lock_id).is_some_and(|entry| entry.is_stale(manager.as_deref())) {
+            let stale = guards.remove(lock_id);
+            drop(guards);
+            self.log_released(lock_id);
+            drop(stale);
+            return Ok(false);
+        }
+        let Some(entry) = guards.get_mut(lock_id) else {
+            return Ok(false);
+        };
+        entry.extend(self.clock.now(), additional);
+        self.audit_entry(LockEventKind::Refresh, lock_id, entry);
+        Ok(true)
+    }
+
+    /// Refresh a group of `owner`'s locks and report whether all of them survived.
+    ///
+    /// Like [`Self::refresh_owner`], but tells the caller outright when the
+    /// group is only partly held, e.g. because one resource was force-unlocked,
+    /// so it can stop treating the group as locked.
+    pub async fn refresh_group(&self, owner: &str, uids: &[String]) -> Result<RefreshOutcome> {
+        let refreshed = self.refresh_owner(owner, uids).await?;
+        Ok(if refreshed.len() == uids.len() {
+            RefreshOutcome::Full
+        } else if refreshed.is_empty() {
+            RefreshOutcome::Lost
+        } else {
+            let lost = uids.iter().filter(|uid| !refreshed.contains(uid)).cloned().collect();
+            RefreshOutcome::Partial { lost }
+        })
+    }
 
-impl LocalGuardEntry {
-    fn new(guard: FastLockGuard, ttl: Duration, owner: String) -> Self {
-        let now = SystemTime::now();
-        Self {
-            guard,
-            expires_at: now + ttl,
-            ttl,
-            owner,
+    /// Refresh the locks held under `uid` on each of `resources`, counting survivors.
+    ///
+    /// Returns `(refreshed, expected)`: unlike [`LockClient::refresh`]'s single
+    /// flag, this tells a group holder that some of its locks expired or were
+    /// force-released while others were refreshed, so it can abort.
+    pub async fn refresh_count(&self, uid: &str, resources: &[crate::ObjectKey]) -> Result<(usize, usize)> {
+        let mut refreshed = 0;
+        for resource in resources {
+            let lock_id = LockId {
+                resource: resource.clone(),
+                uuid: uid.to_string(),
+            };
+            if self.refresh(&lock_id).await? {
+                refreshed += 1;
+            }
         }
+        Ok((refreshed, resources.len()))
     }
 
-    fn is_expired(&self) -> bool {
-        self.expires_at <= SystemTime::now()
+    /// Write-lock each of `resources` independently, keeping whichever are free.
+    ///
+    /// Unlike a lock group this is not atomic: every resource gets its own
+    /// exclusive lock built from `request` (owner, uid, TTL and acquire
+    /// timeout; its resource and lock type are ignored), and a resource that
+    /// cannot be locked is reported as `false` and skipped. Acquired locks
+    /// share `request.lock_id.uuid`, so [`Self::release_group`] can release
+    /// them together. An error such as a closed client stops the attempt
+    /// and leaves the locks already taken held.
+    pub async fn lock_best_effort(
+        &self,
+        request: &LockRequest,
+        resources: &[crate::ObjectKey],
+    ) -> Result<Vec<(crate::ObjectKey, bool)>> {
+        let mut outcomes = Vec::with_capacity(resources.len());
+        for resource in resources {
+            let mut single = request.clone();
+            single.resource = resource.clone();
+            single.lock_type = LockType::Exclusive;
+            single.lock_id = LockId {
+                resource: resource.clone(),
+                uuid: request.lock_id.uuid.clone(),
+            };
+            let acquired = self.acquire_lock(&single).await?.success;
+            outcomes.push((resource.clone(), acquired));
+        }
+        Ok(outcomes)
     }
 
-    fn refresh(&mut self) {
-        self.expires_at = SystemTime::now() + self.ttl;
+    /// Release a lock group: every lock held under `uid`, across resources.
+    ///
+    /// A group is the set of locks acquired with one lock uid on several
+    /// resources. `resources` must name exactly the resources the group
+    /// holds; naming a subset (or an extra resource) fails with
+    /// [`LockError::InvalidRequest`] and releases nothing, so a group is never
+    /// left partly held. Returns `false` if no lock is held under `uid`.
+    pub async fn release_group(&self, uid: &str, resources: &[crate::ObjectKey]) -> Result<bool> {
+        let named: std::collections::BTreeSet<_> = resources.iter().map(|resource| self.normalize_key(resource)).collect();
+        // Shards are locked in index order, so this cannot deadlock with
+        // another group release, and single-shard paths never wait on it.
+        let mut shards = Vec::with_capacity(self.guard_storage.len());
+        for shard in &self.guard_storage {
+            shards.push(shard.write().await);
+        }
+        let held: std::collections::BTreeSet<_> = shards
+            .iter()
+            .flat_map(|guards| guards.keys())
+            .filter(|lock_id| lock_id.uuid == uid)
+            .map(|lock_id| Cow::Borrowed(&lock_id.resource))
+            .collect();
+        if held.is_empty() {
+            return Ok(false);
+        }
+        if held != named {
+            return Err(LockError::invalid_request(format!(
+                "unlock of group {uid} must name exactly its {} resources",
+                held.len()
+            )));
+        }
+        let released: Vec<_> = shards
+            .iter_mut()
+            .flat_map(|guards| guards.extract_if(|lock_id, _| lock_id.uuid == uid).collect::<Vec<_>>())
+            .collect();
+        drop(shards);
+        for (lock_id, entry) in released {
+            self.audit_entry(LockEventKind::released(entry.lock_type()), &lock_id, &entry);
+            self.record_hold(&entry);
+            drop(entry.guard);
+        }
+        Ok(true)
     }
-}
 
-impl LocalClient {
-    /// Create new local client with default shard count
-    pub fn new() -> Self {
-        Self::with_shard_count(DEFAULT_GUARD_SHARD_COUNT)
+    /// Release every lock this client holds for `owner`, on any resource.
+    ///
+    /// Used when evicting a tenant or a dead coordinator. Returns the number
+    /// of locks released.
+    pub async fn release_owner(&self, owner: &str) -> usize {
+        let released = self.remove_guards_where(|_, entry| entry.owner == owner).await;
+        for (lock_id, entry) in &released {
+            self.audit_entry(LockEventKind::released(entry.lock_type()), lock_id, entry);
+            self.record_hold(entry);
+        }
+        released.len()
     }
 
-    /// Create new local client with custom shard count
-    /// Shard count must be a power of 2 for efficient masking
-    pub fn with_shard_count(shard_count: usize) -> Self {
-        assert!(shard_count.is_power_of_two(), "Shard count must be power of 2");
+    /// Force-release every lock this client holds under a `bucket/object` path prefix.
+    ///
+    /// This is an admin operation: lock id and owner are ignored, so any
+    /// request holding a matching resource loses its lock. Returns the number
+    /// of locks released.
+    pub async fn force_unlock_prefix(&self, prefix: &str) -> usize {
+        let released = self
+            .remove_guards_where(|lock_id, _| lock_id.resource.has_path_prefix(prefix))
+            .await;
+        for (lock_id, entry) in &released {
+            self.audit_entry(LockEventKind::ForceUnlock, lock_id, entry);
+            self.record_hold(entry);
+        }
+        if !released.is_empty() {
+            tracing::info!(prefix, released = released.len(), "force-released lock guards under prefix");
+        }
+        released.len()
+    }
 
-        let guard_storage: Vec<Arc<RwLock<HashMap<LockId, LocalGuardEntry>>>> =
-            (0..shard_count).map(|_| Arc::new(RwLock::new(HashMap::new()))).collect();
+    /// Count this client's locks under a `bucket/object` path prefix.
+    ///
+    /// Fills `total_locks`, `locked_resources`, `exclusive_locks`,
+    /// `shared_locks` and `held_weight`; leases are not touched, so dashboards can poll it
+    /// freely.
+    pub async fn count_locks_prefix(&self, prefix: &str) -> LockStats {
+        let mut stats = LockStats::default();
+        let mut resources = std::collections::HashSet::new();
+        for shard in &self.guard_storage {
+            let guards = shard.read().await;
+            for (lock_id, entry) in guards.iter() {
+                if !lock_id.resource.has_path_prefix(prefix) {
+                    continue;
+                }
+                resources.insert(lock_id.resource.clone());
+                stats.held_weight += u64::from(entry.weight);
+                // Each uid of a shared-write group counts as a write lock.
+                match entry.hold_kind() {
+                    HoldKind::SharedWrite | HoldKind::ExclusiveWrite => stats.exclusive_locks += 1,
+                    HoldKind::Read => stats.shared_locks += 1,
+                }
+            }
+        }
+        stats.total_locks = stats.exclusive_locks + stats.shared_locks;
+        stats.locked_resources = resources.len();
+        stats
+    }
 
-        Self {
-            guard_storage,
-            shard_mask: shard_count - 1,
-            manager: None,
+    /// Remove every tracked guard matching `matches` and hand them back.
+    ///
+    /// Guards are returned rather than dropped so their locks are released
+    /// after the shard locks are let go.
+    async fn remove_guards_where<F>(&self, matches: F) -> Vec<(LockId, LocalGuardEntry)>
+    where
+        F: Fn(&LockId, &LocalGuardEntry) -> bool,
+    {
+        let mut removed = Vec::new();
+        for shard in &self.guard_storage {
+            let mut guards = shard.write().await;
+            let ids: Vec<LockId> = guards
+                .iter()
+                .filter(|(lock_id, entry)| matches(lock_id, entry))
+                .map(|(lock_id, _)| lock_id.clone())
+                .collect();
+            removed.extend(
+                ids.into_iter()
+                    .filter_map(|lock_id| guards.remove(&lock_id).map(|entry| (lock_id, entry))),
+            );
         }
+        removed
     }
 
-    /// Create new local client with a specific lock manager
-    /// This allows simulating multi-node environments where each node has its own lock backend
-    pub fn with_manager(manager: Arc<GlobalLockManager>) -> Self {
-        Self {
-            guard_storage: (0..DEFAULT_GUARD_SHARD_COUNT)
-                .map(|_| Arc::new(RwLock::new(HashMap::new())))
-                .collect(),
-            shard_mask: DEFAULT_GUARD_SHARD_COUNT - 1,
-            manager: Some(manager),
+    /// Drop guard entries that no longer match the lock manager.
+    ///
+    /// Admin paths such as `force_unlock` release locks in the manager
+    /// without going through this client, leaving entries that still report
+    /// the resource as held. Repair scans the guard table and removes every
+    /// entry whose guard was released or whose hold the manager no longer
+    /// records. The reverse direction cannot be repaired here: a hold the
+    /// manager knows about but no entry tracks has no guard to rebuild from.
+    pub async fn repair(&self) -> RepairReport {
+        let manager = self.get_lock_manager().as_fast_lock_manager();
+        let mut report = RepairReport::default();
+        for shard in &self.guard_storage {
+            let removed: Vec<_> = shard
+                .write()
+                .await
+                .extract_if(|_, entry| {
+                    if entry.guard.is_released() {
+                        report.released += 1;
+                        true
+                    } else if entry.is_stale(manager.as_deref()) {
+                        report.stale += 1;
+                        true
+                    } else {
+                        false
+                    }
+                })
+                .collect();
+            for (lock_id, _) in &removed {
+                self.log_released(lock_id);
+            }
+            // Drop outside the shard lock, as in `close`.
+            drop(removed);
+        }
+        if report.total() > 0 {
+            tracing::warn!(released = report.released, stale = report.stale, "repaired local lock guard table");
         }
+        report
     }
 
-    /// Get the lock manager (injected manager if available, otherwise global singleton)
-    pub fn get_lock_manager(&self) -> Arc<GlobalLockManager> {
-        self.manager.clone().unwrap_or_else(crate::get_global_lock_manager)
+    /// Whether this client currently holds any lock on `resource`.
+    ///
+    /// Read-only: it neither refreshes leases nor reclaims expired ones.
+    pub async fn is_locked(&self, resource: &crate::ObjectKey) -> bool {
+        let resource = &*self.normalize_key(resource);
+        for shard in &self.guard_storage {
+            if shard.read().await.keys().any(|lock_id| &lock_id.resource == resource) {
+                return true;
+            }
+        }
+        false
     }
 
-    /// Get the shard index for a given lock ID
-    fn get_shard_index(&self, lock_id: &LockId) -> usize {
-        let mut hasher = std::collections::hash_map::DefaultHasher::new();
-        lock_id.hash(&mut hasher);
-        (hasher.finish() as usize) & self.shard_mask
+    /// Whether `owner` still holds `uid` on every one of `resources`.
+    ///
+    /// Lets a client that may have stalled past its lease check before doing
+    /// damage. `HeldByOther` is reported if any resource has passed to
+    /// another holder, otherwise `Expired` if any is no longer held under a
+    /// live lease. Read-only; nothing is refreshed or reclaimed.
+    pub async fn verify_ownership(&self, owner: &str, uid: &str, resources: &[crate::ObjectKey]) -> OwnershipStatus {
+        let now = self.clock.now();
+        let mut status = OwnershipStatus::Held;
+        for resource in resources {
+            let lock_id = LockId {
+                resource: self.normalize_key(resource).into_owned(),
+                uuid: uid.to_string(),
+            };
+            let held = self.get_shard(&lock_id).read().await.get(&lock_id).map(|entry| {
+                if entry.owner != owner {
+                    OwnershipStatus::HeldByOther {
+                        owner: entry.owner.clone(),
+                    }
+                } else if entry.is_expired(now) {
+                    OwnershipStatus::Expired
+                } else {
+                    OwnershipStatus::Held
+                }
+            });
+            let resource_status = match held {
+                Some(held) => held,
+                // `uid` is gone from the table. A holder with the same owner took the
+                // lock again under a new uid, so the lease checked here has lapsed.
+                None => match self
+                    .get_lock_holders(&lock_id.resource)
+                    .await
+                    .into_iter()
+                    .filter(|holder| holder.owner != owner && holder.uid != uid)
+                    .max_by_key(LockHolder::is_write_lock)
+                {
+                    Some(holder) => OwnershipStatus::HeldByOther { owner: holder.owner },
+                    None => OwnershipStatus::Expired,
+                },
+            };
+            match resource_status {
+                OwnershipStatus::HeldByOther { .. } => return resource_status,
+                OwnershipStatus::Expired => status = OwnershipStatus::Expired,
+                OwnershipStatus::Held => {}
+            }
+        }
+        status
     }
 
-    /// Get the shard for a given lock ID
-    fn get_shard(&self, lock_id: &LockId) -> &Arc<RwLock<HashMap<LockId, LocalGuardEntry>>> {
-        let index = self.get_shard_index(lock_id);
-        &self.guard_storage[index]
+    /// One holder of `resource`, preferring the writer if it is write-locked.
+    ///
+    /// Read-only like [`Self::is_locked`]. Use [`Self::get_lock_holders`] to
+    /// see every reader of a shared lock.
+    pub async fn get_lock_holder(&self, resource: &crate::ObjectKey) -> Option<LockHolder> {
+        let mut holders = self.get_lock_holders(resource).await;
+        let writer = holders.iter().position(LockHolder::is_write_lock).unwrap_or(0);
+        (!holders.is_empty()).then(|| holders.swap_remove(writer))
+    }
+
+    /// Every holder of `resource` through this client, ordered by uid
+    pub async fn get_lock_holders(&self, resource: &crate::ObjectKey) -> Vec<LockHolder> {
+        let resource = &*self.normalize_key(resource);
+        let mut holders = Vec::new();
+        for shard in &self.guard_storage {
+            let guards = shard.read().await;
+            holders.extend(
+                guards
+                    .iter()
+                    .filter(|(lock_id, _)| &lock_id.resource == resource)
+                    .map(|(lock_id, entry)| LockHolder {
+                        kind: entry.hold_kind(),
+                        uid: lock_id.uuid.clone(),
+                        owner: entry.owner.clone(),
+                        source: entry.source.clone(),
+                        group: false,
+                    }),
+            );
+        }
+        holders.sort_by(|a, b| a.uid.cmp(&b.uid));
+        self.mark_group_holders(resource, &mut holders).await;
+        holders
+    }
+
+    /// Every resource held under `uid` through this client, sorted.
+    ///
+    /// More than one means the uid holds a lock group, and force-releasing
+    /// just one of its resources leaves the group's holder with a partial set.
+    pub async fn group_members(&self, uid: &str) -> Vec<crate::ObjectKey> {
+        let mut members = Vec::new();
+        for shard in &self.guard_storage {
+            members.extend(
+                shard
+                    .read()
+                    .await
+                    .keys()
+                    .filter(|lock_id| lock_id.uuid == uid)
+                    .map(|lock_id| lock_id.resource.clone()),
+            );
+        }
+        members.sort();
+        members
+    }
+
+    /// Locks whose status report satisfies `pred`, for ad-hoc admin queries.
+    ///
+    /// Each shard is read-locked in turn and the matching reports are
+    /// collected before the iterator is returned, so memory grows with the
+    /// matches rather than the table, but a report is still built for every
+    /// entry to test it. Reading does not refresh or otherwise touch any
+    /// lock. Order is unspecified.
+    pub async fn iter_locks<F>(&self, pred: F) -> impl Iterator<Item = LockInfo> + use<F>
+    where
+        F: Fn(&LockInfo) -> bool,
+    {
+        let now = self.clock.now();
+        let mut matched = Vec::new();
+        for shard in &self.guard_storage {
+            matched.extend(
+                shard
+                    .read()
+                    .await
+                    .iter()
+                    .map(|(lock_id, entry)| entry.lock_info(lock_id, now))
+                    .filter(|info| pred(info)),
+            );
+        }
+        matched.into_iter()
+    }
+
+    /// Refresh cadence of every lock refreshed at least once, tightest margin first.
+    ///
+    /// A holder whose refreshes land close to its TTL is at risk of losing
+    /// the lock to a late refresh long before it actually expires.
+    pub async fn refresh_health(&self) -> Vec<RefreshHealth> {
+        let mut report = Vec::new();
+        for shard in &self.guard_storage {
+            for (lock_id, entry) in shard.read().await.iter() {
+                if let Some(interval) = entry.last_refresh_interval {
+                    report.push(RefreshHealth {
+                        uid: lock_id.uuid.clone(),
+                        resource: lock_id.resource.clone(),
+                        owner: entry.owner.clone(),
+                        ttl: entry.ttl,
+                        last_interval: interval,
+                        margin: entry.ttl.saturating_sub(interval),
+                    });
+                }
+            }
+        }
+        report.sort_by(|a, b| a.margin.cmp(&b.margin).then_with(|| a.uid.cmp(&b.uid)));
+        report
+    }
+
+    /// Flag each of `holders` (sorted by uid) whose uid also holds a resource other than `resource`
+    async fn mark_group_holders(&self, resource: &crate::ObjectKey, holders: &mut [LockHolder]) {
+        if holders.is_empty() {
+            return;
+        }
+        for shard in &self.guard_storage {
+            for lock_id in shard.read().await.keys().filter(|lock_id| &lock_id.resource != resource) {
+                if let Ok(idx) = holders.binary_search_by(|holder| holder.uid.as_str().cmp(&lock_id.uuid)) {
+                    holders[idx].group = true;
+                }
+            }
+        }
+    }
+
+    /// How `resource` is locked through this client, or `None` if it is not.
+    ///
+    /// Read-only like [`Self::get_lock_holders`]: leases are neither
+    /// refreshed nor reclaimed, so expired holders still show up until a
+    /// sweep removes them.
+    pub async fn resource_state(&self, resource: &crate::ObjectKey) -> Option<ResourceState> {
+        let resource = &*self.normalize_key(resource);
+        let now = self.clock.now();
+        let mut holders = Vec::new();
+        let mut oldest_age = Duration::ZERO;
+        let mut since_last_refresh = Duration::ZERO;
+        for shard in &self.guard_storage {
+            let guards = shard.read().await;
+            for (lock_id, entry) in guards.iter().filter(|(lock_id, _)| &lock_id.resource == resource) {
+                oldest_age = oldest_age.max(now.saturating_duration_since(entry.acquired_at));
+                since_last_refresh = since_last_refresh.max(now.saturating_duration_since(entry.last_refreshed()));
+                holders.push(LockHolder {
+                    kind: entry.hold_kind(),
+                    uid: lock_id.uuid.clone(),
+                    owner: entry.owner.clone(),
+                    source: entry.source.clone(),
+                    group: false,
+                });
+            }
+        }
+        if holders.is_empty() {
+            return None;
+        }
+        holders.sort_by(|a, b| a.uid.cmp(&b.uid));
+        self.mark_group_holders(resource, &mut holders).await;
+        let kind = holders
+            .iter()
+            .map(|holder| holder.kind)
+            .find(|kind| *kind != HoldKind::Read)
+            .unwrap_or(HoldKind::Read);
+        Some(ResourceState {
+            kind,
+            holders,
+            oldest_age,
+            since_last_refresh,
+        })
     }
 
     async fn reclaim_expired_guards_for_resource(&self, resource: &crate::ObjectKey) -> usize {
+        self.reclaim_expired_guards(Some(resource)).await
+    }
+
+    async fn reclaim_expired_guards(&self, resource: Option<&crate::ObjectKey>) -> usize {
         let mut reclaimed = 0usize;
+        let now = self.clock.now();
 
         for shard in &self.guard_storage {
             let expired_entries = {
@@ -127,8 +2054,8 @@ impl LocalClient {
                 let mut expired_entries = Vec::new();
 
                 for (lock_id, entry) in std::mem::take(&mut *guards) {
-                    if &lock_id.resource == resource && entry.is_expired() {
-                        expired_entries.push(entry);
+                    if resource.is_none_or(|resource| &lock_id.resource == resource) && entry.is_expired(now) {
+                        expired_entries.push((lock_id, entry));
                     } else {
                         retained.insert(lock_id, entry);
                     }
@@ -138,81 +2065,437 @@ impl LocalClient {
                 expired_entries
             };
 
-            for mut entry in expired_entries {
+            for (lock_id, entry) in expired_entries {
                 // An expired entry whose owner never refreshed it (a dead coordinator, #698) is
                 // reclaimed so a live contender can re-form quorum. With guard heartbeats in place
                 // (#899) a live owner keeps its entry from expiring, so reaching here means the
                 // lease genuinely lapsed. Surface it for observability; the reclaim decision itself
                 // is unchanged.
-                let since_last_refresh = entry
-                    .expires_at
-                    .checked_sub(entry.ttl)
-                    .and_then(|last_refresh| SystemTime::now().duration_since(last_refresh).ok())
-                    .unwrap_or(entry.ttl);
+                let since_last_refresh = now.saturating_duration_since(entry.last_refreshed());
                 tracing::warn!(
                     owner = %entry.owner,
-                    resource = %resource,
+                    resource = %entry.guard.key(),
                     ttl_ms = entry.ttl.as_millis() as u64,
                     since_last_refresh_ms = since_last_refresh.as_millis() as u64,
                     "reclaiming expired lock guard whose lease was not refreshed"
                 );
                 rustfs_io_metrics::record_lock_reclaimed();
-                let _ = entry.guard.release();
+                self.audit_entry(LockEventKind::Expire, &lock_id, &entry);
+                self.record_hold(&entry);
+                if let Some(ExpireHook(on_expire)) = &self.on_expire {
+                    on_expire(&ExpiredLock {
+                        resource: lock_id.resource,
+                        uid: lock_id.uuid,
+                        owner: entry.owner.clone(),
+                        age: now.saturating_duration_since(entry.acquired_at),
+                    });
+                }
+                // Shared-write groups keep the lock until their last member goes.
+                drop(entry.guard);
                 reclaimed = reclaimed.saturating_add(1);
             }
         }
 
         reclaimed
     }
-}
 
-impl Default for LocalClient {
-    fn default() -> Self {
-        Self::new()
+    async fn acquire_traced(&self, request: &LockRequest, shared_write: bool) -> Result<LockResponse> {
+        let request = &*self.normalize_request(request);
+        let span = tracing::debug_span!(
+            "local_lock.acquire",
+            lock_uid = %request.lock_id.uuid,
+            owner = %request.owner,
+            resource = %request.resource,
+            resources = 1usize,
+            lock_type = ?request.lock_type,
+            outcome = tracing::field::Empty,
+        );
+        let rate_limited = self
+            .rate_limiter
+            .as_ref()
+            .is_some_and(|limiter| !limiter.try_acquire(&request.owner, self.clock.now()));
+        let result = if rate_limited {
+            Err(LockError::rate_limited(request.owner.clone()))
+        } else {
+            let started = Instant::now();
+            let result = self
+                .acquire_lock_untraced(request, shared_write)
+                .instrument(span.clone())
+                .await;
+            if result.is_ok() {
+                self.record_wait(started.elapsed());
+            }
+            result
+        };
+
+        let outcome = match &result {
+            Ok(response) if response.success => "acquired",
+            Ok(_) => "rejected",
+            Err(LockError::RateLimited { .. }) => "rate_limited",
+            Err(LockError::Capacity { .. }) => "capacity",
+            Err(LockError::WeightExceeded { .. }) => "weight_exceeded",
+            Err(LockError::Draining) => "draining",
+            Err(_) => "error",
+        };
+        span.record("outcome", outcome);
+        if let Ok(response) = &result {
+            self.contention.record(request, response.success);
+            if response.success {
+                self.log_change(|| {
+                    LockLogRecord::Acquired(LockSnapshotEntry {
+                        lock_id: request.lock_id.clone(),
+                        lock_type: request.lock_type,
+                        owner: request.owner.clone(),
+                        source: request.metadata.client_info.clone(),
+                        ttl: request.ttl,
+                        remaining: request.ttl,
+                        age: Duration::ZERO,
+                        acquired_at: response.lock_info().map(|info| info.acquired_at),
+                    })
+                });
+                self.audit(
+                    LockEventKind::acquired(request.lock_type),
+                    &request.lock_id,
+                    &request.owner,
+                    request.metadata.client_info.as_ref(),
+                );
+            }
+        }
+        if let Ok(response) = &result
+            && !response.success
+            && !request.suppress_contention_logs
+        {
+            tracing::warn!(
+                parent: &span,
+                lock_uid = %request.lock_id.uuid,
+                owner = %request.owner,
+                resource = %request.resource,
+                reason = response.error.as_deref().unwrap_or("unknown"),
+                "local lock acquisition rejected"
+            );
+        }
+        result
     }
-}
 
-#[async_trait::async_trait]
-impl LockClient for LocalClient {
-    async fn acquire_lock(&self, request: &LockRequest) -> Result<LockResponse> {
+    async fn try_acquire_classified(&self, request: &LockRequest) -> Result<LockOutcome> {
+        let attempt = request
+            .clone()
+            .with_acquire_timeout(Duration::ZERO)
+            .with_suppress_contention_logs(true);
+        if self.acquire_traced(&attempt, false).await?.success {
+            return Ok(LockOutcome::Acquired);
+        }
+
+        let resource = self.normalize_key(&request.resource);
+        let holders: Vec<(String, bool)> = match self.get_lock_manager().as_fast_lock_manager() {
+            Some(manager) => manager
+                .lock_holders(&resource)
+                .into_iter()
+                .map(|holder| (holder.owner.to_string(), holder.mode == crate::LockMode::Exclusive))
+                .collect(),
+            None => self
+                .get_lock_holders(&resource)
+                .await
+                .into_iter()
+                .map(|holder| (holder.owner.clone(), holder.is_write_lock()))
+                .collect(),
+        };
+        Ok(match holders.iter().find(|(_, writer)| *writer) {
+            Some((owner, _)) => LockOutcome::BlockedByWriter { owner: owner.clone() },
+            // Readers never block a read; one refused alongside them lost to a waiting writer.
+            None if holders.is_empty() || request.lock_type == LockType::Shared => LockOutcome::Queued,
+            None => LockOutcome::BlockedByReaders(holders.len()),
+        })
+    }
+
+    /// Reserve room for `request` under the configured cap and weight budget.
+    ///
+    /// At the cap, the capacity policy decides between rejecting and evicting.
+    async fn reserve_hold(&self, request: &LockRequest) -> Result<HoldReservation> {
+        let usage = &self.hold_usage;
+        match self.capacity {
+            Some((max_locks, policy)) => loop {
+                let held = usage.locks.load(Ordering::Acquire);
+                if held < max_locks {
+                    if usage
+                        .locks
+                        .compare_exchange(held, held + 1, Ordering::AcqRel, Ordering::Acquire)
+                        .is_ok()
+                    {
+                        break;
+                    }
+                } else if policy == CapacityPolicy::Reject || !self.evict_oldest().await {
+                    return Err(LockError::capacity(max_locks));
+                }
+            },
+            None => {
+                usage.locks.fetch_add(1, Ordering::AcqRel);
+            }
+        }
+        let mut reservation = HoldReservation {
+            usage: usage.clone(),
+            weight: None,
+        };
+        if let Some(budget) = self.weight_budget {
+            let requested = u64::from(request.weight);
+            let mut weights = usage.weights.lock();
+            let held = weights.get(&request.owner).copied().unwrap_or(0);
+            if held + requested > budget {
+                drop(weights);
+                return Err(LockError::weight_exceeded(request.owner.clone(), requested, held, budget));
+            }
+            *weights.entry(request.owner.clone()).or_default() += requested;
+            reservation.weight = Some((request.owner.clone(), requested));
+        }
+        Ok(reservation)
+    }
+
+    /// Force-release the least recently refreshed lock; false if none is held
+    async fn evict_oldest(&self) -> bool {
+        let mut oldest: Option<(LockId, Instant)> = None;
+        for shard in &self.guard_storage {
+            let guards = shard.read().await;
+            for (lock_id, entry) in guards.iter() {
+                let refreshed = entry.last_refreshed();
+                if oldest.as_ref().is_none_or(|(_, oldest_refresh)| refreshed < *oldest_refresh) {
+                    oldest = Some((lock_id.clone(), refreshed));
+                }
+            }
+        }
+        let Some((lock_id, _)) = oldest else {
+            return false;
+        };
+        // Another task may have released it meanwhile; the caller re-checks either way.
+        let removed = self.get_shard(&lock_id).write().await.remove(&lock_id);
+        if let Some(entry) = removed {
+            tracing::warn!(lock_uid = %lock_id.uuid, owner = %entry.owner, resource = %lock_id.resource, "evicting oldest lock to stay under capacity");
+            self.audit_entry(LockEventKind::ForceUnlock, &lock_id, &entry);
+            self.record_hold(&entry);
+            drop(entry.guard);
+        }
+        true
+    }
+
+    fn authorize_owner(&self, owner: &str, resource: &crate::ObjectKey) -> Result<()> {
+        match &self.owner_validator {
+            Some(validator) if !validator.authorize(owner, resource) => {
+                Err(LockError::permission_denied(format!("owner {owner} is not authorized for {resource}")))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Reject `epoch` if `owner` has already been seen at a newer one, otherwise record it.
+    ///
+    /// Epoch 0 is never checked or recorded.
+    fn observe_epoch(&self, owner: &str, epoch: u64) -> Result<()> {
+        if epoch == 0 {
+            return Ok(());
+        }
+        let mut epochs = self.owner_epochs.lock();
+        match epochs.get_mut(owner) {
+            Some(current) if epoch < *current => Err(LockError::stale_epoch(owner, epoch, *current)),
+            Some(current) => {
+                *current = epoch;
+                Ok(())
+            }
+            None => {
+                epochs.insert(owner.to_string(), epoch);
+                Ok(())
+            }
+        }
+    }
+
+    fn record_wait(&self, waited: Duration) {
+        self.wait_times.record(waited);
+        self.max_wait_micros
+            .fetch_max(u64::try_from(waited.as_micros()).unwrap_or(u64::MAX), Ordering::Relaxed);
+    }
+
+    fn record_hold(&self, entry: &LocalGuardEntry) {
+        self.hold_times
+            .record(self.clock.now().saturating_duration_since(entry.acquired_at));
+    }
+
+    /// Drop the guard for `lock_id`, auditing it as a force-unlock when `forced`
+    async fn release_entry(&self, lock_id: &LockId, forced: bool, span: &tracing::Span) -> Result<bool> {
+        let removed = self.get_shard(lock_id).write().await.remove(lock_id);
+        let Some(entry) = removed else {
+            // Lock not found or already released
+            span.record("outcome", "not_held");
+            return Ok(false);
+        };
+        span.record("owner", entry.owner.as_str());
+        span.record("outcome", "released");
+        let kind = if forced {
+            LockEventKind::ForceUnlock
+        } else {
+            LockEventKind::released(entry.lock_type())
+        };
+        self.audit_entry(kind, lock_id, &entry);
+        self.record_hold(&entry);
+        // Guard automatically releases the lock when dropped
+        drop(entry.guard);
+        Ok(true)
+    }
+
+    /// Add `request` to a live shared-write hold of the same owner on its resource.
+    ///
+    /// Returns `None` when there is no such hold to join, leaving `reservation` for the caller.
+    async fn join_shared_write(
+        &self,
+        request: &LockRequest,
+        reservation: &mut Option<HoldReservation>,
+    ) -> Result<Option<LockResponse>> {
+        let now = self.clock.now();
+        let mut group_guard = None;
+        for shard in &self.guard_storage {
+            let guards = shard.read().await;
+            group_guard = guards
+                .iter()
+                .find(|(lock_id, entry)| {
+                    lock_id.resource == request.resource
+                        && entry.shared_write
+                        && entry.owner == request.owner
+                        && !entry.is_expired(now)
+                })
+                .map(|(_, entry)| entry.guard.clone());
+            if group_guard.is_some() {
+                break;
+            }
+        }
+        let Some(guard) = group_guard else {
+            return Ok(None);
+        };
+
+        let mut guards = self.get_shard(&request.lock_id).write().await;
+        if self.is_closed() {
+            return Err(LockError::client_closed());
+        }
+        if self.is_draining() {
+            return Err(LockError::draining());
+        }
+        let mut entry =
+            LocalGuardEntry::new(guard, request.ttl, request.owner.clone(), request.metadata.client_info.clone(), now);
+        entry.shared_write = true;
+        entry.weight = request.weight;
+        entry.reservation = reservation.take();
+        let acquired_wall = entry.acquired_wall;
+        match guards.entry(request.lock_id.clone()) {
+            std::collections::hash_map::Entry::Vacant(slot) => {
+                slot.insert(entry);
+                Ok(Some(Self::acquired_response(request, acquired_wall)))
+            }
+            std::collections::hash_map::Entry::Occupied(_) => Ok(Some(Self::lock_id_in_use(request))),
+        }
+    }
+
+    fn lock_id_in_use(request: &LockRequest) -> LockResponse {
+        LockResponse::failure(format!("Lock id {} is already held through this client", request.lock_id), Duration::ZERO)
+    }
+
+    fn acquired_response(request: &LockRequest, acquired_at: SystemTime) -> LockResponse {
+        let lock_info = LockInfo {
+            id: request.lock_id.clone(),
+            resource: request.resource.clone(),
+            lock_type: request.lock_type,
+            status: crate::types::LockStatus::Acquired,
+            owner: request.owner.clone(),
+            acquired_at,
+            expires_at: acquired_at + request.ttl,
+            last_refreshed: acquired_at,
+            metadata: request.metadata.clone(),
+            priority: request.priority,
+            wait_start_time: None,
+        };
+        LockResponse::success(lock_info, Duration::ZERO)
+    }
+
+    async fn acquire_lock_untraced(&self, request: &LockRequest, shared_write: bool) -> Result<LockResponse> {
+        if self.is_closed() {
+            return Err(LockError::client_closed());
+        }
+        if self.is_draining() {
+            return Err(LockError::draining());
+        }
+        self.observe_epoch(&request.owner, request.epoch)?;
+        let mut reservation = Some(self.reserve_hold(request).await?);
+        if shared_write && let Some(response) = self.join_shared_write(request, &mut reservation).await? {
+            return Ok(response);
+        }
         let lock_manager = self.get_lock_manager();
-        let reclaimed_before_acquire = self.reclaim_expired_guards_for_resource(&request.resource).await;
+        // An expired guard of ours still holds its lock, so a resource the
+        // manager shows as free has nothing to reclaim and skips the table scan.
+        let reclaimed_before_acquire = if lock_manager.is_disabled() || lock_manager.get_lock_info(&request.resource).is_some() {
+            self.reclaim_expired_guards_for_resource(&request.resource).await
+        } else {
+            0
+        };
 
         let build_lock_request = || match request.lock_type {
             LockType::Exclusive => crate::ObjectLockRequest::new_write(request.resource.clone(), request.owner.clone())
-                .with_acquire_timeout(request.acquire_timeout),
+                .with_acquire_timeout(request.acquire_timeout)
+                .with_priority(request.priority.into()),
             LockType::Shared => crate::ObjectLockRequest::new_read(request.resource.clone(), request.owner.clone())
-                .with_acquire_timeout(request.acquire_timeout),
+                .with_acquire_timeout(request.acquire_timeout)
+                .with_priority(request.priority.into()),
         };
 
         let mut retried_after_reclaim = reclaimed_before_acquire > 0;
         loop {
-            match lock_manager.acquire_lock(build_lock_request()).await {
+            let acquire = std::pin::pin!(lock_manager.acquire_lock(build_lock_request()));
+            let mut drained = std::pin::pin!(self.drain_signal.notified());
+            // Register for the drain signal before re-checking the flag so a concurrent
+            // begin_drain() cannot slip in between and leave this waiter parked.
+            drained.as_mut().enable();
+            if self.is_draining() {
+                return Err(LockError::draining());
+            }
+            let acquired = match futures::future::select(acquire, drained).await {
+                futures::future::Either::Left((acquired, _)) => acquired,
+                futures::future::Either::Right(_) => return Err(LockError::draining()),
+            };
+            match acquired {
                 Ok(guard) => {
-                    let lock_id = request.lock_id.clone();
-                    let acquired_at = SystemTime::now();
-                    let expires_at = acquired_at + request.ttl;
-
-                    {
-                        let shard = self.get_shard(&lock_id);
-                        let mut guards = shard.write().await;
-                        guards.insert(lock_id.clone(), LocalGuardEntry::new(guard, request.ttl, request.owner.clone()));
+                    let shard = self.get_shard(&request.lock_id);
+                    let mut guards = shard.write().await;
+                    // close() may have drained this shard while we were waiting on the
+                    // manager; hand the lock straight back instead of leaking it.
+                    if self.is_closed() {
+                        drop(guards);
+                        drop(guard);
+                        return Err(LockError::client_closed());
                     }
-
-                    let lock_info = LockInfo {
-                        id: lock_id,
-                        resource: request.resource.clone(),
-                        lock_type: request.lock_type,
-                        status: crate::types::LockStatus::Acquired,
-                        owner: request.owner.clone(),
-                        acquired_at,
-                        expires_at,
-                        last_refreshed: acquired_at,
-                        metadata: request.metadata.clone(),
-                        priority: request.priority,
-                        wait_start_time: None,
-                    };
-                    return Ok(LockResponse::success(lock_info, Duration::ZERO));
+                    // Likewise for drain_into(), which may have emptied this shard already.
+                    if self.is_draining() {
+                        drop(guards);
+                        drop(guard);
+                        return Err(LockError::draining());
+                    }
+                    let mut entry = LocalGuardEntry::new(
+                        Arc::new(guard),
+                        request.ttl,
+                        request.owner.clone(),
+                        request.metadata.client_info.clone(),
+                        self.clock.now(),
+                    );
+                    entry.shared_write = shared_write;
+                    entry.weight = request.weight;
+                    entry.reservation = reservation.take();
+                    let acquired_wall = entry.acquired_wall;
+                    // Check and insert in one step: a lock id already in the table keeps
+                    // its entry, and the hold just granted is handed back.
+                    return Ok(match guards.entry(request.lock_id.clone()) {
+                        std::collections::hash_map::Entry::Vacant(slot) => {
+                            slot.insert(entry);
+                            Self::acquired_response(request, acquired_wall)
+                        }
+                        std::collections::hash_map::Entry::Occupied(_) => {
+                            drop(guards);
+                            drop(entry);
+                            Self::lock_id_in_use(request)
+                        }
+                    });
                 }
                 Err(crate::fast_lock::LockResult::Timeout) => {
                     if !retried_after_reclaim && self.reclaim_expired_guards_for_resource(&request.resource).await > 0 {
@@ -234,83 +2517,158 @@ impl LockClient for LocalClient {
                         Duration::ZERO,
                     ));
                 }
+                Err(crate::fast_lock::LockResult::ReentrancyLimitExceeded { owner, depth }) => {
+                    return Ok(LockResponse::failure(
+                        format!("Lock reentrancy limit reached: {owner} already holds the lock {depth} times"),
+                        Duration::ZERO,
+                    ));
+                }
+                Err(crate::fast_lock::LockResult::Cancelled) => {
+                    return Ok(LockResponse::failure("Lock acquisition cancelled", Duration::ZERO));
+                }
+                Err(crate::fast_lock::LockResult::BatchLimitExceeded { requested, max }) => {
+                    return Ok(LockResponse::failure(
+                        format!("Lock batch of {requested} objects exceeds the limit of {max}"),
+                        Duration::ZERO,
+                    ));
+                }
+                Err(crate::fast_lock::LockResult::InvalidKey { reason }) => {
+                    return Ok(LockResponse::failure(format!("Invalid lock resource: {reason}"), Duration::ZERO));
+                }
                 Err(crate::fast_lock::LockResult::Acquired) => {
                     unreachable!("Acquired should not be an error")
                 }
             }
         }
     }
+}
+
+impl Default for LocalClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait::async_trait]
+impl LockClient for LocalClient {
+    async fn acquire_lock(&self, request: &LockRequest) -> Result<LockResponse> {
+        self.acquire_traced(request, false).await
+    }
 
     async fn release(&self, lock_id: &LockId) -> Result<bool> {
-        let shard = self.get_shard(lock_id);
-        let mut guards = shard.write().await;
-        if let Some(guard) = guards.remove(lock_id) {
-            // Guard automatically releases the lock when dropped
-            drop(guard.guard);
-            Ok(true)
-        } else {
-            // Lock not found or already released
-            Ok(false)
-        }
+        let lock_id = &*self.normalize_id(lock_id);
+        let span = tracing::debug_span!(
+            "local_lock.release",
+            lock_uid = %lock_id.uuid,
+            owner = tracing::field::Empty,
+            resource = %lock_id.resource,
+            resources = 1usize,
+            outcome = tracing::field::Empty,
+        );
+        self.release_entry(lock_id, false, &span).instrument(span.clone()).await
     }
 
     async fn refresh(&self, lock_id: &LockId) -> Result<bool> {
-        let shard = self.get_shard(lock_id);
-        let mut guards = shard.write().await;
-        if let Some(entry) = guards.get_mut(lock_id) {
-            entry.refresh();
-            Ok(true)
-        } else {
-            Ok(false)
+        let lock_id = &*self.normalize_id(lock_id);
+        let span = tracing::debug_span!(
+            "local_lock.refresh",
+            lock_uid = %lock_id.uuid,
+            owner = tracing::field::Empty,
+            resource = %lock_id.resource,
+            resources = 1usize,
+            outcome = tracing::field::Empty,
+        );
+        async {
+            if self.is_closed() {
+                span.record("outcome", "closed");
+                return Err(LockError::client_closed());
+            }
+            let shard = self.get_shard(lock_id);
+            let mut guards = shard.write().await;
+            let manager = self.get_lock_manager().as_fast_lock_manager();
+            if guards.get(lock_id).is_some_and(|entry| entry.is_stale(manager.as_deref())) {
+                let stale = guards.remove(lock_id);
+                drop(guards);
+                self.log_released(lock_id);
+                drop(stale);
+                span.record("outcome", "stale");
+                return Ok(false);
+            }
+            if let Some(entry) = guards.get_mut(lock_id) {
+                entry.refresh(self.clock.now());
+                self.audit_entry(LockEventKind::Refresh, lock_id, entry);
+                span.record("owner", entry.owner.as_str());
+                span.record("outcome", "refreshed");
+                Ok(true)
+            } else {
+                span.record("outcome", "not_held");
+                Ok(false)
+            }
         }
+        .instrument(span.clone())
+        .await
     }
 
     async fn force_release(&self, lock_id: &LockId) -> Result<bool> {
-        self.release(lock_id).await
+        let lock_id = &*self.normalize_id(lock_id);
+        let span = tracing::debug_span!("local_lock.force_release", lock_uid = %lock_id.uuid, resource = %lock_id.resource);
+        let release_span = tracing::debug_span!(
+            parent: &span,
+            "local_lock.release",
+            lock_uid = %lock_id.uuid,
+            owner = tracing::field::Empty,
+            resource = %lock_id.resource,
+            resources = 1usize,
+            outcome = tracing::field::Empty,
+        );
+        self.release_entry(lock_id, true, &release_span)
+            .instrument(release_span.clone())
+            .instrument(span)
+            .await
     }
 
     async fn check_status(&self, lock_id: &LockId) -> Result<Option<LockInfo>> {
+        let lock_id = &*self.normalize_id(lock_id);
         let shard = self.get_shard(lock_id);
         let guards = shard.read().await;
-        if let Some(entry) = guards.get(lock_id) {
-            // We have an active guard for this lock
-            let lock_type = match entry.guard.mode() {
-                crate::LockMode::Shared => LockType::Shared,
-                crate::LockMode::Exclusive => LockType::Exclusive,
-            };
-            let status = if entry.is_expired() {
-                LockStatus::Expired
-            } else {
-                LockStatus::Acquired
-            };
-            Ok(Some(LockInfo {
-                id: lock_id.clone(),
-                resource: lock_id.resource.clone(),
-                lock_type,
-                status,
-                owner: entry.guard.owner().to_string(),
-                acquired_at: SystemTime::now(),
-                expires_at: entry.expires_at,
-                last_refreshed: SystemTime::now(),
-                metadata: LockMetadata::default(),
-                priority: LockPriority::Normal,
-                wait_start_time: None,
-            }))
-        } else {
-            Ok(None)
-        }
+        // We have an active guard for this lock
+        Ok(guards.get(lock_id).map(|entry| entry.lock_info(lock_id, self.clock.now())))
     }
 
+    /// Lock table sizes; `total_locks` counts holder entries, which is the
+    /// memory-pressure signal, and `locked_resources` the objects behind them
     async fn get_stats(&self) -> Result<LockStats> {
-        Ok(LockStats::default())
+        Ok(self.count_locks_prefix("").await)
     }
 
+    /// Release every guard held by this client and stop accepting work.
+    ///
+    /// Dropping the guards hands each lock back to the manager, which wakes
+    /// anyone blocked on those objects. Like the other forced releases, each
+    /// one is audited as a force unlock and its hold time recorded.
+    /// Afterwards `acquire_lock` and `refresh` fail with
+    /// [`LockError::ClientClosed`]; `release` and `check_status` keep working
+    /// and simply find nothing held.
     async fn close(&self) -> Result<()> {
+        self.closed.store(true, Ordering::Release);
+
+        for shard in &self.guard_storage {
+            let drained = std::mem::take(&mut *shard.write().await);
+            for (lock_id, entry) in &drained {
+                self.log_released(lock_id);
+                self.audit_entry(LockEventKind::ForceUnlock, lock_id, entry);
+                self.record_hold(entry);
+            }
+            // Drop outside the shard lock; each guard releases its object on drop.
+            drop(drained);
+        }
         Ok(())
     }
 
+    /// Offline once draining or closed, so callers routing across clients
+    /// stop sending new locks here
     async fn is_online(&self) -> bool {
-        true
+        !self.is_closed() && !self.is_draining()
     }
 
     async fn is_local(&self) -> bool {
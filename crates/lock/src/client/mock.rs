@@ -0,0 +1,229 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Scripted lock client for downstream tests
+//!
+//! [`MockClient`] has no locking semantics of its own: it records every call
+//! it receives and answers from per-method scripts, so crates built on
+//! [`LockClient`] can assert their lock/unlock call patterns without standing
+//! up a real backend.
+
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, SystemTime};
+
+use async_trait::async_trait;
+use parking_lot::Mutex;
+
+use crate::client::LockClient;
+use crate::types::LockStatus;
+use crate::{LockId, LockInfo, LockRequest, LockResponse, LockStats, Result};
+
+/// Scriptable [`MockClient`] operations
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MockMethod {
+    AcquireLock,
+    Release,
+    Refresh,
+    ForceRelease,
+    CheckStatus,
+}
+
+/// One call received by a [`MockClient`], with its arguments
+#[derive(Debug, Clone)]
+pub enum MockCall {
+    AcquireLock(Box<LockRequest>),
+    Release(LockId),
+    Refresh(LockId),
+    ForceRelease(LockId),
+    CheckStatus(LockId),
+}
+
+impl MockCall {
+    pub fn method(&self) -> MockMethod {
+        match self {
+            Self::AcquireLock(_) => MockMethod::AcquireLock,
+            Self::Release(_) => MockMethod::Release,
+            Self::Refresh(_) => MockMethod::Refresh,
+            Self::ForceRelease(_) => MockMethod::ForceRelease,
+            Self::CheckStatus(_) => MockMethod::CheckStatus,
+        }
+    }
+}
+
+/// Lock client that records calls and replays scripted responses.
+///
+/// Each method pops the next response scripted for it. `Ok(true)` means
+/// the lock was granted, released, refreshed or found; `Ok(false)` means it
+/// was not. Once a method's script runs out it answers `Ok(true)`.
+#[derive(Debug, Default)]
+pub struct MockClient {
+    calls: Mutex<Vec<MockCall>>,
+    scripts: Mutex<HashMap<MockMethod, VecDeque<Result<bool>>>>,
+}
+
+impl MockClient {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queue `responses` for `method`, after any already queued
+    pub fn with_responses(self, method: MockMethod, responses: impl IntoIterator<Item = Result<bool>>) -> Self {
+        self.script(method, responses);
+        self
+    }
+
+    /// Queue `responses` for `method` on a client already in use
+    pub fn script(&self, method: MockMethod, responses: impl IntoIterator<Item = Result<bool>>) {
+        self.scripts.lock().entry(method).or_default().extend(responses);
+    }
+
+    /// Every call received so far, oldest first
+    pub fn calls(&self) -> Vec<MockCall> {
+        self.calls.lock().clone()
+    }
+
+    /// Calls received so far for `method`, oldest first
+    pub fn calls_to(&self, method: MockMethod) -> Vec<MockCall> {
+        self.calls
+            .lock()
+            .iter()
+            .filter(|call| call.method() == method)
+            .cloned()
+            .collect()
+    }
+
+    /// Forget recorded calls, keeping any responses still scripted
+    pub fn clear_calls(&self) {
+        self.calls.lock().clear();
+    }
+
+    fn respond(&self, call: MockCall) -> Result<bool> {
+        let method = call.method();
+        self.calls.lock().push(call);
+        self.scripts
+            .lock()
+            .get_mut(&method)
+            .and_then(VecDeque::pop_front)
+            .unwrap_or(Ok(true))
+    }
+
+    fn lock_info(request: &LockRequest) -> LockInfo {
+        let now = SystemTime::now();
+        LockInfo {
+            id: request.lock_id.clone(),
+            resource: request.resource.clone(),
+            lock_type: request.lock_type,
+            status: LockStatus::Acquired,
+            owner: request.owner.clone(),
+            acquired_at: now,
+            expires_at: now + request.ttl,
+            last_refreshed: now,
+            metadata: request.metadata.clone(),
+            priority: request.priority,
+            wait_start_time: None,
+        }
+    }
+}
+
+#[async_trait]
+impl LockClient for MockClient {
+    async fn acquire_lock(&self, request: &LockRequest) -> Result<LockResponse> {
+        Ok(if self.respond(MockCall::AcquireLock(Box::new(request.clone())))? {
+            LockResponse::success(Self::lock_info(request), Duration::ZERO)
+        } else {
+            LockResponse::failure("scripted lock rejection", Duration::ZERO)
+        })
+    }
+
+    async fn release(&self, lock_id: &LockId) -> Result<bool> {
+        self.respond(MockCall::Release(lock_id.clone()))
+    }
+
+    async fn refresh(&self, lock_id: &LockId) -> Result<bool> {
+        self.respond(MockCall::Refresh(lock_id.clone()))
+    }
+
+    async fn force_release(&self, lock_id: &LockId) -> Result<bool> {
+        self.respond(MockCall::ForceRelease(lock_id.clone()))
+    }
+
+    /// A found lock is reported as an exclusive hold by `mock` on the id's resource
+    async fn check_status(&self, lock_id: &LockId) -> Result<Option<LockInfo>> {
+        if !self.respond(MockCall::CheckStatus(lock_id.clone()))? {
+            return Ok(None);
+        }
+        let mut request = LockRequest::new(lock_id.resource.clone(), crate::LockType::Exclusive, "mock");
+        request.lock_id = lock_id.clone();
+        Ok(Some(Self::lock_info(&request)))
+    }
+
+    async fn get_stats(&self) -> Result<LockStats> {
+        Ok(LockStats::default())
+    }
+
+    async fn close(&self) -> Result<()> {
+        Ok(())
+    }
+
+    async fn is_online(&self) -> bool {
+        true
+    }
+
+    async fn is_local(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{LockError, LockType, ObjectKey};
+
+    #[tokio::test]
+    async fn test_mock_client_records_calls_and_replays_scripts_in_order() {
+        let client = MockClient::new()
+            .with_responses(MockMethod::AcquireLock, [Ok(false), Ok(true)])
+            .with_responses(MockMethod::Release, [Err(LockError::internal("scripted")), Ok(false)]);
+        let request = LockRequest::new(ObjectKey::new("bucket", "object"), LockType::Exclusive, "owner");
+
+        assert!(!client.acquire_lock(&request).await.unwrap().success);
+        assert!(client.acquire_lock(&request).await.unwrap().success);
+        assert!(matches!(client.release(&request.lock_id).await, Err(LockError::Internal { .. })));
+        assert!(!client.release(&request.lock_id).await.unwrap());
+        // Exhausted scripts fall back to success.
+        assert!(client.release(&request.lock_id).await.unwrap());
+        assert!(client.refresh(&request.lock_id).await.unwrap());
+
+        let methods: Vec<_> = client.calls().iter().map(MockCall::method).collect();
+        assert_eq!(
+            methods,
+            [
+                MockMethod::AcquireLock,
+                MockMethod::AcquireLock,
+                MockMethod::Release,
+                MockMethod::Release,
+                MockMethod::Release,
+                MockMethod::Refresh,
+            ]
+        );
+        let acquires = client.calls_to(MockMethod::AcquireLock);
+        assert!(matches!(&acquires[0], MockCall::AcquireLock(seen) if seen.owner == "owner" && seen.lock_id == request.lock_id));
+        assert!(
+            client
+                .calls_to(MockMethod::Release)
+                .iter()
+                .all(|call| matches!(call, MockCall::Release(id) if *id == request.lock_id))
+        );
+    }
+}
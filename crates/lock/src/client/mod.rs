@@ -12,13 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod faulty;
 pub mod local;
+pub mod mock;
+pub mod noop;
 // pub mod remote;
 
-use crate::{LockId, LockInfo, LockRequest, LockResponse, LockStats, Result};
+use crate::{LockError, LockId, LockInfo, LockRequest, LockResponse, LockStats, Result};
 use async_trait::async_trait;
 use futures::future::join_all;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 /// Lock client trait
 #[async_trait]
@@ -76,8 +80,101 @@ impl ClientFactory {
         Arc::new(local::LocalClient::new())
     }
 
+    /// Create a client that grants every lock, for single-node deployments without locking
+    pub fn create_noop() -> Arc<dyn LockClient> {
+        Arc::new(noop::NoopClient::new())
+    }
+
     // /// Create remote client
     // pub fn create_remote(endpoint: String) -> Arc<dyn LockClient> {
     //     Arc::new(remote::RemoteClient::new(endpoint))
     // }
 }
+
+/// Keep trying `request` on `client` until it is granted or `total_timeout` runs out.
+///
+/// Each attempt waits at most `request.acquire_timeout` (and never past the
+/// deadline). A refused lock or a timed-out attempt counts as contention and
+/// is retried after a backoff that starts at `base_backoff`, doubles with
+/// every attempt and is jittered down by up to half so contenders spread
+/// out. Any other error ends the loop at once and is returned. Returns
+/// `false` if the lock was still not granted by the deadline.
+pub async fn acquire_with_retry(
+    client: &dyn LockClient,
+    request: &LockRequest,
+    total_timeout: Duration,
+    base_backoff: Duration,
+) -> Result<bool> {
+    let deadline = Instant::now() + total_timeout;
+    let mut attempt = request.clone();
+    for round in 0u32.. {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        attempt.acquire_timeout = request.acquire_timeout.min(remaining);
+        match client.acquire_lock(&attempt).await {
+            Ok(response) if response.success => return Ok(true),
+            Ok(_) | Err(LockError::Timeout { .. }) => {}
+            Err(err) => return Err(err),
+        }
+
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            break;
+        }
+        let backoff = base_backoff.saturating_mul(1 << round.min(16));
+        tokio::time::sleep(jittered(backoff).min(remaining)).await;
+    }
+    Ok(false)
+}
+
+/// A random duration between half of `backoff` and all of it
+fn jittered(backoff: Duration) -> Duration {
+    backoff.mul_f64(rand::random_range(0.5..=1.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::mock::{MockClient, MockMethod};
+    use crate::{LockType, ObjectKey};
+
+    fn request() -> LockRequest {
+        LockRequest::new(ObjectKey::new("bucket", "object"), LockType::Exclusive, "owner").with_acquire_timeout(Duration::ZERO)
+    }
+
+    #[tokio::test]
+    async fn test_acquire_with_retry_succeeds_after_contention() {
+        let client = MockClient::new().with_responses(
+            MockMethod::AcquireLock,
+            [Ok(false), Err(LockError::timeout("bucket/object", Duration::ZERO)), Ok(false)],
+        );
+        let granted = acquire_with_retry(&client, &request(), Duration::from_secs(5), Duration::from_millis(1))
+            .await
+            .unwrap();
+        assert!(granted);
+        assert_eq!(client.calls_to(MockMethod::AcquireLock).len(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_acquire_with_retry_gives_up_at_the_deadline() {
+        let client = MockClient::new().with_responses(MockMethod::AcquireLock, std::iter::repeat_with(|| Ok(false)).take(1000));
+        let started = Instant::now();
+        let granted = acquire_with_retry(&client, &request(), Duration::from_millis(50), Duration::from_millis(2))
+            .await
+            .unwrap();
+        assert!(!granted);
+        assert!(started.elapsed() >= Duration::from_millis(50));
+        let attempts = client.calls_to(MockMethod::AcquireLock).len();
+        assert!((2..1000).contains(&attempts), "{attempts} attempts");
+    }
+
+    #[tokio::test]
+    async fn test_acquire_with_retry_stops_on_a_hard_error() {
+        let client =
+            MockClient::new().with_responses(MockMethod::AcquireLock, [Err(LockError::invalid_request("too many resources"))]);
+        let err = acquire_with_retry(&client, &request(), Duration::from_secs(5), Duration::from_millis(1))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, LockError::InvalidRequest { .. }));
+        assert_eq!(client.calls_to(MockMethod::AcquireLock).len(), 1);
+    }
+}
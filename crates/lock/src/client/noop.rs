@@ -0,0 +1,127 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Lock client that grants everything
+//!
+//! [`NoopClient`] lets a single-node deployment switch locking off behind
+//! [`LockClient`] instead of branching at every call site. It provides no
+//! mutual exclusion at all, so only use it where nothing else can touch the
+//! same objects.
+
+use std::time::{Duration, SystemTime};
+
+use async_trait::async_trait;
+
+use crate::client::LockClient;
+use crate::types::LockStatus;
+use crate::{LockId, LockInfo, LockRequest, LockResponse, LockStats, Result};
+
+/// Lock client that grants, releases and refreshes every lock without tracking any.
+///
+/// `acquire_lock` always succeeds at once; `release`, `refresh` and
+/// `force_release` always return `Ok(true)`. Since nothing is tracked,
+/// `check_status` finds nothing and `get_stats` reports zeros. The client is
+/// always online and local.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoopClient;
+
+impl NoopClient {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait]
+impl LockClient for NoopClient {
+    async fn acquire_lock(&self, request: &LockRequest) -> Result<LockResponse> {
+        let now = SystemTime::now();
+        let lock_info = LockInfo {
+            id: request.lock_id.clone(),
+            resource: request.resource.clone(),
+            lock_type: request.lock_type,
+            status: LockStatus::Acquired,
+            owner: request.owner.clone(),
+            acquired_at: now,
+            expires_at: now + request.ttl,
+            last_refreshed: now,
+            metadata: request.metadata.clone(),
+            priority: request.priority,
+            wait_start_time: None,
+        };
+        Ok(LockResponse::success(lock_info, Duration::ZERO))
+    }
+
+    async fn release(&self, _lock_id: &LockId) -> Result<bool> {
+        Ok(true)
+    }
+
+    async fn refresh(&self, _lock_id: &LockId) -> Result<bool> {
+        Ok(true)
+    }
+
+    async fn force_release(&self, _lock_id: &LockId) -> Result<bool> {
+        Ok(true)
+    }
+
+    async fn check_status(&self, _lock_id: &LockId) -> Result<Option<LockInfo>> {
+        Ok(None)
+    }
+
+    async fn get_stats(&self) -> Result<LockStats> {
+        Ok(LockStats::default())
+    }
+
+    async fn close(&self) -> Result<()> {
+        Ok(())
+    }
+
+    async fn is_online(&self) -> bool {
+        true
+    }
+
+    async fn is_local(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{LockType, ObjectKey};
+
+    #[tokio::test]
+    async fn test_noop_client_returns_trivial_results() {
+        let client = NoopClient::new();
+        let write = LockRequest::new(ObjectKey::new("bucket", "object"), LockType::Exclusive, "writer");
+        let read = LockRequest::new(ObjectKey::new("bucket", "object"), LockType::Shared, "reader");
+
+        // Conflicting locks are both granted: nothing is tracked.
+        for request in [&write, &read] {
+            let response = client.acquire_lock(request).await.unwrap();
+            assert!(response.success);
+            assert_eq!(response.lock_info().map(|info| &info.id), Some(&request.lock_id));
+        }
+        assert!(client.refresh(&write.lock_id).await.unwrap());
+        assert!(client.release(&write.lock_id).await.unwrap());
+        assert!(client.release(&write.lock_id).await.unwrap());
+        assert!(client.force_release(&read.lock_id).await.unwrap());
+        assert!(client.check_status(&read.lock_id).await.unwrap().is_none());
+
+        let stats = client.get_stats().await.unwrap();
+        assert_eq!((stats.total_locks, stats.exclusive_locks, stats.shared_locks), (0, 0, 0));
+        assert!(client.is_online().await);
+        assert!(client.is_local().await);
+        client.close().await.unwrap();
+    }
+}
@@ -0,0 +1,93 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Time sources for lease expiry
+//!
+//! Lock leases are tracked against a [`Clock`] instead of calling
+//! `Instant::now()` directly, so expiry can be driven deterministically in
+//! tests with [`ManualClock`] rather than with real sleeps.
+
+use std::fmt::Debug;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Monotonic time source
+pub trait Clock: Send + Sync + Debug {
+    /// Current instant according to this clock
+    fn now(&self) -> Instant;
+}
+
+/// Clock backed by the system's monotonic clock
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// Clock that only moves when advanced, for deterministic tests
+#[derive(Debug)]
+pub struct ManualClock {
+    base: Instant,
+    offset: parking_lot::Mutex<Duration>,
+}
+
+impl ManualClock {
+    /// Create a clock frozen at the current instant
+    pub fn new() -> Self {
+        Self {
+            base: Instant::now(),
+            offset: parking_lot::Mutex::new(Duration::ZERO),
+        }
+    }
+
+    /// Move the clock forward by `duration`
+    pub fn advance(&self, duration: Duration) {
+        *self.offset.lock() += duration;
+    }
+}
+
+impl Default for ManualClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Instant {
+        self.base + *self.offset.lock()
+    }
+}
+
+/// Shared handle to the default system clock
+pub fn system_clock() -> Arc<dyn Clock> {
+    Arc::new(SystemClock)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_manual_clock_only_moves_when_advanced() {
+        let clock = ManualClock::new();
+        let start = clock.now();
+        assert_eq!(clock.now(), start);
+
+        clock.advance(Duration::from_secs(90));
+        assert_eq!(clock.now().duration_since(start), Duration::from_secs(90));
+    }
+}
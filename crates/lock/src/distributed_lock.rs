@@ -16,6 +16,7 @@ use crate::{
     ObjectKey,
     client::LockClient,
     error::{LockError, Result},
+    ring::LockerRing,
     types::{LockId, LockInfo, LockRequest, LockResponse, LockStatus, LockType},
 };
 use futures::future::join_all;
@@ -35,6 +36,7 @@ const UNLOCK_RETRY_ATTEMPTS: usize = 3;
 const UNLOCK_RETRY_BACKOFF: Duration = Duration::from_millis(100);
 const LOCK_ACQUIRE_RETRY_INITIAL_BACKOFF: Duration = Duration::from_millis(250);
 const LOCK_ACQUIRE_ATTEMPT_TIMEOUT: Duration = Duration::from_secs(1);
+const PEER_RETRY_DEFAULT_BASE_DELAY: Duration = Duration::from_millis(50);
 const REMOTE_LOCK_RPC_FAILED_PREFIX: &str = "remote lock rpc failed:";
 const REMOTE_LOCK_RPC_TIMED_OUT_PREFIX: &str = "remote lock rpc timed out:";
 const UNRECOVERABLE_QUORUM_FAILURE_PREFIX: &str = "unrecoverable quorum failure";
@@ -355,6 +357,14 @@ pub struct DistributedLock {
     namespace: String,
     /// Quorum size for exclusive/write operations
     quorum: usize,
+    /// Extra attempts a peer gets after a transient failure within one quorum attempt
+    peer_max_retries: usize,
+    /// Backoff before a peer's first retry, doubled on each further retry
+    peer_retry_base_delay: Duration,
+    /// Routes each resource to a subset of `clients`; peer `i` names client `i`
+    ring: Option<LockerRing>,
+    /// Lockers the ring picks per resource
+    ring_replicas: usize,
 }
 
 type LockAcquireTaskResult = (usize, Result<LockResponse>);
@@ -379,15 +389,111 @@ impl DistributedLock {
             clients,
             namespace,
             quorum: q,
+            peer_max_retries: 0,
+            peer_retry_base_delay: PEER_RETRY_DEFAULT_BASE_DELAY,
+            ring: None,
+            ring_replicas: 0,
         }
     }
 
+    /// Coordinate each resource on the `replicas` lockers `ring` assigns it
+    /// instead of on every client.
+    ///
+    /// Peer `i` of the ring must name client `i`. Quorum is then taken over
+    /// the chosen lockers: a majority for exclusive locks and half, rounded
+    /// up, for shared ones.
+    pub fn with_locker_ring(mut self, ring: LockerRing, replicas: usize) -> Self {
+        if ring.len() != self.clients.len() {
+            warn!(
+                ring_peers = ring.len(),
+                clients = self.clients.len(),
+                "locker ring does not match lock clients; ignoring ring"
+            );
+            return self;
+        }
+        self.ring = Some(ring);
+        self.ring_replicas = replicas.max(1);
+        self
+    }
+
+    /// Retry peers that fail transiently up to `max_retries` times, with
+    /// exponential backoff starting at `base_delay`, before counting them
+    /// against quorum.
+    ///
+    /// Only the failing peer is asked again; peers that already granted the
+    /// lock keep their grant, so a retry never acquires twice on one peer.
+    /// Retries share the attempt's deadline. Contention ("already held")
+    /// responses are not retried here.
+    pub fn with_peer_retries(mut self, max_retries: usize, base_delay: Duration) -> Self {
+        self.peer_max_retries = max_retries;
+        self.peer_retry_base_delay = base_delay;
+        self
+    }
+
+    /// Swap in a new client set and write quorum, e.g. after nodes join or leave.
+    ///
+    /// Takes `&mut self`, so no acquisition can observe a half-applied change.
+    /// Guards already handed out keep the clients that granted them and still
+    /// refresh and release through those, so no held lock is dropped. The
+    /// guards in `held` are checked against the new configuration: those no
+    /// longer granted by enough of the new clients to meet its quorum are
+    /// logged and their ids returned, so callers can re-acquire or abort the
+    /// work they protect. A locker ring that does not match the new client
+    /// count is discarded.
+    pub fn reconfigure(
+        &mut self,
+        clients: Vec<Arc<dyn LockClient>>,
+        quorum: usize,
+        held: &[&DistributedLockGuard],
+    ) -> Vec<LockId> {
+        self.quorum = if clients.len() <= 1 {
+            1
+        } else {
+            quorum.clamp(1, clients.len())
+        };
+        self.clients = clients;
+        if self.ring.as_ref().is_some_and(|ring| ring.len() != self.clients.len()) {
+            warn!(
+                clients = self.clients.len(),
+                "locker ring does not match reconfigured lock clients; dropping ring"
+            );
+            self.ring = None;
+        }
+
+        let mut under_quorum = Vec::new();
+        for guard in held {
+            let required = self.participant_quorum(guard.lock_type, self.participants(&guard.lock_id.resource).len());
+            let granted = guard
+                .entries
+                .iter()
+                .filter(|(_, client)| self.clients.iter().any(|current| Arc::ptr_eq(current, client)))
+                .count();
+            if granted < required {
+                warn!(
+                    lock_id = %guard.lock_id.uuid,
+                    resource = %guard.lock_id.resource,
+                    granted,
+                    required,
+                    "held lock no longer meets quorum after reconfiguration"
+                );
+                under_quorum.push(guard.lock_id.clone());
+            }
+        }
+        under_quorum
+    }
+
     /// Get namespace identifier
     pub fn namespace(&self) -> &str {
         &self.namespace
     }
 
-    fn read_quorum(&self) -> usize {
+    /// Number of clients that must grant an exclusive lock
+    pub fn write_quorum(&self) -> usize {
+        self.quorum
+    }
+
+    /// Number of clients that must grant a shared lock
+    pub fn read_quorum(&self) -> usize {
         let client_count = self.clients.len();
         if client_count <= 1 {
             1
@@ -403,11 +509,43 @@ impl DistributedLock {
         }
     }
 
+    /// Indices of the clients that coordinate `resource`
+    fn participants(&self, resource: &ObjectKey) -> Vec<usize> {
+        match &self.ring {
+            Some(ring) => ring.locker_indices(resource, self.ring_replicas),
+            None => (0..self.clients.len()).collect(),
+        }
+    }
+
+    /// Grants needed from `participants` clients for a lock of `lock_type`
+    fn participant_quorum(&self, lock_type: LockType, participants: usize) -> usize {
+        if self.ring.is_none() || participants >= self.clients.len() {
+            return self.required_quorum(lock_type);
+        }
+        match lock_type {
+            LockType::Shared => participants - (participants / 2),
+            LockType::Exclusive => participants / 2 + 1,
+        }
+    }
+
     /// Get resource key for this namespace
     pub fn get_resource_key(&self, resource: &ObjectKey) -> String {
         format!("{}:{}", self.namespace, resource)
     }
 
+    /// Whether every participant is local to this node.
+    ///
+    /// A lock spread over any remote client, or over none at all, is not
+    /// local. Callers that take an all-local fast path rely on this never
+    /// reporting `true` for a mixed set.
+    pub async fn is_local(&self) -> bool {
+        !self.clients.is_empty()
+            && futures::future::join_all(self.clients.iter().map(|client| client.is_local()))
+                .await
+                .into_iter()
+                .all(|local| local)
+    }
+
     /// Get clients (for health check and stats)
     pub(crate) fn clients(&self) -> &[Arc<dyn LockClient>] {
         &self.clients
@@ -419,7 +557,7 @@ impl DistributedLock {
             return Err(LockError::internal("No lock clients available"));
         }
 
-        let required_quorum = self.required_quorum(request.lock_type);
+        let required_quorum = self.participant_quorum(request.lock_type, self.participants(&request.resource).len());
         let LockAcquireQuorumResult {
             response: resp,
             individual_locks,
@@ -539,15 +677,51 @@ impl DistributedLock {
         self.acquire_guard(&req).await
     }
 
-    fn spawn_lock_requests(&self, request: &LockRequest) -> JoinSet<LockAcquireTaskResult> {
+    fn spawn_lock_requests(&self, request: &LockRequest, participants: &[usize]) -> JoinSet<LockAcquireTaskResult> {
         let mut pending = JoinSet::new();
-        for (idx, client) in self.clients.iter().cloned().enumerate() {
+        for &idx in participants {
+            let client = self.clients[idx].clone();
             let request = request.clone();
             pending.spawn(async move { (idx, client.acquire_lock(&request).await) });
         }
         pending
     }
 
+    /// Re-issue `request` to the client at `idx` after the backoff for its `retry`th retry (1-based)
+    fn spawn_peer_retry(&self, pending: &mut JoinSet<LockAcquireTaskResult>, idx: usize, request: &LockRequest, retry: usize) {
+        let Some(client) = self.clients.get(idx).cloned() else {
+            return;
+        };
+        let exponent = u32::try_from(retry.saturating_sub(1)).unwrap_or(u32::MAX);
+        let backoff = self
+            .peer_retry_base_delay
+            .saturating_mul(2u32.checked_pow(exponent).unwrap_or(u32::MAX));
+        let request = request.clone();
+        pending.spawn(async move {
+            tokio::time::sleep(backoff).await;
+            (idx, client.acquire_lock(&request).await)
+        });
+    }
+
+    /// Schedule a retry for the peer at `idx` if it has budget left
+    fn try_peer_retry(
+        &self,
+        pending: &mut JoinSet<LockAcquireTaskResult>,
+        peer_retries: &mut [usize],
+        idx: usize,
+        request: &LockRequest,
+    ) -> bool {
+        let Some(retries) = peer_retries.get_mut(idx) else {
+            return false;
+        };
+        if *retries >= self.peer_max_retries {
+            return false;
+        }
+        *retries += 1;
+        self.spawn_peer_retry(pending, idx, request, *retries);
+        true
+    }
+
     fn lock_acquire_retry_backoff(attempt: usize) -> Duration {
         LOCK_ACQUIRE_RETRY_INITIAL_BACKOFF.saturating_mul(attempt.try_into().unwrap_or(u32::MAX))
     }
@@ -560,7 +734,9 @@ impl DistributedLock {
         }
     }
 
-    async fn release_entries(entries: Vec<(LockId, Arc<dyn LockClient>)>, context: &'static str) {
+    /// Release `entries`, retrying the ones not confirmed, and return how many were confirmed
+    async fn release_entries(entries: Vec<(LockId, Arc<dyn LockClient>)>, context: &'static str) -> usize {
+        let total = entries.len();
         let mut pending = entries;
 
         for attempt in 1..=UNLOCK_RETRY_ATTEMPTS {
@@ -582,7 +758,7 @@ impl DistributedLock {
             pending = release_results.into_iter().flatten().collect();
             if pending.is_empty() {
                 debug!(attempt, context, "distributed unlock completed");
-                return;
+                return total;
             }
 
             if attempt < UNLOCK_RETRY_ATTEMPTS {
@@ -596,6 +772,34 @@ impl DistributedLock {
             context,
             "distributed unlock left unreleased entries after retry"
         );
+        total - pending.len()
+    }
+
+    /// Release `guard` and wait until the lockers confirm it.
+    ///
+    /// Dropping a guard releases in the background and cannot report the
+    /// outcome. This instead retries lockers that fail or do not find the
+    /// lock, and succeeds only once a quorum of the lockers coordinating the
+    /// resource confirm. Falling short fails with
+    /// [`LockError::QuorumNotReached`]; the locks still held on the other
+    /// lockers lapse when their lease runs out.
+    pub async fn unlock(&self, mut guard: DistributedLockGuard) -> Result<()> {
+        if guard.is_disarmed() {
+            return Ok(());
+        }
+        let entries = std::mem::take(&mut guard.entries);
+        // Disarming stops the heartbeat and keeps the drop from releasing a second time.
+        guard.disarm();
+        let required = self.participant_quorum(guard.lock_type, self.participants(&guard.lock_id.resource).len());
+        let confirmed = Self::release_entries(entries, "distributed_lock_unlock").await;
+        if confirmed >= required {
+            Ok(())
+        } else {
+            Err(LockError::QuorumNotReached {
+                required,
+                achieved: confirmed,
+            })
+        }
     }
 
     fn spawn_release_cleanup(entries: Vec<(LockId, Arc<dyn LockClient>)>, context: &'static str) {
@@ -769,14 +973,16 @@ impl DistributedLock {
     /// Collects all individual lock_ids from successful clients and creates an aggregate lock_id.
     /// Returns the LockResponse with aggregate lock_id and individual lock mappings.
     async fn acquire_lock_quorum_once(&self, request: &LockRequest) -> Result<LockAcquireQuorumResult> {
-        let required_quorum = self.required_quorum(request.lock_type);
-        let mut pending = self.spawn_lock_requests(request);
+        let participants = self.participants(&request.resource);
+        let required_quorum = self.participant_quorum(request.lock_type, participants.len());
+        let mut pending = self.spawn_lock_requests(request, &participants);
         let mut individual_locks: Vec<(LockId, Arc<dyn LockClient>)> = Vec::new();
         let fallback_lock_id = request.lock_id.clone();
         let mut last_failure = None;
         let mut last_failure_kind = None;
         let mut last_hard_failure_kind = None;
         let mut hard_failures = 0usize;
+        let mut peer_retries = vec![0usize; self.clients.len()];
         let start = tokio::time::Instant::now();
 
         while !pending.is_empty() {
@@ -835,6 +1041,12 @@ impl DistributedLock {
                         let error = resp.error.unwrap_or_else(|| "unknown error".to_string());
                         let failure_kind = classify_lock_failure(&error);
                         if is_remote_lock_rpc_failure(&error) && !is_remote_lock_rpc_timeout(&error) {
+                            if self.try_peer_retry(&mut pending, &mut peer_retries, idx, request) {
+                                tracing::debug!("Retrying lock on client {} after transient failure: {}", idx, error);
+                                last_failure = Some(error);
+                                last_failure_kind = Some(failure_kind);
+                                continue;
+                            }
                             hard_failures += 1;
                             last_hard_failure_kind = Some(failure_kind);
                         }
@@ -844,8 +1056,14 @@ impl DistributedLock {
                     }
                 }
                 Ok((idx, Err(err))) => {
-                    hard_failures += 1;
                     let failure_kind = classify_lock_error_failure(&err);
+                    if err.is_retryable() && self.try_peer_retry(&mut pending, &mut peer_retries, idx, request) {
+                        tracing::debug!("Retrying lock on client {} after transient error: {}", idx, err);
+                        last_failure = Some(err.to_string());
+                        last_failure_kind = Some(failure_kind);
+                        continue;
+                    }
+                    hard_failures += 1;
                     tracing::warn!("Failed to acquire lock on client {}: {}", idx, err);
                     last_failure = Some(err.to_string());
                     last_failure_kind = Some(failure_kind);
@@ -860,7 +1078,7 @@ impl DistributedLock {
                 }
             }
 
-            if self.clients.len().saturating_sub(hard_failures) < required_quorum {
+            if participants.len().saturating_sub(hard_failures) < required_quorum {
                 let rollback_count = individual_locks.len();
                 Self::spawn_release_cleanup(individual_locks.clone(), "distributed_lock_quorum_rollback");
                 if !pending.is_empty() {
@@ -1009,7 +1227,9 @@ mod tests {
         DistributedLock, LOCK_ACQUIRE_ATTEMPT_TIMEOUT, LockAcquireFailureKind, is_remote_lock_rpc_failure,
         should_warn_lock_failure,
     };
-    use crate::{LockError, LockId, LockInfo, LockRequest, LockResponse, LockStats, LockType, ObjectKey, client::LockClient};
+    use crate::{
+        LockError, LockId, LockInfo, LockRequest, LockResponse, LockStats, LockType, LockerRing, ObjectKey, client::LockClient,
+    };
     use std::assert_matches;
     use std::sync::atomic::{AtomicUsize, Ordering};
     use std::{
@@ -1741,6 +1961,148 @@ mod tests {
         drop(guard);
     }
 
+    #[tokio::test]
+    async fn acquire_guard_peer_retries_recover_flaky_peer_without_reacquiring_others() {
+        let flaky_ids = Arc::new(Mutex::new(Vec::new()));
+        let steady_ids: Vec<_> = (0..2).map(|_| Arc::new(Mutex::new(Vec::new()))).collect();
+        let transient = || AcquirePlan::ClientError {
+            message: "can not get client, err: temporary transport unavailable",
+            delay: Duration::ZERO,
+        };
+        let mut clients: Vec<Arc<dyn LockClient>> = vec![Arc::new(SequencedClient::new(
+            vec![transient(), transient(), AcquirePlan::Success { delay: Duration::ZERO }],
+            flaky_ids.clone(),
+        ))];
+        for seen_ids in &steady_ids {
+            clients.push(Arc::new(SequencedClient::new(
+                vec![AcquirePlan::Success { delay: Duration::ZERO }],
+                seen_ids.clone(),
+            )));
+        }
+        let lock = DistributedLock::new("test".to_string(), clients, 3).with_peer_retries(2, Duration::from_millis(10));
+        let request = LockRequest::new(ObjectKey::new("bucket", "object"), LockType::Exclusive, "owner")
+            .with_acquire_timeout(Duration::from_secs(2));
+
+        let guard = lock
+            .acquire_guard(&request)
+            .await
+            .expect("transient peer failures within budget should not fail acquisition")
+            .expect("flaky peer should succeed on its third call");
+
+        assert_eq!(guard.entries.len(), 3);
+        let flaky_ids = flaky_ids.lock().unwrap().clone();
+        assert_eq!(flaky_ids.len(), 3, "flaky peer should be called once plus two retries");
+        assert!(flaky_ids.iter().all(|id| *id == flaky_ids[0]), "retries stay within one quorum attempt");
+        for seen_ids in &steady_ids {
+            assert_eq!(seen_ids.lock().unwrap().len(), 1, "peers that already granted must not be asked again");
+        }
+        drop(guard);
+    }
+
+    #[tokio::test]
+    async fn acquire_guard_with_locker_ring_only_contacts_assigned_lockers() {
+        let seen: Vec<_> = (0..5).map(|_| Arc::new(Mutex::new(Vec::new()))).collect();
+        let clients: Vec<Arc<dyn LockClient>> = seen
+            .iter()
+            .map(|seen_ids| Arc::new(SequencedClient::new(Vec::new(), seen_ids.clone())) as Arc<dyn LockClient>)
+            .collect();
+        let ring = LockerRing::new((0..5).map(|i| format!("node-{i}")));
+        let resource = ObjectKey::new("bucket", "object");
+        let assigned = ring.locker_indices(&resource, 3);
+        let lock = DistributedLock::new("test".to_string(), clients, 5).with_locker_ring(ring, 3);
+        let request = LockRequest::new(resource, LockType::Exclusive, "owner").with_acquire_timeout(Duration::from_secs(1));
+
+        let guard = lock
+            .acquire_guard(&request)
+            .await
+            .expect("ring acquisition should not error")
+            .expect("assigned lockers should grant quorum");
+
+        assert!(guard.entries.len() >= 2, "a majority of the 3 assigned lockers must grant");
+        for (idx, seen_ids) in seen.iter().enumerate() {
+            let calls = seen_ids.lock().unwrap().len();
+            if assigned.contains(&idx) {
+                assert!(calls <= 1, "assigned locker {idx} asked {calls} times");
+            } else {
+                assert_eq!(calls, 0, "locker {idx} is not assigned this resource");
+            }
+        }
+        drop(guard);
+    }
+
+    #[tokio::test]
+    async fn is_local_only_when_every_participant_is_local() {
+        let local = || Arc::new(crate::LocalClient::new()) as Arc<dyn LockClient>;
+        let remote = || ResponseClient::new(LockResponse::failure("unused", Duration::ZERO)).into_client();
+
+        assert!(
+            DistributedLock::new("test".to_string(), vec![local(), local(), local()], 2)
+                .is_local()
+                .await
+        );
+        assert!(
+            !DistributedLock::new("test".to_string(), vec![remote(), remote()], 2)
+                .is_local()
+                .await
+        );
+        assert!(
+            !DistributedLock::new("test".to_string(), vec![local(), remote(), local()], 2)
+                .is_local()
+                .await
+        );
+        assert!(!DistributedLock::new("test".to_string(), Vec::new(), 1).is_local().await);
+    }
+
+    #[tokio::test]
+    async fn reconfigure_applies_new_quorum_and_reports_held_locks_below_it() {
+        let locals: Vec<_> = (0..5)
+            .map(|_| Arc::new(crate::LocalClient::with_manager(Arc::new(crate::GlobalLockManager::new()))))
+            .collect();
+        let as_clients = |indices: &[usize]| -> Vec<Arc<dyn LockClient>> {
+            indices.iter().map(|&i| locals[i].clone() as Arc<dyn LockClient>).collect()
+        };
+        let mut lock = DistributedLock::new("test".to_string(), as_clients(&[0, 1, 2]), 3);
+        let held_resource = ObjectKey::new("bucket", "held");
+        let held = lock
+            .acquire_guard(
+                &LockRequest::new(held_resource.clone(), LockType::Exclusive, "owner")
+                    .with_acquire_timeout(Duration::from_secs(1)),
+            )
+            .await
+            .unwrap()
+            .expect("all three lockers grant the old quorum");
+        assert_eq!(held.entries.len(), 3);
+
+        // Growing to five keeps all three grants, which meets the new quorum of three.
+        assert!(lock.reconfigure(as_clients(&[0, 1, 2, 3, 4]), 3, &[&held]).is_empty());
+        // Replacing nodes 1 and 2 leaves the held lock with one grant, below a quorum of two.
+        let reported = lock.reconfigure(as_clients(&[0, 3, 4]), 2, &[&held]);
+        assert_eq!(reported, vec![held.lock_id().clone()]);
+        assert!(locals[1].is_locked(&held_resource).await, "reconfiguring must not drop held locks");
+
+        // New acquisitions need three of the five current lockers.
+        lock.reconfigure(as_clients(&[0, 1, 2, 3, 4]), 3, &[]);
+        assert_eq!(lock.write_quorum(), 3);
+        let contended = ObjectKey::new("bucket", "contended");
+        for local in &locals[..3] {
+            let blocker = LockRequest::new(contended.clone(), LockType::Exclusive, "blocker");
+            assert!(local.acquire_lock(&blocker).await.unwrap().success);
+        }
+        let request = LockRequest::new(contended, LockType::Exclusive, "owner").with_acquire_timeout(Duration::from_millis(100));
+        assert!(
+            lock.acquire_guard(&request).await.unwrap().is_none(),
+            "two free lockers no longer make quorum"
+        );
+        let fresh = LockRequest::new(ObjectKey::new("bucket", "fresh"), LockType::Exclusive, "owner")
+            .with_acquire_timeout(Duration::from_secs(1));
+        let guard = lock
+            .acquire_guard(&fresh)
+            .await
+            .unwrap()
+            .expect("free lockers grant the new quorum");
+        assert!(guard.entries.len() >= 3);
+    }
+
     #[tokio::test]
     async fn acquire_guard_returns_timeout_when_zero_locks_make_quorum_impossible_for_attempt() {
         let clients: Vec<Arc<dyn LockClient>> = vec![
@@ -2114,4 +2476,87 @@ mod tests {
 
         drop(guard);
     }
+
+    /// Grants like a local client but never confirms a release
+    #[derive(Debug, Default)]
+    struct StuckReleaseClient {
+        inner: crate::LocalClient,
+        release_calls: AtomicUsize,
+    }
+
+    #[async_trait::async_trait]
+    impl LockClient for StuckReleaseClient {
+        async fn acquire_lock(&self, request: &LockRequest) -> crate::Result<LockResponse> {
+            self.inner.acquire_lock(request).await
+        }
+        async fn release(&self, _lock_id: &LockId) -> crate::Result<bool> {
+            self.release_calls.fetch_add(1, Ordering::SeqCst);
+            Ok(false)
+        }
+        async fn refresh(&self, lock_id: &LockId) -> crate::Result<bool> {
+            self.inner.refresh(lock_id).await
+        }
+        async fn force_release(&self, lock_id: &LockId) -> crate::Result<bool> {
+            self.inner.force_release(lock_id).await
+        }
+        async fn check_status(&self, lock_id: &LockId) -> crate::Result<Option<LockInfo>> {
+            self.inner.check_status(lock_id).await
+        }
+        async fn get_stats(&self) -> crate::Result<LockStats> {
+            self.inner.get_stats().await
+        }
+        async fn close(&self) -> crate::Result<()> {
+            Ok(())
+        }
+        async fn is_online(&self) -> bool {
+            true
+        }
+        async fn is_local(&self) -> bool {
+            false
+        }
+    }
+
+    #[tokio::test]
+    async fn unlock_fails_and_retries_when_fewer_than_quorum_confirm() {
+        let confirming = Arc::new(crate::LocalClient::with_manager(Arc::new(crate::GlobalLockManager::new())));
+        let stuck: Vec<_> = (0..2)
+            .map(|_| {
+                Arc::new(StuckReleaseClient {
+                    inner: crate::LocalClient::with_manager(Arc::new(crate::GlobalLockManager::new())),
+                    ..Default::default()
+                })
+            })
+            .collect();
+        let clients: Vec<Arc<dyn LockClient>> = vec![confirming.clone(), stuck[0].clone(), stuck[1].clone()];
+        let lock = DistributedLock::new("test".to_string(), clients, 2);
+        let request = LockRequest::new(ObjectKey::new("bucket", "stuck"), LockType::Exclusive, "owner")
+            .with_acquire_timeout(Duration::from_secs(1));
+        let guard = lock.acquire_guard(&request).await.unwrap().expect("every locker grants");
+        let granted = guard.entries.len();
+        assert!(granted >= 2);
+
+        let err = lock.unlock(guard).await.unwrap_err();
+        assert_matches!(
+            err,
+            LockError::QuorumNotReached {
+                required: 2,
+                achieved: 1
+            }
+        );
+        assert!(!confirming.is_locked(&request.resource).await, "the confirming locker did release");
+        let retried = stuck
+            .iter()
+            .map(|client| client.release_calls.load(Ordering::SeqCst))
+            .sum::<usize>();
+        // Late grants cleaned up in the background may add release calls of their own.
+        assert!(retried >= (granted - 1) * super::UNLOCK_RETRY_ATTEMPTS, "{retried} release calls");
+
+        // With every locker confirming, unlock succeeds.
+        let locals: Vec<Arc<dyn LockClient>> = (0..3)
+            .map(|_| Arc::new(crate::LocalClient::with_manager(Arc::new(crate::GlobalLockManager::new()))) as Arc<dyn LockClient>)
+            .collect();
+        let lock = DistributedLock::new("test".to_string(), locals, 2);
+        let guard = lock.acquire_guard(&request).await.unwrap().expect("every locker grants");
+        lock.unlock(guard).await.unwrap();
+    }
 }
@@ -0,0 +1,154 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! MinIO dsync-compatible lock arguments
+//!
+//! MinIO's dsync sends one `LockArgs` per call naming every resource of a
+//! lock together with the caller's uid, owner and source. [`DsyncLockArgs`]
+//! carries the same fields under the same wire names and converts to and
+//! from the per-resource [`LockRequest`]s used natively.
+//!
+//! Not everything maps cleanly:
+//! - dsync resources are `bucket/object` paths. Object versions have no
+//!   dsync equivalent, so a versioned key is sent as its whole object,
+//!   which only ever widens the lock.
+//! - The lock type is implied by which dsync call carries the args, so it is
+//!   passed to [`DsyncLockArgs::to_requests`] rather than stored.
+//! - `Quorum` has no per-request counterpart; quorum is decided by the
+//!   [`DistributedLock`](crate::distributed_lock::DistributedLock) that fans
+//!   the requests out, so it is dropped on conversion and left unset when
+//!   converting back.
+//! - TTL, timeouts, priority and the other native knobs are not sent and
+//!   take their defaults.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{LockError, LockId, LockMetadata, LockRequest, LockType, ObjectKey, Result};
+
+/// Lock arguments as exchanged by MinIO dsync
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DsyncLockArgs {
+    /// Lock uid shared by every resource of the lock
+    #[serde(rename = "UID")]
+    pub uid: String,
+    /// `bucket/object` paths
+    #[serde(rename = "Resources")]
+    pub resources: Vec<String>,
+    #[serde(rename = "Owner")]
+    pub owner: String,
+    /// Call site that took the lock, kept as the request's client info
+    #[serde(rename = "Source", default)]
+    pub source: String,
+    /// Lockers that must agree, if the caller overrides the default
+    #[serde(rename = "Quorum", default, skip_serializing_if = "Option::is_none")]
+    pub quorum: Option<usize>,
+}
+
+impl DsyncLockArgs {
+    /// One native request per resource, all under `uid` and of `lock_type`
+    pub fn to_requests(&self, lock_type: LockType) -> Result<Vec<LockRequest>> {
+        self.resources
+            .iter()
+            .map(|path| {
+                let resource = parse_resource(path)?;
+                let mut metadata = LockMetadata::new();
+                if !self.source.is_empty() {
+                    metadata = metadata.with_client_info(self.source.clone());
+                }
+                Ok(LockRequest::new(resource.clone(), lock_type, self.owner.clone())
+                    .with_lock_id(LockId {
+                        resource,
+                        uuid: self.uid.clone(),
+                    })
+                    .with_metadata(metadata))
+            })
+            .collect()
+    }
+}
+
+/// Gathers the requests of one lock back into dsync args.
+///
+/// Every request must share the uid, owner and client info; anything else
+/// is not one dsync lock and is rejected.
+impl TryFrom<&[LockRequest]> for DsyncLockArgs {
+    type Error = LockError;
+
+    fn try_from(requests: &[LockRequest]) -> Result<Self> {
+        let Some(first) = requests.first() else {
+            return Err(LockError::invalid_request("dsync lock args need at least one resource"));
+        };
+        let source = first.metadata.client_info.clone().unwrap_or_default();
+        let mut resources = Vec::with_capacity(requests.len());
+        for request in requests {
+            if request.lock_id.uuid != first.lock_id.uuid
+                || request.owner != first.owner
+                || request.metadata.client_info.as_deref().unwrap_or_default() != source
+            {
+                return Err(LockError::invalid_request(
+                    "dsync lock args need one uid, owner and source across all resources",
+                ));
+            }
+            resources.push(format!("{}/{}", request.resource.bucket, request.resource.object));
+        }
+        Ok(Self {
+            uid: first.lock_id.uuid.clone(),
+            resources,
+            owner: first.owner.clone(),
+            source,
+            quorum: None,
+        })
+    }
+}
+
+/// `bucket/object` as a key on the latest version
+fn parse_resource(path: &str) -> Result<ObjectKey> {
+    match path.trim_start_matches('/').split_once('/') {
+        Some((bucket, object)) if !bucket.is_empty() && !object.is_empty() => Ok(ObjectKey::new(bucket, object)),
+        _ => Err(LockError::invalid_request(format!("dsync resource {path:?} is not a bucket/object path"))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dsync_lock_args_round_trip_through_lock_requests() {
+        let payload = r#"{"UID":"6f1c","Resources":["photos/2024/a.jpg","photos/b.jpg"],"Owner":"node-1","Source":"[erasure.go:120:putObject()]","Quorum":3}"#;
+        let args: DsyncLockArgs = serde_json::from_str(payload).unwrap();
+        assert_eq!(args.quorum, Some(3));
+
+        let requests = args.to_requests(LockType::Exclusive).unwrap();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].resource, ObjectKey::new("photos", "2024/a.jpg"));
+        assert!(
+            requests
+                .iter()
+                .all(|request| request.lock_id.uuid == "6f1c" && request.owner == "node-1")
+        );
+        assert_eq!(requests[1].metadata.client_info.as_deref(), Some("[erasure.go:120:putObject()]"));
+        assert!(requests.iter().all(|request| request.validate().is_ok()));
+
+        // Quorum is the only field that does not survive the trip.
+        let back = DsyncLockArgs::try_from(requests.as_slice()).unwrap();
+        assert_eq!(back, DsyncLockArgs { quorum: None, ..args });
+        assert!(!serde_json::to_string(&back).unwrap().contains("Quorum"));
+
+        let bad = DsyncLockArgs {
+            resources: vec!["no-object".to_string()],
+            ..Default::default()
+        };
+        assert!(matches!(bad.to_requests(LockType::Shared), Err(LockError::InvalidRequest { .. })));
+    }
+}
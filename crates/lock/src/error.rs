@@ -86,6 +86,39 @@ pub enum LockError {
     /// Not the lock owner
     #[error("Not the lock owner: lock_id {lock_id}, owner {owner}")]
     NotOwner { lock_id: LockId, owner: String },
+
+    /// Client has been closed
+    #[error("Lock client is closed")]
+    ClientClosed,
+
+    /// Malformed lock request
+    #[error("Invalid lock request: {reason}")]
+    InvalidRequest { reason: String },
+
+    /// Owner exceeded its acquisition rate limit
+    #[error("Lock acquisitions rate limited for owner {owner}")]
+    RateLimited { owner: String },
+
+    /// Client already holds its configured maximum number of locks
+    #[error("Lock table full: {max_locks} locks held")]
+    Capacity { max_locks: usize },
+
+    /// Operation carried an owner epoch older than one already seen for that owner
+    #[error("Stale epoch for owner {owner}: {epoch} is older than {current}")]
+    StaleEpoch { owner: String, epoch: u64, current: u64 },
+
+    /// Owner's held locks would weigh more than its configured budget
+    #[error("Weight budget exceeded for owner {owner}: {requested} requested, {held} of {budget} held")]
+    WeightExceeded {
+        owner: String,
+        requested: u64,
+        held: u64,
+        budget: u64,
+    },
+
+    /// Client is draining for shutdown and takes no new locks
+    #[error("Lock client is draining")]
+    Draining,
 }
 
 impl Clone for LockError {
@@ -139,6 +172,27 @@ impl Clone for LockError {
                 lock_id: lock_id.clone(),
                 owner: owner.clone(),
             },
+            LockError::ClientClosed => LockError::ClientClosed,
+            LockError::InvalidRequest { reason } => LockError::InvalidRequest { reason: reason.clone() },
+            LockError::RateLimited { owner } => LockError::RateLimited { owner: owner.clone() },
+            LockError::Capacity { max_locks } => LockError::Capacity { max_locks: *max_locks },
+            LockError::StaleEpoch { owner, epoch, current } => LockError::StaleEpoch {
+                owner: owner.clone(),
+                epoch: *epoch,
+                current: *current,
+            },
+            LockError::WeightExceeded {
+                owner,
+                requested,
+                held,
+                budget,
+            } => LockError::WeightExceeded {
+                owner: owner.clone(),
+                requested: *requested,
+                held: *held,
+                budget: *budget,
+            },
+            LockError::Draining => LockError::Draining,
         }
     }
 }
@@ -213,16 +267,71 @@ impl LockError {
         }
     }
 
+    /// Create client closed error
+    pub fn client_closed() -> Self {
+        Self::ClientClosed
+    }
+
+    /// Create invalid request error
+    pub fn invalid_request(reason: impl Into<String>) -> Self {
+        Self::InvalidRequest { reason: reason.into() }
+    }
+
+    /// Create rate limited error
+    pub fn rate_limited(owner: impl Into<String>) -> Self {
+        Self::RateLimited { owner: owner.into() }
+    }
+
+    /// Create lock table capacity error
+    pub fn capacity(max_locks: usize) -> Self {
+        Self::Capacity { max_locks }
+    }
+
+    /// Create draining error
+    pub fn draining() -> Self {
+        Self::Draining
+    }
+
+    /// Create stale owner epoch error
+    pub fn stale_epoch(owner: impl Into<String>, epoch: u64, current: u64) -> Self {
+        Self::StaleEpoch {
+            owner: owner.into(),
+            epoch,
+            current,
+        }
+    }
+
+    /// Create weight budget exceeded error
+    pub fn weight_exceeded(owner: impl Into<String>, requested: u64, held: u64, budget: u64) -> Self {
+        Self::WeightExceeded {
+            owner: owner.into(),
+            requested,
+            held,
+            budget,
+        }
+    }
+
     /// Check if it is a retryable error
     pub fn is_retryable(&self) -> bool {
-        matches!(self, Self::Timeout { .. } | Self::Network { .. } | Self::Internal { .. })
+        matches!(
+            self,
+            Self::Timeout { .. }
+                | Self::Network { .. }
+                | Self::Internal { .. }
+                | Self::RateLimited { .. }
+                | Self::Capacity { .. }
+                | Self::WeightExceeded { .. }
+        )
     }
 
     /// Check if it is a fatal error
     pub fn is_fatal(&self) -> bool {
         matches!(
             self,
-            Self::ResourceNotFound { .. } | Self::PermissionDenied { .. } | Self::Configuration { .. }
+            Self::ResourceNotFound { .. }
+                | Self::PermissionDenied { .. }
+                | Self::Configuration { .. }
+                | Self::InvalidRequest { .. }
         )
     }
 }
@@ -84,6 +84,48 @@ impl FastLockGuard {
         &self.owner
     }
 
+    /// Hand this lock over to `new_owner` without releasing it.
+    ///
+    /// The guard keeps protecting the object and releases it as the new
+    /// owner. Returns false if the lock is no longer held by this guard.
+    pub fn transfer(&mut self, new_owner: impl Into<Arc<str>>) -> bool {
+        if self.released || self.disabled {
+            return false;
+        }
+        let Some(shard) = &self.shard else {
+            return false;
+        };
+
+        let new_owner = new_owner.into();
+        if !shard.transfer_owner(&self.key, &self.owner, &new_owner) {
+            return false;
+        }
+        self.owner = new_owner;
+        true
+    }
+
+    /// Promote this read lock to a write lock, waiting for other readers until `deadline`.
+    ///
+    /// Succeeds at once when this guard holds the only read lock. Returns
+    /// false on timeout, leaving the read lock held, and for guards that are
+    /// already exclusive, released or disabled.
+    pub async fn upgrade_until(&mut self, deadline: std::time::Instant) -> bool {
+        if self.mode != LockMode::Shared || self.released || self.disabled {
+            return false;
+        }
+        let Some(shard) = &self.shard else {
+            return false;
+        };
+
+        if !shard.upgrade_until(&self.key, &self.owner, deadline).await {
+            return false;
+        }
+        record_lock_held_release(LockMode::Shared);
+        record_lock_held_acquire(LockMode::Exclusive);
+        self.mode = LockMode::Exclusive;
+        true
+    }
+
     /// Manually release the lock early
     ///
     /// Returns true if the lock was successfully released, false if it was
@@ -249,13 +291,25 @@ impl MultipleLockGuards {
     ///
     /// Returns the number of locks successfully released.
     pub fn release_all(&mut self) -> usize {
-        let mut released_count = 0;
-        for guard in &mut self.guards {
-            if guard.release() {
-                released_count += 1;
-            }
-        }
-        released_count
+        self.release_all_detailed()
+            .into_iter()
+            .filter(|(_, released)| *released)
+            .count()
+    }
+
+    /// Release all locks manually, reporting the outcome for each object.
+    ///
+    /// An entry is false when that lock was not released by this call, e.g.
+    /// because it was already released or force-unlocked, so cleanup code can
+    /// tell exactly which objects still need attention.
+    pub fn release_all_detailed(&mut self) -> Vec<(ObjectKey, bool)> {
+        self.guards
+            .iter_mut()
+            .map(|guard| {
+                let released = guard.release();
+                (guard.key().clone(), released)
+            })
+            .collect()
     }
 
     /// Check how many locks are still held
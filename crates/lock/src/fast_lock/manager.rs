@@ -22,8 +22,9 @@ use crate::fast_lock::{
     guard::FastLockGuard,
     manager_trait::LockManager,
     metrics::{AggregatedMetrics, GlobalMetrics},
+    priority::PriorityInheritance,
     shard::LockShard,
-    types::{BatchLockRequest, BatchLockResult, LockConfig, LockResult, ObjectKey, ObjectLockInfo, ObjectLockRequest},
+    types::{BatchLockRequest, BatchLockResult, LockConfig, LockMode, LockResult, ObjectKey, ObjectLockInfo, ObjectLockRequest},
 };
 
 /// High-performance object lock manager
@@ -33,6 +34,7 @@ pub struct FastObjectLockManager {
     shard_mask: usize,
     config: LockConfig,
     metrics: Arc<GlobalMetrics>,
+    priority_inheritance: Arc<PriorityInheritance>,
     cleanup_handle: RwLock<Option<tokio::task::JoinHandle<()>>>,
 }
 
@@ -70,13 +72,19 @@ impl FastObjectLockManager {
                 config.shard_count
             )));
         }
+        if config.max_batch == 0 {
+            return Err(LockError::configuration("max batch size must be non-zero"));
+        }
         Ok(())
     }
 
     fn build(config: LockConfig) -> Self {
         let shard_count = config.shard_count;
 
-        let shards: Vec<Arc<LockShard>> = (0..shard_count).map(|i| Arc::new(LockShard::new(i))).collect();
+        let priority_inheritance = Arc::new(PriorityInheritance::new());
+        let shards: Vec<Arc<LockShard>> = (0..shard_count)
+            .map(|i| Arc::new(LockShard::with_config(i, config.clone()).with_priority_inheritance(priority_inheritance.clone())))
+            .collect();
 
         let metrics = Arc::new(GlobalMetrics::new(shard_count));
 
@@ -85,6 +93,7 @@ impl FastObjectLockManager {
             shard_mask: shard_count - 1,
             config,
             metrics,
+            priority_inheritance,
             cleanup_handle: RwLock::new(None),
         };
 
@@ -160,7 +169,16 @@ impl FastObjectLockManager {
     }
 
     /// Acquire multiple locks atomically - optimized version
+    ///
+    /// A key named more than once is locked once, in the strongest mode any
+    /// of its requests asked for. Batches naming an empty bucket or object,
+    /// or more than the configured `max_batch` distinct objects, are rejected
+    /// without locking anything.
     pub async fn acquire_locks_batch(&self, batch_request: BatchLockRequest) -> BatchLockResult {
+        if let Some(rejected) = Self::reject_invalid_keys(&batch_request.requests) {
+            return rejected;
+        }
+
         // Pre-sort requests by (shard_id, key) to avoid deadlocks
         let mut sorted_requests = batch_request.requests;
         sorted_requests.sort_unstable_by(|a, b| {
@@ -168,6 +186,24 @@ impl FastObjectLockManager {
             let shard_b = b.key.shard_index(self.shard_mask);
             shard_a.cmp(&shard_b).then_with(|| a.key.cmp(&b.key))
         });
+        // Duplicates are now adjacent; a second request for a key this owner
+        // already locked in the batch would otherwise block on itself.
+        sorted_requests.dedup_by(|duplicate, kept| {
+            if duplicate.key != kept.key {
+                return false;
+            }
+            if duplicate.mode == LockMode::Exclusive {
+                kept.mode = LockMode::Exclusive;
+            }
+            kept.priority = kept.priority.max(duplicate.priority);
+            kept.acquire_timeout = kept.acquire_timeout.max(duplicate.acquire_timeout);
+            true
+        });
+
+        if sorted_requests.len() > self.config.max_batch {
+            let keys = sorted_requests.into_iter().map(|request| request.key).collect();
+            return Self::batch_limit_exceeded(keys, self.config.max_batch);
+        }
 
         // Preserve shard order so every concurrent batch acquires locks in the same global order.
         let shard_groups = self.group_requests_by_shard(sorted_requests);
@@ -180,6 +216,66 @@ impl FastObjectLockManager {
         }
     }
 
+    /// Acquire shared locks on every key or on none of them.
+    ///
+    /// Each read waits up to the configured default acquire timeout; as soon
+    /// as one key cannot be read-locked (typically because it is write-locked)
+    /// every read lock already taken is rolled back. Batches larger than the
+    /// configured `max_batch` are rejected without locking anything.
+    pub async fn acquire_read_locks_batch(&self, keys: Vec<ObjectKey>, owner: impl Into<Arc<str>>) -> BatchLockResult {
+        let owner = owner.into();
+        let mut batch = BatchLockRequest::new(owner.clone());
+        batch.requests = keys
+            .into_iter()
+            .map(|key| ObjectLockRequest::new_read(key, owner.clone()).with_acquire_timeout(self.config.default_acquire_timeout))
+            .collect();
+        self.acquire_locks_batch(batch).await
+    }
+
+    /// Reject a batch naming an empty bucket or object, reporting each key.
+    fn reject_invalid_keys(requests: &[ObjectLockRequest]) -> Option<BatchLockResult> {
+        let invalid_reason = |key: &ObjectKey| {
+            if key.bucket.is_empty() {
+                Some("empty bucket name")
+            } else if key.object.is_empty() {
+                Some("empty object name")
+            } else {
+                None
+            }
+        };
+        if !requests.iter().any(|request| invalid_reason(&request.key).is_some()) {
+            return None;
+        }
+
+        let failed_locks = requests
+            .iter()
+            .map(|request| {
+                let reason = invalid_reason(&request.key).unwrap_or("batch contains an invalid key");
+                (request.key.clone(), LockResult::InvalidKey { reason })
+            })
+            .collect();
+        Some(BatchLockResult {
+            successful_locks: Vec::new(),
+            failed_locks,
+            all_acquired: false,
+            guards: Vec::new(),
+        })
+    }
+
+    /// Result for a batch rejected up front for naming more than `max` objects
+    fn batch_limit_exceeded(keys: Vec<ObjectKey>, max: usize) -> BatchLockResult {
+        let requested = keys.len();
+        BatchLockResult {
+            successful_locks: Vec::new(),
+            failed_locks: keys
+                .into_iter()
+                .map(|key| (key, LockResult::BatchLimitExceeded { requested, max }))
+                .collect(),
+            all_acquired: false,
+            guards: Vec::new(),
+        }
+    }
+
     /// Group requests by shard with proper fallback handling
     fn group_requests_by_shard(&self, requests: Vec<ObjectLockRequest>) -> Vec<(usize, Vec<ObjectLockRequest>)> {
         let mut shard_groups: Vec<(usize, Vec<ObjectLockRequest>)> = Vec::new();
@@ -290,6 +386,16 @@ impl FastObjectLockManager {
         shard.get_lock_info(key)
     }
 
+    /// Whether `owner` currently holds `key` in `mode`
+    pub fn is_held_by(&self, key: &ObjectKey, owner: &str, mode: LockMode) -> bool {
+        self.get_shard(key).is_held_by(key, owner, mode)
+    }
+
+    /// Current holders of `key`: the writer, or one entry per reading owner
+    pub fn lock_holders(&self, key: &ObjectKey) -> Vec<crate::fast_lock::types::ObjectLockInfo> {
+        self.get_shard(key).holders(key)
+    }
+
     /// Enumerate every currently held lock across all shards.
     ///
     /// Powers the admin "top locks" view. Order is shard-then-insertion and is
@@ -302,6 +408,91 @@ impl FastObjectLockManager {
         infos
     }
 
+    /// Visit every currently held lock without copying the lock table.
+    ///
+    /// Each shard stays read-locked while its holders are visited, so
+    /// `visit` must not acquire or release locks on this manager.
+    pub fn for_each_lock<F>(&self, mut visit: F)
+    where
+        F: FnMut(crate::fast_lock::types::ObjectLockInfoRef<'_>),
+    {
+        for shard in &self.shards {
+            shard.for_each_lock(&mut visit);
+        }
+    }
+
+    /// Lazily enumerate held locks, materializing one shard at a time.
+    ///
+    /// Each shard's holders are still copied into an owned `Vec` when the
+    /// iterator reaches it, so this allocates per entry like
+    /// [`Self::list_locks`]; it only bounds the copy to one shard, and
+    /// stopping early skips the remaining shards entirely. Use
+    /// [`Self::for_each_lock`] to inspect the table without allocating.
+    pub fn iter_locks(&self) -> impl Iterator<Item = crate::fast_lock::types::ObjectLockInfo> + '_ {
+        self.shards.iter().flat_map(|shard| shard.list_locks())
+    }
+
+    /// Enumerate held locks grouped per object, optionally with their waiters.
+    ///
+    /// With `include_waiters` each report also lists the requests blocked on
+    /// that object and how long they have waited, so one call shows both who
+    /// holds and who is stuck behind them.
+    pub fn list_locks_detailed(&self, include_waiters: bool) -> Vec<crate::fast_lock::types::ObjectLockReport> {
+        let mut reports = Vec::new();
+        for shard in &self.shards {
+            reports.extend(shard.lock_reports(include_waiters));
+        }
+        reports
+    }
+
+    /// Wait-queue depth and oldest wait per contended object, plus the total.
+    ///
+    /// Shows contention hotspots before they turn into acquire timeouts.
+    pub fn queue_report(&self) -> crate::fast_lock::types::QueueReport {
+        let mut resources: Vec<_> = self
+            .list_locks_detailed(true)
+            .into_iter()
+            .filter(|report| !report.waiters.is_empty())
+            .map(|report| crate::fast_lock::types::ResourceQueue {
+                depth: report.waiters.len(),
+                longest_wait: report.waiters.iter().map(|waiter| waiter.waited).max().unwrap_or_default(),
+                key: report.key,
+            })
+            .collect();
+        resources.sort_by(|a, b| b.depth.cmp(&a.depth).then_with(|| a.key.cmp(&b.key)));
+        let total_queued = resources.iter().map(|queue| queue.depth).sum();
+        crate::fast_lock::types::QueueReport { resources, total_queued }
+    }
+
+    /// Priority `owner` currently inherits from higher-priority requests blocked on its locks.
+    ///
+    /// While set, the owner's own contended acquisitions are scheduled at this
+    /// priority. Returns `None` when nothing is boosting the owner.
+    pub fn inherited_priority(&self, owner: &str) -> Option<crate::fast_lock::types::LockPriority> {
+        self.priority_inheritance.inherited_priority(owner)
+    }
+
+    /// Cancel every request blocked on `key` without touching its holders.
+    ///
+    /// Each cancelled acquire returns [`LockResult::Cancelled`]. Useful to
+    /// clear a pile-up before force-unlocking a stuck object. Returns the
+    /// number of waiters cancelled.
+    pub fn cancel_waiters(&self, key: &ObjectKey) -> usize {
+        let shard = self.get_shard(key);
+        shard.cancel_waiters(key)
+    }
+
+    /// Reassign one lock `from` holds on `key` to `to` without releasing it.
+    ///
+    /// Used to hand locks over while draining a node. Returns false if `from`
+    /// holds no lock on `key`. Guards issued to `from` keep releasing as
+    /// `from` and will fail; prefer [`FastLockGuard::transfer`] when the guard
+    /// is at hand.
+    pub fn transfer(&self, key: &ObjectKey, from: &str, to: impl Into<Arc<str>>) -> bool {
+        let shard = self.get_shard(key);
+        shard.transfer_owner(key, from, &to.into())
+    }
+
     /// Force-release every holder of the lock on `key`.
     ///
     /// Returns the number of owners released (0 if the resource was not locked).
@@ -435,6 +626,7 @@ impl Clone for FastObjectLockManager {
             shard_mask: self.shard_mask,
             config: self.config.clone(),
             metrics: self.metrics.clone(),
+            priority_inheritance: self.priority_inheritance.clone(),
             cleanup_handle: RwLock::new(None), // Don't clone the cleanup task
         }
     }
@@ -594,4 +786,161 @@ mod tests {
 
         manager.shutdown().await;
     }
+
+    #[tokio::test]
+    async fn test_list_locks_detailed_includes_waiters() {
+        let manager = Arc::new(FastObjectLockManager::new());
+        let key = ObjectKey::new("bucket", "contended");
+
+        let guard = manager
+            .acquire_write_lock(key.clone(), "holder")
+            .await
+            .expect("write lock should acquire");
+
+        let first = {
+            let manager = manager.clone();
+            let key = key.clone();
+            tokio::spawn(async move { manager.acquire_write_lock(key, "waiter-1").await.map(|_| ()) })
+        };
+        let wait_for_waiters = |count: usize| {
+            let manager = manager.clone();
+            async move {
+                tokio::time::timeout(std::time::Duration::from_secs(2), async {
+                    while manager.list_locks_detailed(true).first().map_or(0, |r| r.waiters.len()) < count {
+                        tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+                    }
+                })
+                .await
+                .expect("waiters should register");
+            }
+        };
+        wait_for_waiters(1).await;
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+
+        let second = {
+            let manager = manager.clone();
+            let key = key.clone();
+            tokio::spawn(async move { manager.acquire_read_lock(key, "waiter-2").await.map(|_| ()) })
+        };
+        wait_for_waiters(2).await;
+
+        let reports = manager.list_locks_detailed(true);
+        assert_eq!(reports.len(), 1);
+        let report = &reports[0];
+        assert_eq!(report.key, key);
+        assert_eq!(report.holders.len(), 1);
+        assert_eq!(report.holders[0].owner.as_ref(), "holder");
+
+        assert_eq!(report.waiters.len(), 2);
+        assert_eq!(report.waiters[0].owner.as_ref(), "waiter-1");
+        assert_eq!(report.waiters[0].mode, LockMode::Exclusive);
+        assert_eq!(report.waiters[1].owner.as_ref(), "waiter-2");
+        assert_eq!(report.waiters[1].mode, LockMode::Shared);
+        assert!(report.waiters[0].waited >= std::time::Duration::from_millis(50));
+        assert!(report.waiters[0].waited > report.waiters[1].waited);
+
+        // Without the flag only holders are reported.
+        let reports = manager.list_locks_detailed(false);
+        assert!(reports[0].waiters.is_empty());
+        assert_eq!(reports[0].holders.len(), 1);
+
+        drop(guard);
+        assert!(first.await.expect("first waiter task").is_ok());
+        assert!(second.await.expect("second waiter task").is_ok());
+        assert!(
+            manager
+                .list_locks_detailed(true)
+                .iter()
+                .all(|report| report.waiters.is_empty())
+        );
+
+        manager.shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn test_queue_report_counts_waiters_and_oldest_wait() {
+        let manager = Arc::new(FastObjectLockManager::new());
+        let key = ObjectKey::new("bucket", "queued");
+        let guard = manager
+            .acquire_write_lock(key.clone(), "holder")
+            .await
+            .expect("write lock should acquire");
+        assert_eq!(manager.queue_report().total_queued, 0);
+
+        let mut waiters = Vec::new();
+        for i in 0..3 {
+            let waiter_manager = manager.clone();
+            let key = key.clone();
+            waiters.push(tokio::spawn(async move {
+                waiter_manager
+                    .acquire_write_lock(key, format!("waiter-{i}"))
+                    .await
+                    .map(|_| ())
+            }));
+            tokio::time::timeout(std::time::Duration::from_secs(2), async {
+                while manager.queue_report().total_queued <= i {
+                    tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+                }
+            })
+            .await
+            .expect("waiter should register");
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(30)).await;
+
+        let report = manager.queue_report();
+        assert_eq!(report.total_queued, 3);
+        assert_eq!(report.resources.len(), 1);
+        assert_eq!(report.resources[0].key, key);
+        assert_eq!(report.resources[0].depth, 3);
+        assert!(report.resources[0].longest_wait >= std::time::Duration::from_millis(30));
+
+        drop(guard);
+        for waiter in waiters {
+            assert!(waiter.await.expect("waiter task").is_ok());
+        }
+        assert_eq!(manager.queue_report(), crate::fast_lock::types::QueueReport::default());
+
+        manager.shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn test_cancel_waiters_leaves_holder_untouched() {
+        let manager = Arc::new(FastObjectLockManager::new());
+        let key = ObjectKey::new("bucket", "pile-up");
+
+        let _guard = manager
+            .acquire_write_lock(key.clone(), "holder")
+            .await
+            .expect("write lock should acquire");
+
+        let waiters: Vec<_> = (0..3)
+            .map(|i| {
+                let manager = manager.clone();
+                let key = key.clone();
+                tokio::spawn(async move { manager.acquire_write_lock(key, format!("waiter-{i}")).await.map(|_| ()) })
+            })
+            .collect();
+        tokio::time::timeout(std::time::Duration::from_secs(2), async {
+            while manager.list_locks_detailed(true).first().map_or(0, |r| r.waiters.len()) < 3 {
+                tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+            }
+        })
+        .await
+        .expect("waiters should register");
+
+        assert_eq!(manager.cancel_waiters(&key), 3);
+        for waiter in waiters {
+            let result = tokio::time::timeout(std::time::Duration::from_secs(1), waiter)
+                .await
+                .expect("cancelled waiter should return promptly")
+                .expect("waiter task");
+            assert!(matches!(result, Err(LockResult::Cancelled)));
+        }
+
+        let info = manager.get_lock_info(&key).expect("holder should keep the lock");
+        assert_eq!(info.owner.as_ref(), "holder");
+        assert_eq!(manager.cancel_waiters(&key), 0);
+
+        manager.shutdown().await;
+    }
 }
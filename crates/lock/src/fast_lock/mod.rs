@@ -31,6 +31,7 @@ pub mod manager_trait;
 pub mod metrics;
 pub mod object_pool;
 pub mod optimized_notify;
+pub mod priority;
 pub mod shard;
 pub mod state;
 pub mod types;
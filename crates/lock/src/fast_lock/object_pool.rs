@@ -98,6 +98,9 @@ impl ObjectLockState {
         // Clear owners
         *self.current_owner.write() = None;
         self.shared_owners.write().clear();
+        self.waiters.lock().clear();
+        *self.top_waiter_priority.get_mut() = 0;
+        *self.starving_writers.get_mut() = 0;
 
         // Reset priority
         *self.priority.write() = crate::fast_lock::types::LockPriority::Normal;
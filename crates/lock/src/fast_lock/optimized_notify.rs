@@ -88,6 +88,14 @@ impl OptimizedNotify {
         }
     }
 
+    /// Wake every waiting reader and writer
+    pub fn notify_all(&self) {
+        if self.has_waiters() {
+            let pool_index = self.notify_pool_index.load(Ordering::Relaxed) % NOTIFY_POOL.len();
+            NOTIFY_POOL[pool_index].notify_waiters();
+        }
+    }
+
     /// Wait for reader notification
     pub async fn wait_for_read(&self) {
         // RAII guard decrements the counter even if this future is dropped at
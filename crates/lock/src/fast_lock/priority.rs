@@ -0,0 +1,233 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Priority inheritance for lock holders
+//!
+//! When a high-priority request blocks on an object held by a lower-priority
+//! owner, the holder inherits the waiter's priority for as long as the waiter
+//! stays blocked. The boost applies to the holder's own contended
+//! acquisitions elsewhere: they stop yielding to waiters below the inherited
+//! priority, and they re-poll on the short high-priority backoff (2ms base
+//! instead of 10ms). Nothing else about the holder's scheduling changes.
+
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+use crate::fast_lock::types::LockPriority;
+
+/// Independent slices of the boost tables, so unrelated waiters and holders
+/// do not serialize on one lock
+const BOOST_SHARDS: usize = 16;
+
+/// What one blocked waiter currently lends out
+#[derive(Debug)]
+struct WaiterBoost {
+    priority: LockPriority,
+    holders: Vec<Arc<str>>,
+}
+
+/// How many waiters lend each priority level to one holder
+#[derive(Debug, Default)]
+struct HolderBoost {
+    counts: [u32; 4],
+}
+
+impl HolderBoost {
+    fn slot(priority: LockPriority) -> usize {
+        priority as usize - 1
+    }
+
+    fn add(&mut self, priority: LockPriority) {
+        self.counts[Self::slot(priority)] += 1;
+    }
+
+    /// Drop one boost at `priority`, returning whether any remain
+    fn remove(&mut self, priority: LockPriority) -> bool {
+        let count = &mut self.counts[Self::slot(priority)];
+        *count = count.saturating_sub(1);
+        self.counts.iter().any(|&count| count > 0)
+    }
+
+    fn max(&self) -> Option<LockPriority> {
+        [
+            LockPriority::Critical,
+            LockPriority::High,
+            LockPriority::Normal,
+            LockPriority::Low,
+        ]
+        .into_iter()
+        .find(|&priority| self.counts[Self::slot(priority)] > 0)
+    }
+}
+
+/// Boosts granted to holders.
+///
+/// Each waiter's boost is indexed by its id and each holder keeps a count per
+/// priority, so updating or clearing a waiter touches only the holders it
+/// boosts. Waiter shards are always locked before holder shards.
+#[derive(Debug)]
+pub struct PriorityInheritance {
+    waiters: [Mutex<HashMap<u64, WaiterBoost>>; BOOST_SHARDS],
+    holders: [Mutex<HashMap<Arc<str>, HolderBoost>>; BOOST_SHARDS],
+}
+
+impl Default for PriorityInheritance {
+    fn default() -> Self {
+        Self {
+            waiters: std::array::from_fn(|_| Mutex::new(HashMap::new())),
+            holders: std::array::from_fn(|_| Mutex::new(HashMap::new())),
+        }
+    }
+}
+
+impl PriorityInheritance {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record that `waiter_id` with `priority` is blocked on `holders`.
+    ///
+    /// Replaces any holders previously recorded for the same waiter, so the
+    /// boost follows the lock if it changes hands while the waiter waits.
+    /// Waiters at `Normal` priority or below never boost anyone.
+    pub fn boost_holders(&self, waiter_id: u64, priority: LockPriority, holders: &[Arc<str>]) {
+        let mut waiters = self.waiters[Self::waiter_shard(waiter_id)].lock();
+        if priority <= LockPriority::Normal {
+            if let Some(previous) = waiters.remove(&waiter_id) {
+                self.unboost(&previous);
+            }
+            return;
+        }
+        // A waiter re-polling against the same holders changes nothing.
+        if waiters
+            .get(&waiter_id)
+            .is_some_and(|current| current.priority == priority && current.holders == holders)
+        {
+            return;
+        }
+
+        let boost = WaiterBoost {
+            priority,
+            holders: holders.to_vec(),
+        };
+        for holder in &boost.holders {
+            self.holders[Self::holder_shard(holder)]
+                .lock()
+                .entry(holder.clone())
+                .or_default()
+                .add(priority);
+        }
+        if let Some(previous) = waiters.insert(waiter_id, boost) {
+            self.unboost(&previous);
+        }
+    }
+
+    /// Drop every boost contributed by `waiter_id`
+    pub fn clear_waiter(&self, waiter_id: u64) {
+        let previous = self.waiters[Self::waiter_shard(waiter_id)].lock().remove(&waiter_id);
+        if let Some(previous) = previous {
+            self.unboost(&previous);
+        }
+    }
+
+    /// Highest priority currently inherited by `owner`, if any
+    pub fn inherited_priority(&self, owner: &str) -> Option<LockPriority> {
+        self.holders[Self::holder_shard(owner)]
+            .lock()
+            .get(owner)
+            .and_then(HolderBoost::max)
+    }
+
+    /// Priority `owner` should be scheduled at for a request made at `base`
+    pub fn effective_priority(&self, owner: &str, base: LockPriority) -> LockPriority {
+        self.inherited_priority(owner).map_or(base, |inherited| inherited.max(base))
+    }
+
+    /// Take back the boosts `boost` lent to its holders
+    fn unboost(&self, boost: &WaiterBoost) {
+        for holder in &boost.holders {
+            let mut holders = self.holders[Self::holder_shard(holder)].lock();
+            if let Some(entry) = holders.get_mut(holder.as_ref())
+                && !entry.remove(boost.priority)
+            {
+                holders.remove(holder.as_ref());
+            }
+        }
+    }
+
+    fn waiter_shard(waiter_id: u64) -> usize {
+        waiter_id as usize % BOOST_SHARDS
+    }
+
+    fn holder_shard(owner: &str) -> usize {
+        let mut hasher = DefaultHasher::new();
+        owner.hash(&mut hasher);
+        hasher.finish() as usize % BOOST_SHARDS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_boost_follows_waiter_lifetime() {
+        let inheritance = PriorityInheritance::new();
+        let holder: Arc<str> = Arc::from("holder");
+
+        assert_eq!(inheritance.effective_priority("holder", LockPriority::Low), LockPriority::Low);
+
+        inheritance.boost_holders(1, LockPriority::High, std::slice::from_ref(&holder));
+        inheritance.boost_holders(2, LockPriority::Critical, std::slice::from_ref(&holder));
+        assert_eq!(inheritance.effective_priority("holder", LockPriority::Low), LockPriority::Critical);
+
+        inheritance.clear_waiter(2);
+        assert_eq!(inheritance.effective_priority("holder", LockPriority::Low), LockPriority::High);
+
+        // Re-pointing a waiter at a new holder moves its boost.
+        inheritance.boost_holders(1, LockPriority::High, &[Arc::from("next-holder")]);
+        assert_eq!(inheritance.inherited_priority("holder"), None);
+        assert_eq!(inheritance.inherited_priority("next-holder"), Some(LockPriority::High));
+
+        // Normal-priority waiters never boost.
+        inheritance.boost_holders(3, LockPriority::Normal, std::slice::from_ref(&holder));
+        assert_eq!(inheritance.inherited_priority("holder"), None);
+    }
+
+    #[test]
+    fn test_clearing_a_waiter_only_touches_its_own_holders() {
+        let inheritance = PriorityInheritance::new();
+        let a: Arc<str> = Arc::from("holder-a");
+        let b: Arc<str> = Arc::from("holder-b");
+
+        inheritance.boost_holders(1, LockPriority::High, &[a.clone(), b.clone()]);
+        inheritance.boost_holders(2, LockPriority::Critical, std::slice::from_ref(&b));
+
+        // Re-polling with the same holders must not stack a second boost.
+        inheritance.boost_holders(1, LockPriority::High, &[a, b.clone()]);
+        inheritance.clear_waiter(1);
+        assert_eq!(inheritance.inherited_priority("holder-a"), None);
+        assert_eq!(inheritance.inherited_priority("holder-b"), Some(LockPriority::Critical));
+
+        // Dropping to Normal withdraws the waiter's boost like clearing it.
+        inheritance.boost_holders(2, LockPriority::Normal, std::slice::from_ref(&b));
+        assert_eq!(inheritance.inherited_priority("holder-b"), None);
+
+        // Clearing an unknown waiter is a no-op.
+        inheritance.clear_waiter(3);
+    }
+}
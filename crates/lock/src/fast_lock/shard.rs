@@ -15,14 +15,16 @@
 use parking_lot::RwLock;
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::sync::atomic::Ordering;
 use std::time::{Duration, Instant, SystemTime};
 use tokio::time::timeout;
 
 use crate::fast_lock::{
     metrics::ShardMetrics,
     object_pool::ObjectStatePool,
+    priority::PriorityInheritance,
     state::ObjectLockState,
-    types::{LockMode, LockResult, ObjectKey, ObjectLockRequest},
+    types::{LockConfig, LockMode, LockPolicy, LockPriority, LockResult, ObjectKey, ObjectLockRequest},
 };
 use std::collections::HashSet;
 
@@ -39,6 +41,10 @@ pub struct LockShard {
     _shard_id: usize,
     /// Active guard IDs to prevent cleanup of locks with live guards
     active_guards: parking_lot::Mutex<HashSet<u64>>,
+    /// Manager configuration the shard enforces per-request limits from
+    config: LockConfig,
+    /// Priority boosts shared by every shard of the owning manager
+    priority_inheritance: Arc<PriorityInheritance>,
 }
 
 /// Cancellation-safe waiter counter ticket.
@@ -77,33 +83,237 @@ impl Drop for WaiterCounterGuard {
     }
 }
 
+/// Cancellation-safe waiter registry ticket.
+///
+/// Keeps a blocked request visible in the object's waiter list for exactly as
+/// long as it is waiting, including when the waiting future is dropped.
+struct WaiterRegistration {
+    state: Arc<ObjectLockState>,
+    id: u64,
+    priority_inheritance: Arc<PriorityInheritance>,
+    /// Whether this waiter counts among the object's starving writers
+    starving: bool,
+}
+
+impl WaiterRegistration {
+    fn new(
+        state: Arc<ObjectLockState>,
+        request: &ObjectLockRequest,
+        since: Instant,
+        priority_inheritance: Arc<PriorityInheritance>,
+    ) -> Self {
+        let id = state.register_waiter(&request.owner, request.mode, request.priority, since);
+        Self {
+            state,
+            id,
+            priority_inheritance,
+            starving: false,
+        }
+    }
+
+    /// Count this writer as starving, holding back new readers until it is dropped
+    fn mark_starving(&mut self) {
+        if !self.starving {
+            self.starving = true;
+            self.state.starving_writers.fetch_add(1, Ordering::AcqRel);
+        }
+    }
+}
+
+impl Drop for WaiterRegistration {
+    fn drop(&mut self) {
+        self.state.unregister_waiter(self.id);
+        self.priority_inheritance.clear_waiter(self.id);
+        if self.starving {
+            self.state.starving_writers.fetch_sub(1, Ordering::AcqRel);
+        }
+    }
+}
+
 impl LockShard {
     pub fn new(shard_id: usize) -> Self {
+        Self::with_config(shard_id, LockConfig::default())
+    }
+
+    /// Create a shard that enforces the limits of the given manager config
+    pub fn with_config(shard_id: usize, config: LockConfig) -> Self {
         Self {
             objects: RwLock::new(HashMap::new()),
             object_pool: ObjectStatePool::new(),
             metrics: ShardMetrics::new(),
             _shard_id: shard_id,
             active_guards: parking_lot::Mutex::new(HashSet::new()),
+            config,
+            priority_inheritance: Arc::new(PriorityInheritance::new()),
         }
     }
 
+    /// Share priority boosts with the other shards of the same manager
+    pub(crate) fn with_priority_inheritance(mut self, priority_inheritance: Arc<PriorityInheritance>) -> Self {
+        self.priority_inheritance = priority_inheritance;
+        self
+    }
+
+    /// Priority `request` is scheduled at, including any boost inherited by its owner
+    fn effective_priority(&self, request: &ObjectLockRequest) -> LockPriority {
+        self.priority_inheritance.effective_priority(&request.owner, request.priority)
+    }
+
+    /// Delay before the next early retry; boosted and high-priority requests re-poll sooner
+    fn retry_backoff(priority: LockPriority, retry_count: u32) -> Duration {
+        let base_ms: u64 = if priority >= LockPriority::High { 2 } else { 10 };
+        Duration::from_millis(std::cmp::min(base_ms << retry_count, 100))
+    }
+
     /// Acquire lock with fast path optimization
     pub async fn acquire_lock(&self, request: &ObjectLockRequest) -> Result<(), LockResult> {
         let start_time = Instant::now();
 
+        self.check_reentrancy(request)?;
+
         // Try fast path first
         if let Some(_state) = self.try_fast_path(request) {
             self.metrics.record_fast_path_success();
             return Ok(());
         }
 
+        // A concurrent re-acquire by the same owner may have reached the limit since the first check.
+        self.check_reentrancy(request)?;
+        self.check_reader_capacity(request)?;
+
         // Slow path with waiting
         self.acquire_lock_slow_path(request, start_time).await
     }
 
+    /// Reject a shared re-acquire once the owner is at `max_reentrancy` holds.
+    ///
+    /// This only picks the error to report; the limit itself is enforced when
+    /// the hold is granted, in [`ObjectLockState::try_acquire_shared_fast_up_to`].
+    fn check_reentrancy(&self, request: &ObjectLockRequest) -> Result<(), LockResult> {
+        let max_depth = self.config.max_reentrancy;
+        if max_depth == 0 || request.mode != LockMode::Shared {
+            return Ok(());
+        }
+
+        let Some(state) = self.objects.read().get(&request.key).cloned() else {
+            return Ok(());
+        };
+        let depth = state.shared_depth(&request.owner);
+        if depth as usize >= max_depth {
+            tracing::warn!(
+                "Reentrant lock depth limit reached: key={}, owner={}, depth={}",
+                request.key,
+                request.owner,
+                depth
+            );
+            return Err(LockResult::ReentrancyLimitExceeded {
+                owner: request.owner.clone(),
+                depth,
+            });
+        }
+        Ok(())
+    }
+
+    /// Reject a shared request outright when the object is already at `max_readers`
+    fn check_reader_capacity(&self, request: &ObjectLockRequest) -> Result<(), LockResult> {
+        if self.config.max_readers == 0 || request.mode != LockMode::Shared {
+            return Ok(());
+        }
+
+        let Some(state) = self.objects.read().get(&request.key).cloned() else {
+            return Ok(());
+        };
+        if usize::from(state.atomic_state.reader_count()) < self.config.max_readers {
+            return Ok(());
+        }
+        let current_owner = state.shared_owners.read().first().map(|entry| entry.owner.clone());
+        match current_owner {
+            Some(current_owner) => Err(LockResult::Conflict {
+                current_owner,
+                current_mode: LockMode::Shared,
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Maximum concurrent shared holds representable for the configured `max_readers`
+    fn reader_limit(&self) -> u8 {
+        match self.config.max_readers {
+            0 => u8::MAX,
+            limit => u8::try_from(limit).unwrap_or(u8::MAX),
+        }
+    }
+
+    /// Most shared holds one owner may stack for the configured `max_reentrancy`
+    fn reentrancy_limit(&self) -> u32 {
+        match self.config.max_reentrancy {
+            0 => u32::MAX,
+            limit => u32::try_from(limit).unwrap_or(u32::MAX),
+        }
+    }
+
+    /// Attempt the atomic acquisition for `request` on an existing state.
+    ///
+    /// `queued` is the request's start time and waiter id once it is waiting.
+    fn try_acquire_state(&self, state: &ObjectLockState, request: &ObjectLockRequest, queued: Option<(Instant, u64)>) -> bool {
+        if self.yields_to_waiter(state, request) || self.defers_under_policy(state, request, queued) {
+            return false;
+        }
+        match request.mode {
+            LockMode::Shared => {
+                !self.holds_back_reader(state, request)
+                    && state.try_acquire_shared_fast_up_to(
+                        &request.owner,
+                        request.lock_timeout,
+                        self.reader_limit(),
+                        self.reentrancy_limit(),
+                    )
+            }
+            LockMode::Exclusive => state.try_acquire_exclusive_fast(&request.owner, request.lock_timeout),
+        }
+    }
+
+    /// Whether the configured [`LockPolicy`] makes `request` wait behind another waiter.
+    ///
+    /// Owners that already hold the object are never held back, so a
+    /// reentrant acquisition cannot wait on a waiter that waits on it.
+    fn defers_under_policy(&self, state: &ObjectLockState, request: &ObjectLockRequest, queued: Option<(Instant, u64)>) -> bool {
+        let defers = match self.config.policy {
+            // Write preference is enforced through the starving-writer count.
+            LockPolicy::ReadPreferring | LockPolicy::WritePreferring { .. } => false,
+            LockPolicy::Fair => state.has_earlier_conflicting_waiter(request.mode, queued),
+        };
+        defers && !state.holder_owners().contains(&request.owner)
+    }
+
+    /// Whether a new shared `request` must wait for a writer that has been denied too often
+    fn holds_back_reader(&self, state: &ObjectLockState, request: &ObjectLockRequest) -> bool {
+        matches!(self.config.policy, LockPolicy::WritePreferring { .. })
+            && state.starving_writers.load(Ordering::Acquire) != 0
+            && state.shared_depth(&request.owner) == 0
+    }
+
+    /// Whether a write request denied `denials` times in a row is now starving
+    fn is_starving(&self, request: &ObjectLockRequest, denials: usize) -> bool {
+        match self.config.policy {
+            LockPolicy::WritePreferring { after_denials } => request.mode == LockMode::Exclusive && denials >= after_denials,
+            LockPolicy::ReadPreferring | LockPolicy::Fair => false,
+        }
+    }
+
+    /// Whether `request` must leave the object to a higher-priority waiter.
+    ///
+    /// Inherited priority counts, so a holder boosted by the waiter it blocks
+    /// can still take further holds it needs to finish.
+    fn yields_to_waiter(&self, state: &ObjectLockState, request: &ObjectLockRequest) -> bool {
+        state.has_waiter_above(request.priority) && state.has_waiter_above(self.effective_priority(request))
+    }
+
     /// Try fast path only (without fallback to slow path)
     pub fn try_fast_path_only(&self, request: &ObjectLockRequest) -> bool {
+        if self.check_reentrancy(request).is_err() {
+            return false;
+        }
         // Early check to avoid unnecessary lock contention
         if let Some(state) = self.objects.read().get(&request.key)
             && !state.atomic_state.is_fast_path_available(request.mode)
@@ -123,12 +333,7 @@ impl LockShard {
                 drop(objects);
 
                 // Try atomic acquisition
-                let success = match request.mode {
-                    LockMode::Shared => state.try_acquire_shared_fast(&request.owner, request.lock_timeout),
-                    LockMode::Exclusive => state.try_acquire_exclusive_fast(&request.owner, request.lock_timeout),
-                };
-
-                if success {
+                if self.try_acquire_state(&state, request, None) {
                     return Some(state);
                 }
             }
@@ -144,7 +349,7 @@ impl LockShard {
                 let state = state.clone();
                 drop(objects);
 
-                if state.try_acquire_exclusive_fast(&request.owner, request.lock_timeout) {
+                if self.try_acquire_state(&state, request, None) {
                     return Some(state);
                 }
             } else {
@@ -178,8 +383,16 @@ impl LockShard {
         // only returning `Timeout` once the real deadline passes. The notification
         // still delivers prompt wakeups in the common (no-collision) case.
         const NOTIFY_WAIT_CAP: Duration = Duration::from_millis(50);
+        let mut registration: Option<WaiterRegistration> = None;
+        let mut denials = 0usize;
 
         loop {
+            if let Some(registration) = &registration
+                && registration.state.is_waiter_cancelled(registration.id)
+            {
+                return Err(LockResult::Cancelled);
+            }
+
             // Get or create object state
             let state = {
                 let mut objects = self.objects.write();
@@ -195,29 +408,47 @@ impl LockShard {
             };
 
             // Try acquisition again
-            let success = match request.mode {
-                LockMode::Shared => state.try_acquire_shared_fast(&request.owner, request.lock_timeout),
-                LockMode::Exclusive => state.try_acquire_exclusive_fast(&request.owner, request.lock_timeout),
-            };
-
-            if success {
+            let queued = registration.as_ref().map(|registration| (start_time, registration.id));
+            if self.try_acquire_state(&state, request, queued) {
                 self.metrics.record_slow_path_success();
                 return Ok(());
             }
+            self.check_reentrancy(request)?;
 
             // Check timeout
             if Instant::now() >= deadline {
                 self.metrics.record_timeout();
                 return Err(LockResult::Timeout);
             }
+            denials += 1;
+
+            // Stay listed as a waiter, following the state if it was replaced
+            if !registration.as_ref().is_some_and(|r| Arc::ptr_eq(&r.state, &state)) {
+                registration = Some(WaiterRegistration::new(
+                    state.clone(),
+                    request,
+                    start_time,
+                    self.priority_inheritance.clone(),
+                ));
+            }
+            if self.is_starving(request, denials)
+                && let Some(registration) = &mut registration
+            {
+                registration.mark_starving();
+            }
+            // Lend our priority to whoever currently blocks us
+            let priority = self.effective_priority(request);
+            if let Some(registration) = &registration {
+                self.priority_inheritance
+                    .boost_holders(registration.id, priority, &state.holder_owners());
+            }
 
             // Use intelligent wait strategy: mix of notification wait and exponential backoff
             let remaining = deadline - Instant::now();
 
             if retry_count < MAX_RETRIES && remaining > Duration::from_millis(10) {
                 // For early retries, use a brief exponential backoff instead of full notification wait
-                let backoff_ms = std::cmp::min(10 << retry_count, 100); // 10ms, 20ms, 40ms, 80ms, 100ms max
-                let backoff_duration = Duration::from_millis(backoff_ms);
+                let backoff_duration = Self::retry_backoff(priority, retry_count);
 
                 if backoff_duration < remaining {
                     tokio::time::sleep(backoff_duration).await;
@@ -355,6 +586,22 @@ impl LockShard {
         result
     }
 
+    /// Cancel every request currently waiting on `key`, returning how many were cancelled
+    pub fn cancel_waiters(&self, key: &ObjectKey) -> usize {
+        let state = self.objects.read().get(key).cloned();
+        state.map_or(0, |state| state.cancel_waiters())
+    }
+
+    /// Hand one lock `from` holds on `key` over to `to` without releasing it
+    pub fn transfer_owner(&self, key: &ObjectKey, from: &str, to: &Arc<str>) -> bool {
+        let objects = self.objects.read();
+        let transferred = objects.get(key).is_some_and(|state| state.transfer_owner(from, to));
+        if !transferred {
+            tracing::debug!("Lock transfer failed - owner holds no lock: key={}, from={}, to={}", key, from, to);
+        }
+        transferred
+    }
+
     /// Register a guard to prevent premature cleanup
     pub fn register_guard(&self, guard_id: u64) {
         let mut guards = self.active_guards.lock();
@@ -519,6 +766,55 @@ impl LockShard {
         None
     }
 
+    /// Promote `owner`'s shared hold on `key` to exclusive, waiting until `deadline`.
+    ///
+    /// While waiting, new readers are held off so the remaining ones can
+    /// drain. Returns false on timeout with the shared hold still in place;
+    /// two readers upgrading the same object at once both time out.
+    pub async fn upgrade_until(&self, key: &ObjectKey, owner: &Arc<str>, deadline: Instant) -> bool {
+        const UPGRADE_POLL: Duration = Duration::from_millis(5);
+
+        let Some(state) = self.objects.read().get(key).cloned() else {
+            return false;
+        };
+        if state.try_upgrade(owner) {
+            return true;
+        }
+
+        let _waiter_guard = WaiterCounterGuard::new(state.clone(), LockMode::Exclusive);
+        loop {
+            let now = Instant::now();
+            if now >= deadline {
+                return false;
+            }
+            tokio::time::sleep(UPGRADE_POLL.min(deadline - now)).await;
+            if state.try_upgrade(owner) {
+                return true;
+            }
+        }
+    }
+
+    /// Whether `owner` currently holds `key` in `mode`
+    pub fn is_held_by(&self, key: &ObjectKey, owner: &str, mode: LockMode) -> bool {
+        let Some(state) = self.objects.read().get(key).cloned() else {
+            return false;
+        };
+        match mode {
+            LockMode::Exclusive => state
+                .current_owner
+                .read()
+                .as_ref()
+                .is_some_and(|info| info.owner.as_ref() == owner),
+            LockMode::Shared => state.shared_depth(owner) > 0,
+        }
+    }
+
+    /// Current holders of `key`, one entry per distinct owner
+    pub fn holders(&self, key: &ObjectKey) -> Vec<crate::fast_lock::types::ObjectLockInfo> {
+        let objects = self.objects.read();
+        objects.get(key).map(|state| Self::holders_of(key, state)).unwrap_or_default()
+    }
+
     /// Enumerate every currently held lock in this shard.
     ///
     /// Exclusive locks yield a single entry; shared locks yield one entry per
@@ -529,46 +825,110 @@ impl LockShard {
         let objects = self.objects.read();
         let mut infos = Vec::new();
         for (key, state) in objects.iter() {
-            let Some(mode) = state.current_mode() else {
-                continue;
+            infos.extend(Self::holders_of(key, state));
+        }
+        infos
+    }
+
+    /// Per-object holders and, when `include_waiters` is set, blocked requests.
+    ///
+    /// Objects appear if they are held or, with waiters included, if anyone
+    /// is waiting on them. Collecting waiters takes each object's waiter
+    /// mutex, so leave it off when only holders are needed.
+    pub fn lock_reports(&self, include_waiters: bool) -> Vec<crate::fast_lock::types::ObjectLockReport> {
+        let objects = self.objects.read();
+        let mut reports = Vec::new();
+        for (key, state) in objects.iter() {
+            let holders = Self::holders_of(key, state);
+            let waiters = if include_waiters {
+                state
+                    .waiters_snapshot()
+                    .into_iter()
+                    .map(|waiter| crate::fast_lock::types::ObjectLockWaiterInfo {
+                        owner: waiter.owner,
+                        mode: waiter.mode,
+                        priority: waiter.priority,
+                        waited: waiter.since.elapsed(),
+                    })
+                    .collect()
+            } else {
+                Vec::new()
             };
-            let priority = *state.priority.read();
-            match mode {
-                LockMode::Exclusive => {
-                    if let Some(info) = state.current_owner.read().clone() {
-                        let expires_at = info
-                            .acquired_at
-                            .checked_add(info.lock_timeout)
-                            .unwrap_or_else(|| info.acquired_at + crate::fast_lock::DEFAULT_LOCK_TIMEOUT);
-                        infos.push(crate::fast_lock::types::ObjectLockInfo {
-                            key: key.clone(),
-                            mode,
-                            owner: info.owner,
-                            acquired_at: info.acquired_at,
-                            expires_at,
-                            priority,
-                        });
-                    }
+            if holders.is_empty() && waiters.is_empty() {
+                continue;
+            }
+            reports.push(crate::fast_lock::types::ObjectLockReport {
+                key: key.clone(),
+                holders,
+                waiters,
+            });
+        }
+        reports
+    }
+
+    /// Current holders of one object's lock
+    fn holders_of(key: &ObjectKey, state: &ObjectLockState) -> Vec<crate::fast_lock::types::ObjectLockInfo> {
+        let mut infos = Vec::new();
+        Self::visit_holders(key, state, &mut |holder| infos.push(holder.to_info()));
+        infos
+    }
+
+    /// Visit every holder of every object in this shard without cloning.
+    ///
+    /// The shard's map and each object's owner list stay read-locked while
+    /// `visit` runs, so keep it short and never acquire locks from inside it.
+    pub fn for_each_lock<F>(&self, visit: &mut F)
+    where
+        F: FnMut(crate::fast_lock::types::ObjectLockInfoRef<'_>),
+    {
+        let objects = self.objects.read();
+        for (key, state) in objects.iter() {
+            Self::visit_holders(key, state, visit);
+        }
+    }
+
+    fn visit_holders<F>(key: &ObjectKey, state: &ObjectLockState, visit: &mut F)
+    where
+        F: FnMut(crate::fast_lock::types::ObjectLockInfoRef<'_>),
+    {
+        let Some(mode) = state.current_mode() else {
+            return;
+        };
+        let priority = *state.priority.read();
+        match mode {
+            LockMode::Exclusive => {
+                if let Some(info) = state.current_owner.read().as_ref() {
+                    let expires_at = info
+                        .acquired_at
+                        .checked_add(info.lock_timeout)
+                        .unwrap_or_else(|| info.acquired_at + crate::fast_lock::DEFAULT_LOCK_TIMEOUT);
+                    visit(crate::fast_lock::types::ObjectLockInfoRef {
+                        key,
+                        mode,
+                        owner: &info.owner,
+                        acquired_at: info.acquired_at,
+                        expires_at,
+                        priority,
+                    });
                 }
-                LockMode::Shared => {
-                    for entry in state.shared_owners.read().iter() {
-                        let expires_at = entry
-                            .acquired_at
-                            .checked_add(entry.lock_timeout)
-                            .unwrap_or_else(|| entry.acquired_at + crate::fast_lock::DEFAULT_LOCK_TIMEOUT);
-                        infos.push(crate::fast_lock::types::ObjectLockInfo {
-                            key: key.clone(),
-                            mode,
-                            owner: entry.owner.clone(),
-                            acquired_at: entry.acquired_at,
-                            expires_at,
-                            priority,
-                        });
-                    }
+            }
+            LockMode::Shared => {
+                for entry in state.shared_owners.read().iter() {
+                    let expires_at = entry
+                        .acquired_at
+                        .checked_add(entry.lock_timeout)
+                        .unwrap_or_else(|| entry.acquired_at + crate::fast_lock::DEFAULT_LOCK_TIMEOUT);
+                    visit(crate::fast_lock::types::ObjectLockInfoRef {
+                        key,
+                        mode,
+                        owner: &entry.owner,
+                        acquired_at: entry.acquired_at,
+                        expires_at,
+                        priority,
+                    });
                 }
             }
         }
-        infos
     }
 
     /// Force-release every holder of a lock on `key`, regardless of owner.
@@ -1170,4 +1530,186 @@ mod tests {
         assert!(hits >= 1, "subsequent acquire should hit the recycled pool");
         assert!(shard.pool_hit_rate() > 0.0, "pool hit rate should rise after recycling");
     }
+
+    #[tokio::test]
+    async fn test_max_reentrancy_rejects_excess_shared_reacquire() {
+        let shard = LockShard::with_config(0, LockConfig::default().with_max_reentrancy(2));
+        let key = ObjectKey::new("bucket", "reentrant");
+        let owner: Arc<str> = Arc::from("owner");
+        let request = ObjectLockRequest::new_read(key.clone(), owner.clone()).with_acquire_timeout(Duration::from_millis(100));
+
+        // Re-entering up to the limit succeeds.
+        assert!(shard.acquire_lock(&request).await.is_ok());
+        assert!(shard.acquire_lock(&request).await.is_ok());
+
+        // One more hold is rejected immediately rather than timing out.
+        match shard.acquire_lock(&request).await {
+            Err(LockResult::ReentrancyLimitExceeded { owner: rejected, depth }) => {
+                assert_eq!(rejected.as_ref(), "owner");
+                assert_eq!(depth, 2);
+            }
+            other => panic!("expected reentrancy rejection, got {other:?}"),
+        }
+
+        // The limit is per owner: another reader is unaffected.
+        let other = ObjectLockRequest::new_read(key.clone(), "other-owner");
+        assert!(shard.acquire_lock(&other).await.is_ok());
+
+        // Releasing decrements the depth, making room for another re-entry.
+        assert!(shard.release_lock(&key, &owner, LockMode::Shared));
+        assert!(shard.acquire_lock(&request).await.is_ok());
+
+        assert!(shard.release_lock(&key, &owner, LockMode::Shared));
+        assert!(shard.release_lock(&key, &owner, LockMode::Shared));
+        assert!(shard.release_lock(&key, &Arc::from("other-owner"), LockMode::Shared));
+        assert!(shard.get_lock_info(&key).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_max_reentrancy_holds_when_depth_grows_after_pre_check() {
+        let shard = LockShard::with_config(0, LockConfig::default().with_max_reentrancy(2));
+        let key = ObjectKey::new("bucket", "reentrant-race");
+        let owner: Arc<str> = Arc::from("owner");
+        let request = ObjectLockRequest::new_read(key.clone(), owner.clone()).with_acquire_timeout(Duration::from_millis(100));
+        assert!(shard.acquire_lock(&request).await.is_ok());
+
+        // A concurrent re-acquire passes the pre-check at depth 1, then another
+        // one by the same owner reaches the limit before it tries the fast path.
+        assert!(shard.check_reentrancy(&request).is_ok());
+        assert!(shard.acquire_lock(&request).await.is_ok());
+        assert!(shard.try_fast_path(&request).is_none(), "the grant itself must enforce the limit");
+
+        let state = shard.objects.read().get(&key).cloned().unwrap();
+        assert_eq!(state.shared_depth(&owner), 2);
+        assert_eq!(state.atomic_state.reader_count(), 2, "the refused hold must give its reader slot back");
+
+        assert!(matches!(
+            shard.acquire_lock(&request).await,
+            Err(LockResult::ReentrancyLimitExceeded { depth: 2, .. })
+        ));
+
+        assert!(shard.release_lock(&key, &owner, LockMode::Shared));
+        assert!(shard.release_lock(&key, &owner, LockMode::Shared));
+        assert!(shard.get_lock_info(&key).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_max_readers_rejects_reads_beyond_cap() {
+        let shard = LockShard::with_config(0, LockConfig::default().with_max_readers(2));
+        let key = ObjectKey::new("bucket", "hot");
+        let reader1: Arc<str> = Arc::from("reader1");
+        let reader2: Arc<str> = Arc::from("reader2");
+
+        assert!(
+            shard
+                .acquire_lock(&ObjectLockRequest::new_read(key.clone(), reader1.clone()))
+                .await
+                .is_ok()
+        );
+        assert!(
+            shard
+                .acquire_lock(&ObjectLockRequest::new_read(key.clone(), reader2.clone()))
+                .await
+                .is_ok()
+        );
+
+        // The third reader is turned away instead of queueing behind the others.
+        let third = ObjectLockRequest::new_read(key.clone(), "reader3").with_acquire_timeout(Duration::from_secs(5));
+        let started = Instant::now();
+        assert!(matches!(
+            shard.acquire_lock(&third).await,
+            Err(LockResult::Conflict {
+                current_mode: LockMode::Shared,
+                ..
+            })
+        ));
+        assert!(started.elapsed() < Duration::from_secs(1), "capacity rejection must not wait");
+
+        // Existing readers release normally and free up capacity.
+        assert!(shard.release_lock(&key, &reader1, LockMode::Shared));
+        assert!(shard.acquire_lock(&third).await.is_ok());
+        assert!(shard.release_lock(&key, &reader2, LockMode::Shared));
+        assert!(shard.release_lock(&key, &Arc::from("reader3"), LockMode::Shared));
+        assert!(shard.get_lock_info(&key).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_transfer_owner_moves_exclusive_and_shared_holds() {
+        let shard = LockShard::new(0);
+        let old_owner: Arc<str> = Arc::from("draining-node");
+        let new_owner: Arc<str> = Arc::from("successor-node");
+
+        let write_key = ObjectKey::new("bucket", "write");
+        assert!(
+            shard
+                .acquire_lock(&ObjectLockRequest::new_write(write_key.clone(), old_owner.clone()))
+                .await
+                .is_ok()
+        );
+        assert!(shard.transfer_owner(&write_key, &old_owner, &new_owner));
+        assert!(!shard.release_lock(&write_key, &old_owner, LockMode::Exclusive));
+        assert!(shard.release_lock(&write_key, &new_owner, LockMode::Exclusive));
+
+        // One reentrant shared hold moves and merges into the new owner's entry.
+        let read_key = ObjectKey::new("bucket", "read");
+        let read_old = ObjectLockRequest::new_read(read_key.clone(), old_owner.clone());
+        assert!(shard.acquire_lock(&read_old).await.is_ok());
+        assert!(shard.acquire_lock(&read_old).await.is_ok());
+        assert!(
+            shard
+                .acquire_lock(&ObjectLockRequest::new_read(read_key.clone(), new_owner.clone()))
+                .await
+                .is_ok()
+        );
+        assert!(shard.transfer_owner(&read_key, &old_owner, &new_owner));
+        assert!(shard.release_lock(&read_key, &old_owner, LockMode::Shared));
+        assert!(!shard.release_lock(&read_key, &old_owner, LockMode::Shared));
+        for _ in 0..2 {
+            assert!(shard.release_lock(&read_key, &new_owner, LockMode::Shared));
+        }
+        assert!(shard.get_lock_info(&read_key).is_none());
+
+        // Nothing to hand over once the owner holds nothing.
+        assert!(!shard.transfer_owner(&read_key, &old_owner, &new_owner));
+    }
+
+    #[tokio::test]
+    async fn test_holder_inherits_priority_of_blocked_waiter() {
+        let shard = Arc::new(LockShard::new(0));
+        let key = ObjectKey::new("bucket", "inverted");
+        let holder: Arc<str> = Arc::from("low-holder");
+
+        let held = ObjectLockRequest::new_write(key.clone(), holder.clone()).with_priority(LockPriority::Low);
+        assert!(shard.acquire_lock(&held).await.is_ok());
+
+        // The holder's next contended acquisition would normally back off at low priority.
+        let other =
+            ObjectLockRequest::new_write(ObjectKey::new("bucket", "other"), holder.clone()).with_priority(LockPriority::Low);
+        assert_eq!(shard.effective_priority(&other), LockPriority::Low);
+
+        let waiter = {
+            let shard = shard.clone();
+            let request = ObjectLockRequest::new_write(key.clone(), "critical-waiter")
+                .with_priority(LockPriority::Critical)
+                .with_acquire_timeout(Duration::from_secs(5));
+            tokio::spawn(async move { shard.acquire_lock(&request).await })
+        };
+        tokio::time::timeout(Duration::from_secs(2), async {
+            while shard.priority_inheritance.inherited_priority(&holder).is_none() {
+                tokio::time::sleep(Duration::from_millis(5)).await;
+            }
+        })
+        .await
+        .expect("holder should inherit the waiter's priority");
+
+        // While the critical waiter is blocked, the holder's own acquisitions are expedited.
+        assert_eq!(shard.effective_priority(&other), LockPriority::Critical);
+        assert!(LockShard::retry_backoff(shard.effective_priority(&other), 0) < LockShard::retry_backoff(LockPriority::Low, 0));
+
+        // Releasing the blocking lock lets the waiter in and ends the boost.
+        assert!(shard.release_lock(&key, &holder, LockMode::Exclusive));
+        assert!(waiter.await.expect("waiter task").is_ok());
+        assert_eq!(shard.priority_inheritance.inherited_priority(&holder), None);
+        assert_eq!(shard.effective_priority(&other), LockPriority::Low);
+    }
 }
@@ -15,7 +15,10 @@
 #[cfg(test)]
 mod fast_lock_tests {
     use crate::LockError;
-    use crate::fast_lock::types::{LockConfig, LockMode, LockPriority, LockResult, ObjectKey, ObjectLockRequest};
+    use crate::fast_lock::guard::MultipleLockGuards;
+    use crate::fast_lock::types::{
+        BatchLockRequest, LockConfig, LockMode, LockPolicy, LockPriority, LockResult, ObjectKey, ObjectLockRequest,
+    };
     use crate::fast_lock::{DEFAULT_SHARD_COUNT, FastObjectLockManager};
     use std::sync::Arc;
     use std::time::Duration;
@@ -616,4 +619,514 @@ mod fast_lock_tests {
             .expect("Should acquire write lock after all read locks released");
         assert!(write_guard.release());
     }
+
+    #[tokio::test]
+    async fn test_guard_transfer_releases_as_new_owner() {
+        let manager = create_test_manager();
+        let key = ObjectKey::new("test-bucket", "test-object");
+
+        let mut guard = manager
+            .acquire_write_lock(key.clone(), "old-owner")
+            .await
+            .expect("Should acquire lock");
+        assert!(guard.transfer("new-owner"));
+        assert_eq!(guard.owner().as_ref(), "new-owner");
+
+        let info = manager.get_lock_info(&key).expect("lock should still be held");
+        assert_eq!(info.owner.as_ref(), "new-owner");
+
+        // The lock stays exclusive across the hand-over.
+        let contender = ObjectLockRequest::new_write(key.clone(), "old-owner").with_acquire_timeout(Duration::from_millis(100));
+        assert!(matches!(manager.acquire_lock(contender).await, Err(LockResult::Timeout)));
+
+        assert!(guard.release());
+        assert!(manager.get_lock_info(&key).is_none());
+        assert!(!guard.transfer("another-owner"), "released guards cannot be transferred");
+    }
+
+    #[tokio::test]
+    async fn test_guard_transfer_moves_only_its_own_reentrant_read_hold() {
+        let manager = create_test_manager();
+        let key = ObjectKey::new("test-bucket", "test-object");
+
+        let mut transferred = manager
+            .acquire_read_lock(key.clone(), "old-owner")
+            .await
+            .expect("Should acquire first read lock");
+        let mut kept = manager
+            .acquire_read_lock(key.clone(), "old-owner")
+            .await
+            .expect("Should acquire reentrant read lock");
+        assert!(transferred.transfer("new-owner"));
+
+        assert!(kept.release(), "the old owner's other guard still releases");
+        assert!(manager.get_lock_info(&key).is_some());
+        assert!(transferred.release());
+        assert!(manager.get_lock_info(&key).is_none(), "both holds are gone");
+
+        let writer = ObjectLockRequest::new_write(key, "writer").with_acquire_timeout(Duration::from_millis(100));
+        assert!(manager.acquire_lock(writer).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_read_lock_batch_is_all_or_nothing() {
+        let config = LockConfig {
+            shard_count: 4,
+            default_acquire_timeout: Duration::from_millis(100),
+            ..LockConfig::default()
+        };
+        let manager = FastObjectLockManager::with_config(config);
+        let keys: Vec<_> = (0..3).map(|i| ObjectKey::new("test-bucket", format!("object-{i}"))).collect();
+
+        let result = manager.acquire_read_locks_batch(keys.clone(), "reader").await;
+        assert!(result.all_acquired);
+        assert_eq!(result.guards.len(), 3);
+        for key in &keys {
+            assert_eq!(manager.get_lock_info(key).map(|info| info.mode), Some(LockMode::Shared));
+        }
+        drop(result);
+
+        // A write-locked key in the middle rolls the whole batch back.
+        let _writer = manager
+            .acquire_write_lock(keys[1].clone(), "writer")
+            .await
+            .expect("Should acquire write lock");
+        let result = manager.acquire_read_locks_batch(keys.clone(), "reader").await;
+        assert!(!result.all_acquired);
+        assert!(result.guards.is_empty());
+        assert_eq!(result.failed_locks.len(), 1);
+        assert_eq!(result.failed_locks[0].0, keys[1]);
+        assert!(manager.get_lock_info(&keys[0]).is_none(), "earlier read locks must be rolled back");
+        assert!(manager.get_lock_info(&keys[2]).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_read_lock_batch_rejects_oversized_batches() {
+        let manager = create_test_manager();
+        let keys: Vec<_> = (0..=crate::MAX_DELETE_LIST)
+            .map(|i| ObjectKey::new("test-bucket", format!("object-{i}")))
+            .collect();
+
+        let result = manager.acquire_read_locks_batch(keys, "reader").await;
+        assert!(!result.all_acquired);
+        assert!(result.guards.is_empty());
+        assert!(matches!(
+            result.failed_locks[0].1,
+            LockResult::BatchLimitExceeded { requested, max } if requested == crate::MAX_DELETE_LIST + 1 && max == crate::MAX_DELETE_LIST
+        ));
+        assert!(manager.list_locks().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_max_batch_bounds_batch_acquisition() {
+        let config = LockConfig {
+            shard_count: 4,
+            ..LockConfig::default()
+        }
+        .with_max_batch(2);
+        let manager = FastObjectLockManager::with_config(config);
+        let key = |i: usize| ObjectKey::new("test-bucket", format!("object-{i}"));
+
+        // Exactly at the limit is accepted.
+        let at_limit = BatchLockRequest::new("owner").add_write_lock(key(0)).add_write_lock(key(1));
+        let result = manager.acquire_locks_batch(at_limit).await;
+        assert!(result.all_acquired);
+        assert_eq!(result.guards.len(), 2);
+        drop(result);
+
+        // One over the limit is rejected before anything is locked.
+        let over_limit = BatchLockRequest::new("owner")
+            .add_write_lock(key(0))
+            .add_write_lock(key(1))
+            .add_read_lock(key(2));
+        let result = manager.acquire_locks_batch(over_limit).await;
+        assert!(!result.all_acquired);
+        assert!(result.guards.is_empty());
+        assert_eq!(result.failed_locks.len(), 3);
+        assert!(
+            result
+                .failed_locks
+                .iter()
+                .all(|(_, err)| matches!(err, LockResult::BatchLimitExceeded { requested: 3, max: 2 }))
+        );
+        assert!(manager.list_locks().is_empty());
+
+        // Read batches share the same bound.
+        let result = manager.acquire_read_locks_batch((0..3).map(key).collect(), "reader").await;
+        assert!(!result.all_acquired);
+        assert!(
+            manager
+                .acquire_read_locks_batch((0..2).map(key).collect(), "reader")
+                .await
+                .all_acquired
+        );
+    }
+
+    #[test]
+    fn try_with_config_rejects_zero_max_batch() {
+        let err = FastObjectLockManager::try_with_config(LockConfig::default().with_max_batch(0))
+            .expect_err("a zero batch bound would reject every batch");
+        assert!(matches!(err, LockError::Configuration { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_release_all_detailed_reports_per_object_outcome() {
+        let manager = create_test_manager();
+        let write_a = ObjectKey::new("test-bucket", "write-a");
+        let write_b = ObjectKey::new("test-bucket", "write-b");
+        let read_c = ObjectKey::new("test-bucket", "read-c");
+
+        let batch = BatchLockRequest::new("owner")
+            .add_write_lock(write_a.clone())
+            .add_write_lock(write_b.clone())
+            .add_read_lock(read_c.clone());
+        let result = manager.acquire_locks_batch(batch).await;
+        assert!(result.all_acquired);
+        let mut guards = MultipleLockGuards::from(result.guards);
+
+        // An operator clears one of the write locks behind the guards' back.
+        assert_eq!(manager.force_unlock(&write_b), 1);
+
+        let mut outcomes = guards.release_all_detailed();
+        outcomes.sort_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(outcomes, vec![(read_c.clone(), true), (write_a.clone(), true), (write_b.clone(), false)]);
+
+        // Nothing is left to release, and the count wrapper agrees.
+        assert_eq!(guards.release_all(), 0);
+        assert!(manager.list_locks().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_for_each_lock_matches_list_locks() {
+        let manager = FastObjectLockManager::new();
+        let _write = manager
+            .acquire_write_lock(ObjectKey::new("bucket", "visit-write"), "writer")
+            .await
+            .expect("write lock");
+        let _read_a = manager
+            .acquire_read_lock(ObjectKey::new("bucket", "visit-read"), "reader-a")
+            .await
+            .expect("read lock a");
+        let _read_b = manager
+            .acquire_read_lock(ObjectKey::new("bucket", "visit-read"), "reader-b")
+            .await
+            .expect("read lock b");
+
+        let mut visited = Vec::new();
+        manager.for_each_lock(|holder| {
+            visited.push((
+                holder.key.object.to_string(),
+                holder.owner.to_string(),
+                holder.mode == LockMode::Exclusive,
+            ))
+        });
+        visited.sort();
+
+        let mut listed: Vec<_> = manager
+            .list_locks()
+            .into_iter()
+            .map(|info| (info.key.object.to_string(), info.owner.to_string(), info.mode == LockMode::Exclusive))
+            .collect();
+        listed.sort();
+
+        assert_eq!(visited.len(), 3);
+        assert_eq!(visited, listed);
+        assert_eq!(manager.iter_locks().count(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_batch_rejects_empty_names() {
+        let manager = create_test_manager();
+        let valid = ObjectKey::new("bucket", "valid");
+        let batch = BatchLockRequest::new("owner")
+            .add_write_lock(valid.clone())
+            .add_write_lock(ObjectKey::new("bucket", ""))
+            .add_read_lock(ObjectKey::new("", "object"));
+
+        let result = manager.acquire_locks_batch(batch).await;
+        assert!(!result.all_acquired);
+        assert!(result.guards.is_empty());
+        let reasons: Vec<_> = result
+            .failed_locks
+            .iter()
+            .map(|(_, err)| match err {
+                LockResult::InvalidKey { reason } => *reason,
+                other => panic!("expected InvalidKey, got {other:?}"),
+            })
+            .collect();
+        assert_eq!(reasons, ["batch contains an invalid key", "empty object name", "empty bucket name"]);
+
+        // Nothing was locked, including the valid key.
+        assert!(manager.get_lock_info(&valid).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_batch_dedupes_repeated_keys() {
+        let manager = create_test_manager();
+        let repeated = ObjectKey::new("bucket", "repeated");
+        let other = ObjectKey::new("bucket", "other");
+        let batch = BatchLockRequest::new("owner")
+            .add_read_lock(repeated.clone())
+            .add_write_lock(repeated.clone())
+            .add_read_lock(repeated.clone())
+            .add_read_lock(other.clone())
+            .add_read_lock(other.clone());
+
+        let result = manager.acquire_locks_batch(batch).await;
+        assert!(result.all_acquired, "duplicates must not block on themselves: {:?}", result.failed_locks);
+        assert_eq!(result.guards.len(), 2);
+        assert_eq!(manager.get_lock_info(&repeated).map(|info| info.mode), Some(LockMode::Exclusive));
+        assert_eq!(manager.get_lock_info(&other).map(|info| info.mode), Some(LockMode::Shared));
+
+        drop(result);
+        assert!(manager.get_lock_info(&repeated).is_none());
+        assert!(manager.get_lock_info(&other).is_none(), "the deduped read lock was taken only once");
+    }
+
+    #[tokio::test]
+    async fn test_higher_priority_waiter_acquires_first() {
+        let manager = Arc::new(create_test_manager());
+        let key = ObjectKey::new("bucket", "contended");
+        let mut holder = manager
+            .acquire_write_lock(key.clone(), "holder")
+            .await
+            .expect("holder should acquire");
+
+        let order = Arc::new(parking_lot::Mutex::new(Vec::new()));
+        let acquire = |owner: &'static str, priority: LockPriority| {
+            let manager = manager.clone();
+            let order = order.clone();
+            let request = ObjectLockRequest::new_write(key.clone(), owner)
+                .with_priority(priority)
+                .with_acquire_timeout(Duration::from_secs(5));
+            async move {
+                let guard = manager.acquire_lock(request).await.expect("waiter should acquire");
+                order.lock().push(owner);
+                sleep(Duration::from_millis(20)).await;
+                drop(guard);
+            }
+        };
+
+        // The urgent request queues behind the holder.
+        let high = tokio::spawn(acquire("high", LockPriority::High));
+        tokio::time::timeout(Duration::from_secs(2), async {
+            while manager.list_locks_detailed(true).first().map_or(0, |r| r.waiters.len()) < 1 {
+                sleep(Duration::from_millis(5)).await;
+            }
+        })
+        .await
+        .expect("high-priority waiter should register");
+
+        // A routine request arriving right as the resource frees must not barge
+        // ahead of it, even though it is polled before the queued waiter wakes.
+        assert!(holder.release());
+        acquire("low", LockPriority::Low).await;
+        high.await.expect("high waiter task");
+
+        assert_eq!(*order.lock(), ["high", "low"]);
+    }
+
+    #[tokio::test]
+    async fn test_upgrade_until_waits_for_other_readers() {
+        let manager = create_test_manager();
+        let key = ObjectKey::new("bucket", "upgrade");
+        let mut scanner = manager.acquire_read_lock(key.clone(), "scanner").await.unwrap();
+        let mut transient = manager.acquire_read_lock(key.clone(), "transient").await.unwrap();
+
+        let release_later = async {
+            sleep(Duration::from_millis(50)).await;
+            assert!(transient.release());
+        };
+        let deadline = std::time::Instant::now() + Duration::from_secs(2);
+        let (upgraded, ()) = tokio::join!(scanner.upgrade_until(deadline), release_later);
+
+        assert!(upgraded);
+        assert_eq!(scanner.mode(), LockMode::Exclusive);
+        let info = manager.get_lock_info(&key).expect("still locked");
+        assert_eq!((info.mode, info.owner.as_ref()), (LockMode::Exclusive, "scanner"));
+
+        // The upgraded guard releases as a writer.
+        assert!(scanner.release());
+        assert!(manager.get_lock_info(&key).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_upgrade_until_times_out_and_keeps_read_lock() {
+        let manager = create_test_manager();
+        let key = ObjectKey::new("bucket", "upgrade-timeout");
+        let mut scanner = manager.acquire_read_lock(key.clone(), "scanner").await.unwrap();
+        let _reader = manager.acquire_read_lock(key.clone(), "reader").await.unwrap();
+
+        let deadline = std::time::Instant::now() + Duration::from_millis(50);
+        assert!(!scanner.upgrade_until(deadline).await);
+        assert_eq!(scanner.mode(), LockMode::Shared);
+        assert_eq!(manager.get_lock_info(&key).map(|info| info.mode), Some(LockMode::Shared));
+
+        // Readers are admitted again once the upgrade gives up.
+        let late = ObjectLockRequest::new_read(key.clone(), "late").with_acquire_timeout(Duration::from_millis(100));
+        assert!(manager.acquire_lock(late).await.is_ok());
+
+        // A sole reader upgrades immediately instead of waiting on itself.
+        let sole_key = ObjectKey::new("bucket", "upgrade-sole");
+        let mut sole = manager.acquire_read_lock(sole_key, "sole").await.unwrap();
+        assert!(sole.upgrade_until(std::time::Instant::now()).await);
+        assert_eq!(sole.mode(), LockMode::Exclusive);
+    }
+
+    #[tokio::test]
+    async fn test_write_preferring_after_denials_lets_writer_past_continuous_readers() {
+        let manager = Arc::new(FastObjectLockManager::with_config(
+            LockConfig::default().with_lock_policy(LockPolicy::WritePreferring { after_denials: 2 }),
+        ));
+        let key = ObjectKey::new("bucket", "hot-read");
+        let stop = Arc::new(std::sync::atomic::AtomicBool::new(false));
+
+        // Overlapping readers keep the object share-locked without a gap.
+        let mut readers = Vec::new();
+        for i in 0..4 {
+            let (manager, key, stop) = (manager.clone(), key.clone(), stop.clone());
+            readers.push(tokio::spawn(async move {
+                sleep(Duration::from_millis(5 * i)).await;
+                while !stop.load(std::sync::atomic::Ordering::Acquire) {
+                    let request = ObjectLockRequest::new_read(key.clone(), format!("reader-{i}"))
+                        .with_acquire_timeout(Duration::from_secs(2));
+                    if let Ok(guard) = manager.acquire_lock(request).await {
+                        sleep(Duration::from_millis(20)).await;
+                        drop(guard);
+                    }
+                }
+            }));
+        }
+        sleep(Duration::from_millis(30)).await;
+
+        // Readers are held back after the writer's second denied retry, a few tens of
+        // milliseconds in. Under `ReadPreferring` the writer spends its first ~750ms of
+        // backoff retries losing to overlapping readers before it is counted as waiting.
+        let writer = ObjectLockRequest::new_write(key.clone(), "writer").with_acquire_timeout(Duration::from_secs(2));
+        let started = std::time::Instant::now();
+        let result = manager.acquire_lock(writer).await;
+        let elapsed = started.elapsed();
+        assert!(result.is_ok(), "writer should win once readers are held back: {:?}", result.err());
+        assert!(elapsed < Duration::from_millis(250), "writer waited {elapsed:?}");
+
+        drop(result);
+        stop.store(true, std::sync::atomic::Ordering::Release);
+        for reader in readers {
+            reader.await.unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_dropped_starved_writer_stops_holding_back_readers() {
+        let manager = Arc::new(FastObjectLockManager::with_config(
+            LockConfig::default().with_lock_policy(LockPolicy::WritePreferring { after_denials: 2 }),
+        ));
+        let key = ObjectKey::new("bucket", "abandoned-writer");
+        let holder = manager
+            .acquire_lock(ObjectLockRequest::new_read(key.clone(), "holder"))
+            .await
+            .unwrap();
+
+        let writer = {
+            let (manager, key) = (manager.clone(), key.clone());
+            tokio::spawn(async move {
+                let request = ObjectLockRequest::new_write(key, "writer").with_acquire_timeout(Duration::from_secs(10));
+                manager.acquire_lock(request).await.map(drop)
+            })
+        };
+        wait_for_queued(&manager, 1).await;
+        sleep(Duration::from_millis(50)).await;
+
+        let held_back = ObjectLockRequest::new_read(key.clone(), "reader").with_acquire_timeout(Duration::from_millis(50));
+        assert!(manager.acquire_lock(held_back).await.is_err(), "starved writer should hold back readers");
+
+        // Dropping the waiting future must release its hold on readers.
+        writer.abort();
+        assert!(writer.await.unwrap_err().is_cancelled());
+        let reader = ObjectLockRequest::new_read(key.clone(), "reader").with_acquire_timeout(Duration::from_millis(200));
+        assert!(manager.acquire_lock(reader).await.is_ok());
+        drop(holder);
+    }
+
+    /// Wait until `count` requests are blocked across `manager`
+    async fn wait_for_queued(manager: &FastObjectLockManager, count: usize) {
+        tokio::time::timeout(Duration::from_secs(2), async {
+            while manager.queue_report().total_queued < count {
+                sleep(Duration::from_millis(5)).await;
+            }
+        })
+        .await
+        .expect("waiters should register");
+    }
+
+    /// Whether a new reader joins an existing one while a writer waits, under `policy`
+    async fn reader_joins_past_waiting_writer(policy: LockPolicy) -> bool {
+        let manager = Arc::new(FastObjectLockManager::with_config(LockConfig::default().with_lock_policy(policy)));
+        let key = ObjectKey::new("bucket", "policy-read");
+        let reader = manager.acquire_read_lock(key.clone(), "reader-1").await.unwrap();
+        let writer = {
+            let (manager, key) = (manager.clone(), key.clone());
+            tokio::spawn(async move {
+                let request = ObjectLockRequest::new_write(key, "writer").with_acquire_timeout(Duration::from_secs(2));
+                manager.acquire_lock(request).await.map(|_| ())
+            })
+        };
+        wait_for_queued(&manager, 1).await;
+
+        let late = ObjectLockRequest::new_read(key, "reader-2").with_acquire_timeout(Duration::from_millis(100));
+        let joined = manager.acquire_lock(late).await.is_ok();
+        drop(reader);
+        assert!(writer.await.unwrap().is_ok(), "the writer gets in once the readers leave");
+        joined
+    }
+
+    /// Which of a waiting reader and a later waiting writer gets the object first under `policy`
+    async fn first_waiter_granted(policy: LockPolicy) -> &'static str {
+        let manager = Arc::new(FastObjectLockManager::with_config(LockConfig::default().with_lock_policy(policy)));
+        let key = ObjectKey::new("bucket", "policy-order");
+        let holder = manager.acquire_write_lock(key.clone(), "holder").await.unwrap();
+        let granted = Arc::new(std::sync::Mutex::new(Vec::new()));
+
+        let mut waiters = Vec::new();
+        for (name, mode) in [("reader", LockMode::Shared), ("writer", LockMode::Exclusive)] {
+            let (waiter_manager, key, granted) = (manager.clone(), key.clone(), granted.clone());
+            waiters.push(tokio::spawn(async move {
+                let request = match mode {
+                    LockMode::Shared => ObjectLockRequest::new_read(key, name),
+                    LockMode::Exclusive => ObjectLockRequest::new_write(key, name),
+                }
+                .with_acquire_timeout(Duration::from_secs(2));
+                let guard = waiter_manager.acquire_lock(request).await.expect("waiter should be granted");
+                granted.lock().unwrap().push(name);
+                sleep(Duration::from_millis(30)).await;
+                drop(guard);
+            }));
+            wait_for_queued(&manager, waiters.len()).await;
+        }
+
+        drop(holder);
+        for waiter in waiters {
+            waiter.await.unwrap();
+        }
+        let granted = granted.lock().unwrap();
+        assert_eq!(granted.len(), 2);
+        granted[0]
+    }
+
+    #[tokio::test]
+    async fn test_read_preferring_policy_admits_readers_past_waiting_writer() {
+        assert!(reader_joins_past_waiting_writer(LockPolicy::ReadPreferring).await);
+    }
+
+    #[tokio::test]
+    async fn test_write_preferring_policy_holds_back_readers_for_waiting_writer() {
+        assert!(!reader_joins_past_waiting_writer(LockPolicy::WritePreferring { after_denials: 0 }).await);
+        assert_eq!(first_waiter_granted(LockPolicy::WritePreferring { after_denials: 0 }).await, "writer");
+    }
+
+    #[tokio::test]
+    async fn test_fair_policy_grants_waiters_in_arrival_order() {
+        assert!(!reader_joins_past_waiting_writer(LockPolicy::Fair).await);
+        assert_eq!(first_waiter_granted(LockPolicy::Fair).await, "reader");
+    }
 }
@@ -135,6 +135,47 @@ impl ObjectKey {
         }
     }
 
+    /// This key with runs of `/` in the object collapsed and any leading `/`
+    /// stripped, lowercased as well when `fold_case` is set. A trailing `/` is
+    /// kept, since `a/` names a different object than `a`. The version is kept as is.
+    pub fn normalized(&self, fold_case: bool) -> Self {
+        let mut object = String::with_capacity(self.object.len());
+        for ch in self.object.chars() {
+            if !(ch == '/' && (object.is_empty() || object.ends_with('/'))) {
+                object.push(ch);
+            }
+        }
+        if object.is_empty() && !self.object.is_empty() {
+            object.push('/');
+        }
+        if fold_case {
+            object = object.to_lowercase();
+        }
+        let bucket: Arc<str> = if fold_case && self.bucket.chars().any(char::is_uppercase) {
+            self.bucket.to_lowercase().into()
+        } else {
+            self.bucket.clone()
+        };
+        let object: Arc<str> = if *object == *self.object {
+            self.object.clone()
+        } else {
+            object.into()
+        };
+        Self {
+            bucket,
+            object,
+            version: self.version.clone(),
+        }
+    }
+
+    /// Whether `bucket/object` starts with `prefix`, without building the path
+    pub fn has_path_prefix(&self, prefix: &str) -> bool {
+        match prefix.strip_prefix(&*self.bucket) {
+            Some(rest) => rest.is_empty() || rest.strip_prefix('/').is_some_and(|rest| self.object.starts_with(rest)),
+            None => self.bucket.starts_with(prefix),
+        }
+    }
+
     /// Get shard index from object key hash
     pub fn shard_index(&self, shard_mask: usize) -> usize {
         let mut hasher = std::collections::hash_map::DefaultHasher::new();
@@ -143,6 +184,18 @@ impl ObjectKey {
     }
 }
 
+/// Sort `resources` and drop duplicates, giving the order a group of locks
+/// should be taken in.
+///
+/// Callers locking several resources one at a time can deadlock each other
+/// when they go in different orders; taking them in this order on every
+/// node avoids that. Batch acquisitions already order their keys this way
+/// internally.
+pub fn canonicalize(resources: &mut Vec<ObjectKey>) {
+    resources.sort_unstable();
+    resources.dedup();
+}
+
 /// Optimized object key using smart strings for better performance
 #[derive(Debug, Clone)]
 pub struct OptimizedObjectKey {
@@ -333,6 +386,17 @@ pub enum LockPriority {
     Critical = 4,
 }
 
+impl From<crate::types::LockPriority> for LockPriority {
+    fn from(priority: crate::types::LockPriority) -> Self {
+        match priority {
+            crate::types::LockPriority::Low => Self::Low,
+            crate::types::LockPriority::Normal => Self::Normal,
+            crate::types::LockPriority::High => Self::High,
+            crate::types::LockPriority::Critical => Self::Critical,
+        }
+    }
+}
+
 /// Lock acquisition result
 #[derive(Debug)]
 pub enum LockResult {
@@ -345,6 +409,15 @@ pub enum LockResult {
         current_owner: Arc<str>,
         current_mode: LockMode,
     },
+    /// Reentrant acquisition rejected because the owner already holds the
+    /// lock `depth` times, the configured `max_reentrancy`
+    ReentrancyLimitExceeded { owner: Arc<str>, depth: u32 },
+    /// The wait was cancelled by an operator before the lock was granted
+    Cancelled,
+    /// The batch names more objects than a single batch may lock
+    BatchLimitExceeded { requested: usize, max: usize },
+    /// The request names an object that cannot be locked, such as an empty name
+    InvalidKey { reason: &'static str },
 }
 
 /// Configuration for the lock manager
@@ -356,6 +429,44 @@ pub struct LockConfig {
     pub cleanup_interval: Duration,
     pub max_idle_time: Duration,
     pub enable_metrics: bool,
+    /// Maximum number of times one owner may re-acquire a shared lock it
+    /// already holds on the same object. `0` disables the limit.
+    pub max_reentrancy: usize,
+    /// Maximum number of concurrent shared holds per object. Further read
+    /// requests are rejected with a conflict instead of queueing. `0` means
+    /// unlimited (bounded only by the 255-reader state encoding).
+    pub max_readers: usize,
+    /// Maximum number of objects a single batch may lock. Defaults to
+    /// [`crate::MAX_DELETE_LIST`].
+    pub max_batch: usize,
+    /// How contention between readers and writers is resolved
+    pub policy: LockPolicy,
+}
+
+/// How an object's lock arbitrates between readers and writers
+///
+/// - `ReadPreferring` admits a reader whenever no writer holds the object.
+///   Throughput for read-heavy objects is best, but a steady stream of
+///   overlapping readers can starve a writer.
+/// - `WritePreferring` holds back new readers once a waiting writer has been
+///   denied `after_denials` times in a row, until it gets in or stops
+///   waiting. With `0` readers wait as soon as a writer does, so writes land
+///   promptly at the cost of read latency on hot objects; a small count is a
+///   cheaper middle ground that only steps in for a writer that is actually
+///   starving. Readers that already hold the object can still re-acquire it.
+/// - `Fair` grants waiters in arrival order: no request overtakes an earlier
+///   waiter it conflicts with, though readers behind a reader still share.
+///   Neither side starves, but bursts of readers get less overlap.
+///
+/// Waiter priorities apply on top of every policy.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LockPolicy {
+    #[default]
+    ReadPreferring,
+    WritePreferring {
+        after_denials: usize,
+    },
+    Fair,
 }
 
 impl Default for LockConfig {
@@ -367,10 +478,49 @@ impl Default for LockConfig {
             cleanup_interval: crate::fast_lock::CLEANUP_INTERVAL,
             max_idle_time: Duration::from_secs(300), // 5 minutes
             enable_metrics: true,
+            max_reentrancy: 0,
+            max_readers: 0,
+            max_batch: crate::MAX_DELETE_LIST,
+            policy: LockPolicy::ReadPreferring,
         }
     }
 }
 
+impl LockConfig {
+    /// Reject reentrant acquisitions once an owner holds the same lock `depth` times.
+    ///
+    /// Catches acquire-without-release leaks early instead of letting the
+    /// holder count grow until the reader capacity is exhausted.
+    pub fn with_max_reentrancy(mut self, depth: usize) -> Self {
+        self.max_reentrancy = depth;
+        self
+    }
+
+    /// Cap the number of concurrent shared holds on a single object.
+    ///
+    /// Keeps a hot object from accumulating readers without bound and
+    /// starving writers. `0` restores the unlimited default.
+    pub fn with_max_readers(mut self, max_readers: usize) -> Self {
+        self.max_readers = max_readers;
+        self
+    }
+
+    /// Set the largest batch of objects one batch acquisition may lock.
+    ///
+    /// Bulk-delete heavy deployments can raise it; memory-constrained nodes
+    /// can lower it. Must be non-zero.
+    pub fn with_max_batch(mut self, max_batch: usize) -> Self {
+        self.max_batch = max_batch;
+        self
+    }
+
+    /// Resolve reader/writer contention with `policy`; see [`LockPolicy`] for the trade-offs.
+    pub fn with_lock_policy(mut self, policy: LockPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+}
+
 /// Lock information for monitoring
 #[derive(Debug, Clone)]
 pub struct ObjectLockInfo {
@@ -382,6 +532,69 @@ pub struct ObjectLockInfo {
     pub priority: LockPriority,
 }
 
+/// Borrowed view of one lock holder, handed out by `for_each_lock`
+#[derive(Debug, Clone, Copy)]
+pub struct ObjectLockInfoRef<'a> {
+    pub key: &'a ObjectKey,
+    pub mode: LockMode,
+    pub owner: &'a Arc<str>,
+    pub acquired_at: SystemTime,
+    pub expires_at: SystemTime,
+    pub priority: LockPriority,
+}
+
+impl ObjectLockInfoRef<'_> {
+    /// Owned copy of this holder
+    pub fn to_info(&self) -> ObjectLockInfo {
+        ObjectLockInfo {
+            key: self.key.clone(),
+            mode: self.mode,
+            owner: self.owner.clone(),
+            acquired_at: self.acquired_at,
+            expires_at: self.expires_at,
+            priority: self.priority,
+        }
+    }
+}
+
+/// A request blocked waiting for a lock, for monitoring
+#[derive(Debug, Clone)]
+pub struct ObjectLockWaiterInfo {
+    pub owner: Arc<str>,
+    pub mode: LockMode,
+    pub priority: LockPriority,
+    /// How long the request has been blocked so far
+    pub waited: Duration,
+}
+
+/// Holders and, optionally, waiters of a single object lock
+#[derive(Debug, Clone)]
+pub struct ObjectLockReport {
+    pub key: ObjectKey,
+    pub holders: Vec<ObjectLockInfo>,
+    /// Blocked requests, oldest first. Empty unless waiters were requested.
+    pub waiters: Vec<ObjectLockWaiterInfo>,
+}
+
+/// Wait-queue depth of one contended object
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResourceQueue {
+    pub key: ObjectKey,
+    /// Requests currently blocked on the object
+    pub depth: usize,
+    /// How long the oldest of them has waited so far
+    pub longest_wait: Duration,
+}
+
+/// Contention across every object, as reported by `FastObjectLockManager::queue_report`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct QueueReport {
+    /// Objects with at least one waiter, deepest queue first
+    pub resources: Vec<ResourceQueue>,
+    /// Waiters across all objects
+    pub total_queued: usize,
+}
+
 /// Batch lock operation request
 #[derive(Debug)]
 pub struct BatchLockRequest {
@@ -444,6 +657,50 @@ mod tests {
         assert_eq!(key2.to_string(), "bucket1/object1@v1");
     }
 
+    #[test]
+    fn test_object_key_path_prefix() {
+        let key = ObjectKey::new("photos", "2024/trip/a.jpg");
+
+        assert!(key.has_path_prefix(""));
+        assert!(key.has_path_prefix("pho"));
+        assert!(key.has_path_prefix("photos"));
+        assert!(key.has_path_prefix("photos/"));
+        assert!(key.has_path_prefix("photos/2024/"));
+        assert!(key.has_path_prefix("photos/2024/trip/a.jpg"));
+        assert!(!key.has_path_prefix("photos/2025/"));
+        assert!(!key.has_path_prefix("photos2/"));
+        assert!(!key.has_path_prefix("photosx"));
+        assert!(!ObjectKey::new("photos-archive", "x").has_path_prefix("photos/"));
+    }
+
+    #[test]
+    fn test_object_key_normalized() {
+        let expected = ObjectKey::new("bucket", "a/b");
+        assert_eq!(ObjectKey::new("bucket", "/a/b").normalized(false), expected);
+        assert_eq!(ObjectKey::new("bucket", "a//b").normalized(false), expected);
+        assert_eq!(ObjectKey::new("bucket", "//a///b").normalized(false), expected);
+        assert_eq!(ObjectKey::new("Bucket", "A/b").normalized(false), ObjectKey::new("Bucket", "A/b"));
+        assert_eq!(ObjectKey::new("Bucket", "/A//b").normalized(true), expected);
+        // A trailing `/` names a distinct object, so it survives collapsing.
+        assert_eq!(ObjectKey::new("bucket", "a/b/").normalized(false), ObjectKey::new("bucket", "a/b/"));
+        assert_eq!(ObjectKey::new("bucket", "a//b//").normalized(false), ObjectKey::new("bucket", "a/b/"));
+        assert_eq!(ObjectKey::new("bucket", "/").normalized(false), ObjectKey::new("bucket", "/"));
+        assert_eq!(ObjectKey::new("bucket", "//").normalized(false), ObjectKey::new("bucket", "/"));
+        let versioned = ObjectKey::with_version("bucket", "a//b", "V1").normalized(true);
+        assert_eq!(versioned, ObjectKey::with_version("bucket", "a/b", "V1"));
+    }
+
+    #[test]
+    fn test_canonicalize_agrees_regardless_of_request_order() {
+        let key = |object: &str| ObjectKey::new("bucket", object);
+        let mut first = vec![key("c"), key("a"), key("b"), key("a")];
+        let mut second = vec![key("b"), key("c"), key("a")];
+        canonicalize(&mut first);
+        canonicalize(&mut second);
+        assert_eq!(first, second);
+        assert_eq!(first, vec![key("a"), key("b"), key("c")]);
+    }
+
     #[test]
     fn test_lock_request() {
         let req = ObjectLockRequest::new_read(ObjectKey::new("bucket", "object"), "owner")
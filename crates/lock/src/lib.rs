@@ -20,6 +20,7 @@
 pub mod distributed_lock;
 pub mod local_lock;
 pub mod namespace;
+pub mod ring;
 
 // Abstraction Layer Modules
 pub mod client;
@@ -28,7 +29,11 @@ pub mod client;
 pub mod fast_lock;
 
 // Core Modules
+pub mod audit;
+pub mod clock;
+pub mod dsync;
 pub mod error;
+pub mod snapshot;
 pub mod types;
 
 // ============================================================================
@@ -37,9 +42,13 @@ pub mod types;
 
 // Re-export main types for easy access
 pub use crate::{
+    // Audit trail
+    audit::{LockAudit, LockEvent, LockEventKind},
     // Client interfaces
     client::{LockClient, local::LocalClient},
     distributed_lock::DistributedLockGuard,
+    // MinIO compatibility
+    dsync::DsyncLockArgs,
     // Error types
     error::{LockError, Result},
     // Fast Lock System exports
@@ -49,10 +58,14 @@ pub use crate::{
     },
     // Main components
     namespace::{NamespaceLock, NamespaceLockGuard, NamespaceLockWrapper},
+    // Resource routing
+    ring::LockerRing,
+    // Persistence
+    snapshot::{LockDiff, LockLogRecord, LockSnapshot, LockSnapshotEntry, LockStore},
     // Core types
     types::{
-        HealthInfo, HealthStatus, LockId, LockInfo, LockMetadata, LockPriority, LockRequest, LockResponse, LockStats, LockStatus,
-        LockType,
+        HealthInfo, HealthStatus, HoldKind, LockHolder, LockId, LockInfo, LockMetadata, LockPriority, LockRequest, LockResponse,
+        LockStats, LockStatus, LockType, ResourceState,
     },
 };
 
@@ -101,6 +114,17 @@ impl Default for GlobalLockManager {
 impl GlobalLockManager {
     /// Create a lock manager based on environment variable configuration
     pub fn new() -> Self {
+        Self::with_config(fast_lock::LockConfig {
+            default_acquire_timeout: Self::acquire_timeout_from_env(),
+            ..Default::default()
+        })
+    }
+
+    /// Create a lock manager with `config`, such as a non-default [`fast_lock::LockPolicy`].
+    ///
+    /// Locks can still be disabled through the environment; the acquire
+    /// timeout is taken from `config` as given.
+    pub fn with_config(config: fast_lock::LockConfig) -> Self {
         // Check lock enablement env vars with deprecated compatibility support.
         let locks_enabled = rustfs_utils::get_env_bool_with_aliases(
             ENV_LOCK_ENABLED,
@@ -123,9 +147,13 @@ impl GlobalLockManager {
             }
             return Self::Disabled(DisabledLockManager::new());
         }
-        tracing::info!("Lock system enabled");
+        tracing::info!("Lock system enabled with acquire timeout: {}s", config.default_acquire_timeout.as_secs());
+
+        Self::Enabled(Arc::new(FastObjectLockManager::with_config(config)))
+    }
 
-        // Read lock acquire timeout from environment variable
+    /// Lock acquire timeout from `RUSTFS_LOCK_ACQUIRE_TIMEOUT`, clamped to the supported range
+    fn acquire_timeout_from_env() -> std::time::Duration {
         let mut acquire_secs = rustfs_utils::get_env_u64("RUSTFS_LOCK_ACQUIRE_TIMEOUT", DEFAULT_RUSTFS_ACQUIRE_TIMEOUT);
 
         // Enforce minimum of 1 second
@@ -142,16 +170,7 @@ impl GlobalLockManager {
             );
             acquire_secs = DEFAULT_RUSTFS_MAX_ACQUIRE_TIMEOUT;
         }
-        let acquire_timeout = std::time::Duration::from_secs(acquire_secs);
-        tracing::info!("Lock system enabled with acquire timeout: {}s", acquire_timeout.as_secs());
-
-        // Create lock manager with custom configuration
-        let config = fast_lock::LockConfig {
-            default_acquire_timeout: acquire_timeout,
-            ..Default::default()
-        };
-
-        Self::Enabled(Arc::new(FastObjectLockManager::with_config(config)))
+        std::time::Duration::from_secs(acquire_secs)
     }
 
     /// Check if the lock manager is disabled
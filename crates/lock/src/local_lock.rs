@@ -16,7 +16,7 @@ use crate::{
     GlobalLockManager, ObjectKey,
     error::Result,
     fast_lock::{FastLockGuard, LockManager, LockMode, ObjectLockRequest},
-    types::{LockPriority, LockRequest, LockType},
+    types::{LockRequest, LockType},
 };
 use std::sync::Arc;
 use std::time::Duration;
@@ -57,21 +57,13 @@ impl LocalLock {
         };
         let owner: Arc<str> = request.owner.clone().into();
 
-        // Convert LockPriority from types::LockPriority to fast_lock::types::LockPriority
-        let fast_priority = match request.priority {
-            LockPriority::Low => crate::fast_lock::types::LockPriority::Low,
-            LockPriority::Normal => crate::fast_lock::types::LockPriority::Normal,
-            LockPriority::High => crate::fast_lock::types::LockPriority::High,
-            LockPriority::Critical => crate::fast_lock::types::LockPriority::Critical,
-        };
-
         let object_request = ObjectLockRequest {
             key: object_key,
             mode,
             owner,
             acquire_timeout: request.acquire_timeout,
             lock_timeout: request.ttl,
-            priority: fast_priority,
+            priority: request.priority.into(),
         };
 
         match self.manager.as_ref().acquire_lock(object_request).await {
@@ -181,6 +181,14 @@ impl NamespaceLock {
         }
     }
 
+    /// Whether locking never leaves this node; see [`DistributedLock::is_local`]
+    pub async fn is_local(&self) -> bool {
+        match self {
+            Self::Distributed(lock) => lock.is_local().await,
+            Self::Local(_) => true,
+        }
+    }
+
     /// Get resource key for this namespace
     pub fn get_resource_key(&self, resource: &ObjectKey) -> String {
         match self {
@@ -322,9 +330,11 @@ impl NamespaceLock {
                 let results = futures::future::join_all(client_checks).await;
                 let connected_clients = results.iter().filter(|&&online| online).count();
 
-                let quorum = if clients.len() > 1 { (clients.len() / 2) + 1 } else { 1 };
-
-                health.status = if connected_clients >= quorum {
+                // Judge health against the configured write quorum, which may be
+                // stricter than a plain majority.
+                health.write_quorum = lock.write_quorum();
+                health.read_quorum = lock.read_quorum();
+                health.status = if connected_clients >= health.write_quorum {
                     crate::types::HealthStatus::Healthy
                 } else {
                     crate::types::HealthStatus::Degraded
@@ -344,15 +354,25 @@ impl NamespaceLock {
     }
 
     /// Get namespace statistics
+    ///
+    /// `healthy_participants` and `total_participants` say how many lock
+    /// clients are online, so monitoring can alert on a degraded quorum
+    /// before acquisitions start failing.
     pub async fn get_stats(&self) -> crate::types::LockStats {
         let mut stats = crate::types::LockStats::default();
 
         match self {
             Self::Distributed(lock) => {
                 // Parallelize stats collection for better performance
-                let stats_futures: Vec<_> = lock.clients().iter().map(|client| client.get_stats()).collect();
+                let clients = lock.clients();
+                let stats_futures: Vec<_> = clients.iter().map(|client| client.get_stats()).collect();
+                let online_futures: Vec<_> = clients.iter().map(|client| client.is_online()).collect();
 
-                let results = futures::future::join_all(stats_futures).await;
+                let (results, online) =
+                    futures::future::join(futures::future::join_all(stats_futures), futures::future::join_all(online_futures))
+                        .await;
+                stats.healthy_participants = online.into_iter().filter(|online| *online).count();
+                stats.total_participants = clients.len();
 
                 for result in results {
                     match result {
@@ -369,7 +389,10 @@ impl NamespaceLock {
             Self::Local(_) => {
                 // Local locks use GlobalLockManager which doesn't expose detailed stats
                 // Stats are tracked internally but not exposed through the same interface
-                // We leave stats at default (0) for now
+                // We leave stats at default (0) for now, apart from the always-available
+                // single participant.
+                stats.healthy_participants = 1;
+                stats.total_participants = 1;
             }
         }
 
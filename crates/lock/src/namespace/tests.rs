@@ -13,7 +13,13 @@
 // limitations under the License.
 
 use super::*;
-use crate::client::{ClientFactory, local::LocalClient};
+use crate::client::{
+    ClientFactory,
+    local::{
+        CapacityPolicy, ContentionReport, DurationHistogram, LocalClient, LockOutcome, OwnershipStatus, RefreshOutcome,
+        ReleaseTypePolicy, RepairReport,
+    },
+};
 use crate::types::LockType;
 use crate::{GlobalLockManager, LockError, LockInfo, LockResponse, LockStats};
 use std::sync::{
@@ -412,6 +418,522 @@ async fn test_local_client_uses_request_lock_id_for_release() {
     assert!(second_response.success);
 }
 
+#[tokio::test]
+async fn test_local_client_expiry_follows_injected_clock() {
+    let manager = Arc::new(GlobalLockManager::new());
+    let clock = Arc::new(crate::clock::ManualClock::new());
+    let client = LocalClient::with_manager(manager).with_clock(clock.clone());
+    let resource = create_test_object_key("bucket", "object-manual-clock");
+    let request = LockRequest::new(resource.clone(), LockType::Exclusive, "owner-a").with_ttl(Duration::from_secs(30));
+
+    assert!(client.acquire_lock(&request).await.unwrap().success);
+    assert_eq!(client.reclaim_expired().await, 0, "lease is still valid");
+
+    clock.advance(Duration::from_secs(31));
+    let status = client
+        .check_status(&request.lock_id)
+        .await
+        .unwrap()
+        .expect("entry still tracked");
+    assert_eq!(status.status, crate::LockStatus::Expired);
+
+    assert_eq!(client.reclaim_expired().await, 1);
+    assert!(client.check_status(&request.lock_id).await.unwrap().is_none());
+
+    let successor = LockRequest::new(resource, LockType::Exclusive, "owner-b").with_acquire_timeout(Duration::from_millis(100));
+    assert!(client.acquire_lock(&successor).await.unwrap().success);
+}
+
+#[tokio::test]
+async fn test_local_client_close_releases_held_locks() {
+    let manager = Arc::new(GlobalLockManager::new());
+    let audit = Arc::new(CollectingAudit::default());
+    let client = Arc::new(LocalClient::with_manager(manager.clone()).with_audit(audit.clone()));
+    let write_resource = create_test_object_key("bucket", "object-close-write");
+    let read_resource = create_test_object_key("bucket", "object-close-read");
+
+    let write = LockRequest::new(write_resource.clone(), LockType::Exclusive, "owner-a");
+    let read = LockRequest::new(read_resource, LockType::Shared, "owner-a");
+    assert!(client.acquire_lock(&write).await.unwrap().success);
+    assert!(client.acquire_lock(&read).await.unwrap().success);
+    assert_eq!(client.held_count().await, 2);
+
+    // A second node blocks on the write lock until the first one closes.
+    let peer = LocalClient::with_manager(manager);
+    let waiter = tokio::spawn(async move {
+        let request =
+            LockRequest::new(write_resource, LockType::Exclusive, "owner-b").with_acquire_timeout(Duration::from_secs(5));
+        peer.acquire_lock(&request).await.unwrap().success
+    });
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert!(!waiter.is_finished());
+
+    client.close().await.unwrap();
+    assert!(client.is_closed());
+    assert_eq!(client.held_count().await, 0);
+    assert_eq!(client.hold_duration_histogram().count, 2, "closing ends both holds");
+    let mut force_unlocked: Vec<_> = audit
+        .events
+        .lock()
+        .iter()
+        .filter(|event| event.kind == crate::LockEventKind::ForceUnlock)
+        .map(|event| event.uid.clone())
+        .collect();
+    force_unlocked.sort();
+    let mut held = vec![write.lock_id.uuid.clone(), read.lock_id.uuid.clone()];
+    held.sort();
+    assert_eq!(force_unlocked, held, "closing audits each released lock");
+    assert!(client.check_status(&write.lock_id).await.unwrap().is_none());
+    assert!(waiter.await.unwrap(), "waiter should acquire once the closed client releases");
+
+    let retry = LockRequest::new(create_test_object_key("bucket", "object-after-close"), LockType::Exclusive, "owner-a");
+    assert!(matches!(client.acquire_lock(&retry).await, Err(crate::LockError::ClientClosed)));
+    assert!(matches!(client.refresh(&write.lock_id).await, Err(crate::LockError::ClientClosed)));
+    assert!(!client.release(&write.lock_id).await.unwrap());
+}
+
+#[tokio::test]
+async fn test_local_client_lock_holder_queries() {
+    let client = LocalClient::with_manager(Arc::new(GlobalLockManager::new()));
+
+    let unlocked = create_test_object_key("bucket", "object-holder-free");
+    assert!(!client.is_locked(&unlocked).await);
+    assert!(client.get_lock_holder(&unlocked).await.is_none());
+
+    let written = create_test_object_key("bucket", "object-holder-write");
+    let write = LockRequest::new(written.clone(), LockType::Exclusive, "writer")
+        .with_metadata(crate::LockMetadata::new().with_client_info("node-1"));
+    assert!(client.acquire_lock(&write).await.unwrap().success);
+    let status_before = client.check_status(&write.lock_id).await.unwrap().unwrap();
+
+    assert!(client.is_locked(&written).await);
+    let holder = client.get_lock_holder(&written).await.expect("write holder");
+    assert!(holder.is_write_lock());
+    assert_eq!(holder.uid, write.lock_id.uuid);
+    assert_eq!(holder.owner, "writer");
+    assert_eq!(holder.source.as_deref(), Some("node-1"));
+
+    let read = create_test_object_key("bucket", "object-holder-read");
+    let read_a = LockRequest::new(read.clone(), LockType::Shared, "reader-a");
+    let read_b = LockRequest::new(read.clone(), LockType::Shared, "reader-b");
+    assert!(client.acquire_lock(&read_a).await.unwrap().success);
+    assert!(client.acquire_lock(&read_b).await.unwrap().success);
+
+    assert!(client.is_locked(&read).await);
+    let holders = client.get_lock_holders(&read).await;
+    assert_eq!(holders.len(), 2);
+    assert!(
+        holders
+            .iter()
+            .all(|holder| !holder.is_write_lock() && holder.source.is_none())
+    );
+    let mut owners: Vec<_> = holders.iter().map(|holder| holder.owner.as_str()).collect();
+    owners.sort_unstable();
+    assert_eq!(owners, ["reader-a", "reader-b"]);
+    assert!(!client.get_lock_holder(&read).await.unwrap().is_write_lock());
+
+    // Queries leave leases untouched.
+    let status_after = client.check_status(&write.lock_id).await.unwrap().unwrap();
+    assert_eq!(status_after.status, crate::LockStatus::Acquired);
+    assert!(status_after.expires_at <= status_before.expires_at + Duration::from_millis(50));
+}
+
+#[tokio::test]
+async fn test_local_client_force_unlock_older_than_keeps_fresh_locks() {
+    let manager = Arc::new(GlobalLockManager::new());
+    let clock = Arc::new(crate::clock::ManualClock::new());
+    let client = LocalClient::with_manager(manager.clone()).with_clock(clock.clone());
+    let resource = create_test_object_key("bucket", "object-stale-sweep");
+    let ttl = Duration::from_secs(300);
+
+    let stale = LockRequest::new(resource.clone(), LockType::Shared, "owner-a").with_ttl(ttl);
+    let fresh = LockRequest::new(resource.clone(), LockType::Shared, "owner-a").with_ttl(ttl);
+    assert!(client.acquire_lock(&stale).await.unwrap().success);
+    assert!(client.acquire_lock(&fresh).await.unwrap().success);
+
+    clock.advance(Duration::from_secs(60));
+    assert!(client.refresh(&fresh.lock_id).await.unwrap());
+    clock.advance(Duration::from_secs(5));
+
+    assert_eq!(client.force_unlock_older_than(&resource, Duration::from_secs(30)).await, 1);
+    assert_eq!(client.hold_duration_histogram().count, 1);
+    assert!(client.check_status(&stale.lock_id).await.unwrap().is_none());
+    assert!(client.check_status(&fresh.lock_id).await.unwrap().is_some());
+
+    // The fresh holder still blocks writers.
+    let writer = LockRequest::new(resource, LockType::Exclusive, "owner-b").with_acquire_timeout(Duration::from_millis(100));
+    assert!(
+        !LocalClient::with_manager(manager)
+            .acquire_lock(&writer)
+            .await
+            .unwrap()
+            .success
+    );
+}
+
+#[tokio::test]
+async fn test_local_client_release_owner_only_drops_that_owner() {
+    let client = LocalClient::with_manager(Arc::new(GlobalLockManager::new()));
+    let shared = create_test_object_key("bucket", "object-owner-shared");
+
+    let evicted = [
+        LockRequest::new(create_test_object_key("bucket", "object-owner-a1"), LockType::Exclusive, "tenant-a"),
+        LockRequest::new(create_test_object_key("bucket", "object-owner-a2"), LockType::Exclusive, "tenant-a"),
+        LockRequest::new(shared.clone(), LockType::Shared, "tenant-a"),
+    ];
+    let kept = [
+        LockRequest::new(create_test_object_key("bucket", "object-owner-b1"), LockType::Exclusive, "tenant-b"),
+        LockRequest::new(shared.clone(), LockType::Shared, "tenant-b"),
+    ];
+    for request in evicted.iter().chain(kept.iter()) {
+        assert!(client.acquire_lock(request).await.unwrap().success);
+    }
+
+    assert_eq!(client.release_owner("tenant-a").await, 3);
+    assert_eq!(client.release_owner("tenant-a").await, 0);
+    for request in &evicted {
+        assert!(client.check_status(&request.lock_id).await.unwrap().is_none());
+    }
+    for request in &kept {
+        assert!(client.check_status(&request.lock_id).await.unwrap().is_some());
+    }
+    let holders = client.get_lock_holders(&shared).await;
+    assert_eq!(holders.len(), 1);
+    assert_eq!(holders[0].owner, "tenant-b");
+}
+
+#[tokio::test]
+async fn test_local_client_force_unlock_prefix_spares_siblings() {
+    let client = LocalClient::with_manager(Arc::new(GlobalLockManager::new()));
+    let under_prefix = [
+        LockRequest::new(create_test_object_key("bucket", "path/a"), LockType::Exclusive, "owner-a"),
+        LockRequest::new(create_test_object_key("bucket", "path/nested/b"), LockType::Shared, "owner-b"),
+    ];
+    let siblings = [
+        LockRequest::new(create_test_object_key("bucket", "path2/c"), LockType::Exclusive, "owner-a"),
+        LockRequest::new(create_test_object_key("bucket", "other/d"), LockType::Exclusive, "owner-a"),
+        LockRequest::new(create_test_object_key("bucket-2", "path/e"), LockType::Exclusive, "owner-a"),
+    ];
+    for request in under_prefix.iter().chain(siblings.iter()) {
+        assert!(client.acquire_lock(request).await.unwrap().success);
+    }
+
+    assert_eq!(client.force_unlock_prefix("bucket/path/").await, 2);
+    assert_eq!(client.hold_duration_histogram().count, 2);
+    for request in &under_prefix {
+        assert!(!client.is_locked(&request.resource).await);
+    }
+    for request in &siblings {
+        assert!(client.is_locked(&request.resource).await);
+    }
+}
+
+#[tokio::test]
+async fn test_local_client_count_locks_prefix() {
+    let client = LocalClient::with_manager(Arc::new(GlobalLockManager::new()));
+    let requests = [
+        LockRequest::new(create_test_object_key("logs", "2024/a"), LockType::Exclusive, "owner-a"),
+        LockRequest::new(create_test_object_key("logs", "2024/b"), LockType::Shared, "owner-a"),
+        LockRequest::new(create_test_object_key("logs", "2024/b"), LockType::Shared, "owner-b"),
+        LockRequest::new(create_test_object_key("media", "c"), LockType::Exclusive, "owner-a"),
+        LockRequest::new(create_test_object_key("media", "d"), LockType::Exclusive, "owner-b"),
+    ];
+    for request in &requests {
+        assert!(client.acquire_lock(request).await.unwrap().success);
+    }
+
+    let logs = client.count_locks_prefix("logs/").await;
+    assert_eq!((logs.total_locks, logs.exclusive_locks, logs.shared_locks), (3, 1, 2));
+
+    let media = client.count_locks_prefix("media/").await;
+    assert_eq!((media.total_locks, media.exclusive_locks, media.shared_locks), (2, 2, 0));
+
+    assert_eq!(client.count_locks_prefix("archive/").await.total_locks, 0);
+    assert_eq!(client.count_locks_prefix("").await.total_locks, requests.len());
+}
+
+#[tokio::test]
+async fn test_local_client_reclaim_leaves_no_tracked_or_held_state() {
+    let manager = Arc::new(GlobalLockManager::new());
+    let clock = Arc::new(crate::clock::ManualClock::new());
+    let client = LocalClient::with_manager(manager.clone()).with_clock(clock.clone());
+    let expiring = LockRequest::new(create_test_object_key("bucket", "object-reclaim-a"), LockType::Exclusive, "owner-a")
+        .with_ttl(Duration::from_secs(10));
+    let expiring_shared = LockRequest::new(create_test_object_key("bucket", "object-reclaim-b"), LockType::Shared, "owner-a")
+        .with_ttl(Duration::from_secs(10));
+    let live = LockRequest::new(create_test_object_key("bucket", "object-reclaim-c"), LockType::Exclusive, "owner-a")
+        .with_ttl(Duration::from_secs(600));
+    for request in [&expiring, &expiring_shared, &live] {
+        assert!(client.acquire_lock(request).await.unwrap().success);
+    }
+
+    clock.advance(Duration::from_secs(11));
+    assert_eq!(client.reclaim_expired().await, 2);
+
+    assert_eq!(client.held_count().await, 1);
+    assert!(!client.is_locked(&expiring.resource).await);
+    assert!(!client.is_locked(&expiring_shared.resource).await);
+    let held: Vec<_> = manager
+        .as_fast_lock_manager()
+        .map(|fast| fast.list_locks())
+        .unwrap_or_default()
+        .into_iter()
+        .map(|info| info.key)
+        .collect();
+    assert_eq!(held, vec![live.resource.clone()]);
+}
+
+type TraceFields = std::collections::HashMap<String, String>;
+
+/// Records span fields and event levels, for asserting on instrumentation
+#[derive(Debug, Default)]
+struct TraceCapture {
+    next_span: AtomicUsize,
+    spans: parking_lot::Mutex<std::collections::HashMap<u64, (&'static str, TraceFields)>>,
+    events: parking_lot::Mutex<Vec<(tracing::Level, TraceFields)>>,
+}
+
+struct FieldRecorder<'a>(&'a mut TraceFields);
+
+impl tracing::field::Visit for FieldRecorder<'_> {
+    fn record_str(&mut self, field: &tracing::field::Field, value: &str) {
+        self.0.insert(field.name().to_string(), value.to_string());
+    }
+
+    fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+        self.0.insert(field.name().to_string(), format!("{value:?}"));
+    }
+}
+
+struct CaptureSubscriber(Arc<TraceCapture>);
+
+impl tracing::Subscriber for CaptureSubscriber {
+    fn enabled(&self, _metadata: &tracing::Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, attrs: &tracing::span::Attributes<'_>) -> tracing::span::Id {
+        let id = self.0.next_span.fetch_add(1, Ordering::SeqCst) as u64 + 1;
+        let mut fields = TraceFields::new();
+        attrs.record(&mut FieldRecorder(&mut fields));
+        self.0.spans.lock().insert(id, (attrs.metadata().name(), fields));
+        tracing::span::Id::from_u64(id)
+    }
+
+    fn record(&self, span: &tracing::span::Id, values: &tracing::span::Record<'_>) {
+        if let Some((_, fields)) = self.0.spans.lock().get_mut(&span.into_u64()) {
+            values.record(&mut FieldRecorder(fields));
+        }
+    }
+
+    fn record_follows_from(&self, _span: &tracing::span::Id, _follows: &tracing::span::Id) {}
+
+    fn event(&self, event: &tracing::Event<'_>) {
+        let mut fields = TraceFields::new();
+        event.record(&mut FieldRecorder(&mut fields));
+        self.0.events.lock().push((*event.metadata().level(), fields));
+    }
+
+    fn enter(&self, _span: &tracing::span::Id) {}
+
+    fn exit(&self, _span: &tracing::span::Id) {}
+}
+
+impl TraceCapture {
+    fn spans_named(&self, name: &str) -> Vec<TraceFields> {
+        self.spans
+            .lock()
+            .values()
+            .filter(|(span_name, _)| *span_name == name)
+            .map(|(_, fields)| fields.clone())
+            .collect()
+    }
+}
+
+#[tokio::test]
+async fn test_local_client_operations_emit_spans() {
+    let capture = Arc::new(TraceCapture::default());
+    let _subscriber = tracing::subscriber::set_default(CaptureSubscriber(capture.clone()));
+
+    let manager = Arc::new(GlobalLockManager::new());
+    let client = LocalClient::with_manager(manager.clone());
+    let resource = create_test_object_key("bucket", "object-traced");
+    let request = LockRequest::new(resource.clone(), LockType::Exclusive, "traced-owner");
+    assert!(client.acquire_lock(&request).await.unwrap().success);
+
+    let acquired = capture.spans_named("local_lock.acquire");
+    assert_eq!(acquired.len(), 1);
+    assert_eq!(acquired[0]["lock_uid"], request.lock_id.uuid);
+    assert_eq!(acquired[0]["owner"], "traced-owner");
+    assert_eq!(acquired[0]["resources"], "1");
+    assert_eq!(acquired[0]["outcome"], "acquired");
+
+    // A contended acquisition is marked rejected and logged at warn level.
+    let contender =
+        LockRequest::new(resource, LockType::Exclusive, "other-owner").with_acquire_timeout(Duration::from_millis(20));
+    assert!(
+        !LocalClient::with_manager(manager)
+            .acquire_lock(&contender)
+            .await
+            .unwrap()
+            .success
+    );
+    let rejected: Vec<_> = capture
+        .spans_named("local_lock.acquire")
+        .into_iter()
+        .filter(|fields| fields["lock_uid"] == contender.lock_id.uuid)
+        .collect();
+    assert_eq!(rejected.len(), 1);
+    assert_eq!(rejected[0]["outcome"], "rejected");
+    assert!(
+        capture
+            .events
+            .lock()
+            .iter()
+            .any(|(level, fields)| *level == tracing::Level::WARN
+                && fields.get("owner").map(String::as_str) == Some("other-owner"))
+    );
+
+    assert!(client.refresh(&request.lock_id).await.unwrap());
+    assert!(client.release(&request.lock_id).await.unwrap());
+    assert_eq!(capture.spans_named("local_lock.refresh")[0]["outcome"], "refreshed");
+    let released = capture.spans_named("local_lock.release");
+    assert_eq!(released[0]["outcome"], "released");
+    assert_eq!(released[0]["owner"], "traced-owner");
+}
+
+#[tokio::test]
+async fn test_local_client_contention_report_counts_rejections() {
+    let manager = Arc::new(GlobalLockManager::new());
+    let holder = LocalClient::with_manager(manager.clone());
+    let client = LocalClient::with_manager(manager);
+    let hot = create_test_object_key("bucket", "object-hot");
+    let warm = create_test_object_key("bucket", "object-warm");
+
+    assert!(
+        holder
+            .acquire_lock(&LockRequest::new(hot.clone(), LockType::Exclusive, "holder"))
+            .await
+            .unwrap()
+            .success
+    );
+    assert!(
+        holder
+            .acquire_lock(&LockRequest::new(warm.clone(), LockType::Exclusive, "holder"))
+            .await
+            .unwrap()
+            .success
+    );
+
+    let contended = |resource: &ObjectKey, lock_type| {
+        LockRequest::new(resource.clone(), lock_type, "contender")
+            .with_acquire_timeout(Duration::from_millis(10))
+            .with_suppress_contention_logs(true)
+    };
+    for _ in 0..3 {
+        assert!(
+            !client
+                .acquire_lock(&contended(&hot, LockType::Exclusive))
+                .await
+                .unwrap()
+                .success
+        );
+    }
+    assert!(!client.acquire_lock(&contended(&hot, LockType::Shared)).await.unwrap().success);
+    assert!(
+        !client
+            .acquire_lock(&contended(&warm, LockType::Shared))
+            .await
+            .unwrap()
+            .success
+    );
+    let free = create_test_object_key("bucket", "object-free");
+    assert!(
+        client
+            .acquire_lock(&LockRequest::new(free, LockType::Shared, "contender"))
+            .await
+            .unwrap()
+            .success
+    );
+
+    let report = client.contention_report();
+    assert_eq!(report.rejected_exclusive, 3);
+    assert_eq!(report.rejected_shared, 2);
+    assert_eq!(report.rejected(), 5);
+    assert_eq!((report.acquired_shared, report.acquired_exclusive), (1, 0));
+    assert_eq!(report.rejected_by_resource, vec![(hot, 4), (warm, 1)]);
+    assert_eq!(holder.contention_report().acquired_exclusive, 2);
+}
+
+#[tokio::test]
+async fn test_local_client_reset_stats_keeps_held_locks() {
+    let manager = Arc::new(GlobalLockManager::new());
+    let holder = LocalClient::with_manager(manager.clone());
+    let client = LocalClient::with_manager(manager);
+    let resource = create_test_object_key("bucket", "object-reset-stats");
+    let request = LockRequest::new(resource.clone(), LockType::Exclusive, "holder");
+    assert!(holder.acquire_lock(&request).await.unwrap().success);
+
+    let contended = LockRequest::new(resource, LockType::Exclusive, "contender")
+        .with_acquire_timeout(Duration::from_millis(10))
+        .with_suppress_contention_logs(true);
+    assert!(!client.acquire_lock(&contended).await.unwrap().success);
+    assert_eq!(client.contention_report().rejected(), 1);
+
+    holder.reset_stats();
+    client.reset_stats();
+    assert_eq!(holder.contention_report(), ContentionReport::default());
+    assert_eq!(client.contention_report(), ContentionReport::default());
+
+    // The held lock is untouched and still blocks the contender.
+    assert_eq!(holder.held_count().await, 1);
+    assert!(holder.check_status(&request.lock_id).await.unwrap().is_some());
+    assert!(!client.acquire_lock(&contended).await.unwrap().success);
+    assert_eq!(client.contention_report().rejected_exclusive, 1);
+}
+
+#[tokio::test]
+async fn test_local_client_refresh_owner_refreshes_batch() {
+    let clock = Arc::new(crate::clock::ManualClock::new());
+    let client = LocalClient::with_manager(Arc::new(GlobalLockManager::new())).with_clock(clock.clone());
+    let ttl = Duration::from_secs(30);
+    let owned: Vec<LockRequest> = (0..3)
+        .map(|i| {
+            LockRequest::new(
+                create_test_object_key("bucket", &format!("object-refresh-{i}")),
+                LockType::Exclusive,
+                "owner-a",
+            )
+            .with_ttl(ttl)
+        })
+        .collect();
+    let foreign = LockRequest::new(create_test_object_key("bucket", "object-refresh-foreign"), LockType::Exclusive, "owner-b")
+        .with_ttl(ttl);
+    for request in owned.iter().chain(std::iter::once(&foreign)) {
+        assert!(client.acquire_lock(request).await.unwrap().success);
+    }
+    assert!(client.release(&owned[2].lock_id).await.unwrap());
+
+    clock.advance(Duration::from_secs(20));
+    let uids: Vec<String> = owned
+        .iter()
+        .chain(std::iter::once(&foreign))
+        .map(|request| request.lock_id.uuid.clone())
+        .chain(std::iter::once("unknown-uid".to_string()))
+        .collect();
+    let refreshed = client.refresh_owner("owner-a", &uids).await.unwrap();
+    assert_eq!(refreshed, vec![owned[0].lock_id.uuid.clone(), owned[1].lock_id.uuid.clone()]);
+
+    // Refreshed leases outlive the original TTL; the other owner's did not move.
+    clock.advance(Duration::from_secs(15));
+    for request in &owned[..2] {
+        let status = client.check_status(&request.lock_id).await.unwrap().unwrap();
+        assert_eq!(status.status, crate::LockStatus::Acquired);
+    }
+    let foreign_status = client.check_status(&foreign.lock_id).await.unwrap().unwrap();
+    assert_eq!(foreign_status.status, crate::LockStatus::Expired);
+}
+
 #[tokio::test]
 async fn test_namespace_lock_get_resource_key() {
     let client = ClientFactory::create_local();
@@ -726,6 +1248,33 @@ async fn test_namespace_lock_distributed_with_clients_and_quorum() {
     drop(guard_b);
 }
 
+#[tokio::test]
+async fn test_namespace_lock_health_reports_configured_quorum() {
+    let offline = Arc::new(crate::client::faulty::FaultyClient::new(Arc::new(LocalClient::with_manager(Arc::new(
+        GlobalLockManager::new(),
+    )))));
+    let clients: Vec<Arc<dyn LockClient>> = vec![
+        Arc::new(LocalClient::with_manager(Arc::new(GlobalLockManager::new()))),
+        Arc::new(LocalClient::with_manager(Arc::new(GlobalLockManager::new()))),
+        offline.clone(),
+    ];
+    let lock = NamespaceLock::with_clients_and_quorum("strict-quorum".to_string(), clients, 3);
+
+    let health = lock.get_health().await;
+    assert_eq!((health.write_quorum, health.read_quorum), (3, 2));
+    assert_eq!(health.status, crate::types::HealthStatus::Healthy);
+
+    // Two of three nodes would satisfy a majority, but not the configured quorum of three.
+    offline.set_offline(true);
+    let health = lock.get_health().await;
+    assert_eq!(health.connected_nodes, 2);
+    assert_eq!(health.status, crate::types::HealthStatus::Degraded);
+
+    let local = NamespaceLock::new("local".to_string(), crate::client::ClientFactory::create_local());
+    let health = local.get_health().await;
+    assert_eq!((health.write_quorum, health.read_quorum), (1, 1));
+}
+
 #[tokio::test]
 async fn test_namespace_lock_distributed_eight_node_write_releases_all_nodes() {
     let managers = (0..8).map(|_| Arc::new(GlobalLockManager::new())).collect::<Vec<_>>();
@@ -1326,3 +1875,1869 @@ async fn crashed_owner_distributed_lock_is_reclaimed() {
         .expect("stale orphan entries must be reclaimed; no permanent deadlock (#698)");
     drop(recovered);
 }
+
+#[tokio::test]
+async fn test_batch_acquire_applies_each_request_ttl() {
+    let manager = Arc::new(GlobalLockManager::new());
+    let clock = Arc::new(crate::clock::ManualClock::new());
+    let client = LocalClient::with_manager(manager).with_clock(clock.clone());
+    let index = LockRequest::new(create_test_object_key("bucket", "object-ttl-index"), LockType::Exclusive, "owner-a")
+        .with_ttl(Duration::from_secs(5));
+    let data = LockRequest::new(create_test_object_key("bucket", "object-ttl-data"), LockType::Exclusive, "owner-a")
+        .with_ttl(Duration::from_secs(60));
+
+    let responses = client.acquire_locks_batch(&[index.clone(), data.clone()]).await.unwrap();
+    assert!(responses.iter().all(|response| response.success));
+
+    clock.advance(Duration::from_secs(6));
+    assert_eq!(client.reclaim_expired().await, 1, "only the short-lived index lock expires");
+    assert!(client.check_status(&index.lock_id).await.unwrap().is_none());
+    let status = client
+        .check_status(&data.lock_id)
+        .await
+        .unwrap()
+        .expect("data lock still tracked");
+    assert_eq!(status.status, crate::LockStatus::Acquired);
+}
+
+#[tokio::test]
+async fn test_local_client_refresh_group_reports_partial_hold() {
+    let manager = Arc::new(GlobalLockManager::new());
+    let client = LocalClient::with_manager(manager.clone());
+    let group: Vec<LockRequest> = ["data", "index", "meta"]
+        .iter()
+        .map(|name| {
+            LockRequest::new(
+                create_test_object_key("bucket", &format!("object-group-{name}")),
+                LockType::Exclusive,
+                "owner-a",
+            )
+        })
+        .collect();
+    for request in &group {
+        assert!(client.acquire_lock(request).await.unwrap().success);
+    }
+    let uids: Vec<String> = group.iter().map(|request| request.lock_id.uuid.clone()).collect();
+    assert_eq!(client.refresh_group("owner-a", &uids).await.unwrap(), RefreshOutcome::Full);
+
+    // One member is force-unlocked underneath the client.
+    let fast = manager.as_fast_lock_manager().expect("fast lock manager");
+    assert_eq!(fast.force_unlock(&group[1].resource), 1);
+
+    assert_eq!(
+        client.refresh_group("owner-a", &uids).await.unwrap(),
+        RefreshOutcome::Partial {
+            lost: vec![uids[1].clone()]
+        }
+    );
+    // The stale entry was cleaned up rather than refreshed.
+    assert!(client.check_status(&group[1].lock_id).await.unwrap().is_none());
+    assert_eq!(client.held_count().await, 2);
+    assert_eq!(client.repair().await, RepairReport::default());
+
+    // Single-lock refresh notices a force-unlock the same way.
+    assert_eq!(fast.force_unlock(&group[0].resource), 1);
+    assert!(!client.refresh(&group[0].lock_id).await.unwrap());
+    assert!(client.check_status(&group[0].lock_id).await.unwrap().is_none());
+
+    assert!(client.release(&group[2].lock_id).await.unwrap());
+    assert_eq!(client.refresh_group("owner-a", &uids).await.unwrap(), RefreshOutcome::Lost);
+}
+
+#[tokio::test]
+async fn test_local_client_repair_drops_force_unlocked_entries() {
+    let manager = Arc::new(GlobalLockManager::new());
+    let client = LocalClient::with_manager(manager.clone());
+    let unlocked = create_test_object_key("bucket", "object-repair-unlocked");
+    let kept = create_test_object_key("bucket", "object-repair-kept");
+    let unlocked_request = LockRequest::new(unlocked.clone(), LockType::Exclusive, "owner-a");
+    let kept_request = LockRequest::new(kept, LockType::Shared, "owner-a");
+    assert!(client.acquire_lock(&unlocked_request).await.unwrap().success);
+    assert!(client.acquire_lock(&kept_request).await.unwrap().success);
+    assert_eq!(client.repair().await, RepairReport::default());
+
+    // An admin force-unlock bypasses the client, so its entry goes stale.
+    let fast = manager.as_fast_lock_manager().expect("fast lock manager");
+    assert_eq!(fast.force_unlock(&unlocked), 1);
+    assert!(client.is_locked(&unlocked).await);
+
+    assert_eq!(client.repair().await, RepairReport { released: 0, stale: 1 });
+    assert!(!client.is_locked(&unlocked).await);
+    assert!(client.check_status(&unlocked_request.lock_id).await.unwrap().is_none());
+    assert!(client.check_status(&kept_request.lock_id).await.unwrap().is_some());
+    assert_eq!(client.repair().await.total(), 0);
+}
+
+#[derive(Debug, Default)]
+struct MemoryLockStore {
+    snapshot: parking_lot::Mutex<Option<crate::LockSnapshot>>,
+    log: parking_lot::Mutex<Vec<crate::LockLogRecord>>,
+}
+
+#[async_trait::async_trait]
+impl crate::LockStore for MemoryLockStore {
+    async fn persist(&self, snapshot: &crate::LockSnapshot) -> crate::Result<()> {
+        *self.snapshot.lock() = Some(snapshot.clone());
+        self.log.lock().clear();
+        Ok(())
+    }
+
+    async fn load(&self) -> crate::Result<Option<crate::LockSnapshot>> {
+        Ok(self.snapshot.lock().clone())
+    }
+
+    async fn append(&self, records: &[crate::LockLogRecord]) -> crate::Result<()> {
+        self.log.lock().extend_from_slice(records);
+        Ok(())
+    }
+
+    async fn load_log(&self) -> crate::Result<Vec<crate::LockLogRecord>> {
+        Ok(self.log.lock().clone())
+    }
+}
+
+#[tokio::test]
+async fn test_local_client_checkpoint_and_reload_round_trips() {
+    let store = Arc::new(MemoryLockStore::default());
+    let client = LocalClient::with_manager(Arc::new(GlobalLockManager::new())).with_store(store.clone(), Duration::from_secs(30));
+    let write = LockRequest::new(create_test_object_key("bucket", "object-store-write"), LockType::Exclusive, "owner-a")
+        .with_ttl(Duration::from_secs(60))
+        .with_metadata(crate::LockMetadata::new().with_client_info("node-1"));
+    let read = LockRequest::new(create_test_object_key("bucket", "object-store-read"), LockType::Shared, "owner-b");
+    assert!(client.acquire_lock(&write).await.unwrap().success);
+    assert!(client.acquire_lock(&read).await.unwrap().success);
+
+    assert!(client.checkpoint().await.unwrap());
+    let persisted = store.snapshot.lock().clone().expect("snapshot persisted");
+    assert_eq!(persisted.len(), 2);
+    drop(client);
+
+    // A fresh node rebuilds the same table from the store.
+    let restarted = LocalClient::with_manager(Arc::new(GlobalLockManager::new())).with_store(store, Duration::from_secs(30));
+    assert_eq!(restarted.load_from_store().await.unwrap(), 2);
+    let reloaded = restarted.snapshot().await;
+    let identity = |snapshot: &crate::LockSnapshot| {
+        snapshot
+            .entries
+            .iter()
+            .map(|entry| {
+                (
+                    entry.lock_id.clone(),
+                    entry.lock_type,
+                    entry.owner.clone(),
+                    entry.source.clone(),
+                    entry.ttl,
+                )
+            })
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(identity(&reloaded), identity(&persisted));
+    assert!(restarted.refresh(&write.lock_id).await.unwrap());
+    assert_eq!(
+        restarted
+            .get_lock_holder(&write.resource)
+            .await
+            .and_then(|holder| holder.source),
+        Some("node-1".to_string())
+    );
+}
+
+#[tokio::test]
+async fn test_local_client_snapshot_diff_reports_added_resource() {
+    let clock = Arc::new(crate::clock::ManualClock::new());
+    let client = LocalClient::with_manager(Arc::new(GlobalLockManager::new())).with_clock(clock.clone());
+    let existing = LockRequest::new(create_test_object_key("bucket", "object-diff-existing"), LockType::Shared, "owner-a");
+    assert!(client.acquire_lock(&existing).await.unwrap().success);
+    let before = client.snapshot().await;
+
+    clock.advance(Duration::from_secs(5));
+    let added = LockRequest::new(create_test_object_key("bucket", "object-diff-added"), LockType::Exclusive, "owner-b");
+    assert!(client.acquire_lock(&added).await.unwrap().success);
+    let after = client.snapshot().await;
+
+    // Ages are relative to each snapshot, so the older lock has aged while the new one has not.
+    let age = |snapshot: &crate::LockSnapshot, request: &LockRequest| {
+        snapshot
+            .entries
+            .iter()
+            .find(|entry| entry.lock_id == request.lock_id)
+            .map(|entry| entry.age)
+    };
+    assert_eq!(age(&after, &existing), Some(Duration::from_secs(5)));
+    assert_eq!(age(&after, &added), Some(Duration::ZERO));
+
+    let changes = crate::snapshot::diff(&before, &after);
+    assert_eq!(changes.added, vec![added.resource.clone()]);
+    assert!(changes.removed.is_empty() && changes.holder_changed.is_empty());
+    assert!(crate::snapshot::diff(&after, &after).is_empty());
+
+    assert!(client.release(&added.lock_id).await.unwrap());
+    assert_eq!(crate::snapshot::diff(&after, &client.snapshot().await).removed, vec![added.resource]);
+}
+
+#[tokio::test]
+async fn test_local_client_shared_write_group_coexists() {
+    let client = LocalClient::with_manager(Arc::new(GlobalLockManager::new()));
+    let resource = create_test_object_key("bucket", "object-shared-write");
+    let part = |owner: &str| {
+        LockRequest::new(resource.clone(), LockType::Exclusive, owner).with_acquire_timeout(Duration::from_millis(50))
+    };
+    let first = part("upload-1");
+    let second = part("upload-1");
+    assert!(client.acquire_shared_write(&first).await.unwrap().success);
+    assert!(client.acquire_shared_write(&second).await.unwrap().success);
+
+    let holders = client.get_lock_holders(&resource).await;
+    assert_eq!(holders.len(), 2);
+    assert!(
+        holders
+            .iter()
+            .all(|holder| holder.kind == crate::HoldKind::SharedWrite && holder.is_write_lock())
+    );
+    let stats = client.count_locks_prefix("bucket/object-shared-write").await;
+    assert_eq!((stats.exclusive_locks, stats.shared_locks), (2, 0));
+
+    // Other owners, readers and plain writers stay out while the group holds it.
+    assert!(!client.acquire_shared_write(&part("upload-2")).await.unwrap().success);
+    assert!(!client.acquire_lock(&part("upload-1")).await.unwrap().success);
+    let read = LockRequest::new(resource.clone(), LockType::Shared, "reader").with_acquire_timeout(Duration::from_millis(50));
+    assert!(!client.acquire_lock(&read).await.unwrap().success);
+    assert!(client.acquire_shared_write(&read).await.is_err());
+
+    // The resource is only freed once the last member lets go.
+    assert!(client.release(&first.lock_id).await.unwrap());
+    assert!(!client.acquire_lock(&read).await.unwrap().success);
+    assert!(client.release(&second.lock_id).await.unwrap());
+    assert!(client.acquire_lock(&read).await.unwrap().success);
+}
+
+#[tokio::test]
+async fn test_local_client_shared_write_rejected_against_exclusive_writer() {
+    let client = LocalClient::with_manager(Arc::new(GlobalLockManager::new()));
+    let resource = create_test_object_key("bucket", "object-shared-write-exclusive");
+    let request =
+        || LockRequest::new(resource.clone(), LockType::Exclusive, "upload-1").with_acquire_timeout(Duration::from_millis(50));
+    let exclusive = request();
+    assert!(client.acquire_lock(&exclusive).await.unwrap().success);
+    assert_eq!(
+        client.get_lock_holder(&resource).await.map(|holder| holder.kind),
+        Some(crate::HoldKind::ExclusiveWrite)
+    );
+
+    // Even the same owner cannot join an ordinary exclusive hold.
+    assert!(!client.acquire_shared_write(&request()).await.unwrap().success);
+    assert!(client.release(&exclusive.lock_id).await.unwrap());
+    assert!(client.acquire_shared_write(&request()).await.unwrap().success);
+}
+
+#[tokio::test]
+async fn test_local_client_extend_survives_reclaim_sweep() {
+    let clock = Arc::new(crate::clock::ManualClock::new());
+    let client = LocalClient::with_manager(Arc::new(GlobalLockManager::new())).with_clock(clock.clone());
+    let ttl = Duration::from_secs(10);
+    let extended =
+        LockRequest::new(create_test_object_key("bucket", "object-extend"), LockType::Exclusive, "owner-a").with_ttl(ttl);
+    let plain =
+        LockRequest::new(create_test_object_key("bucket", "object-extend-plain"), LockType::Exclusive, "owner-a").with_ttl(ttl);
+    assert!(client.acquire_lock(&extended).await.unwrap().success);
+    assert!(client.acquire_lock(&plain).await.unwrap().success);
+    assert!(client.extend(&extended.lock_id, Duration::from_secs(20)).await.unwrap());
+
+    // Past the TTL only the unextended lock is swept.
+    clock.advance(Duration::from_secs(15));
+    assert_eq!(client.reclaim_expired().await, 1);
+    assert!(client.is_locked(&extended.resource).await);
+    assert!(!client.is_locked(&plain.resource).await);
+    assert!(!client.extend(&plain.lock_id, Duration::from_secs(20)).await.unwrap());
+
+    // Refreshing drops the extension, back to the lock's own TTL.
+    assert!(client.refresh(&extended.lock_id).await.unwrap());
+    clock.advance(Duration::from_secs(11));
+    assert_eq!(client.reclaim_expired().await, 1);
+    assert!(!client.is_locked(&extended.resource).await);
+}
+
+#[tokio::test]
+async fn test_local_client_hold_duration_histogram_records_release() {
+    let clock = Arc::new(crate::clock::ManualClock::new());
+    let client = LocalClient::with_manager(Arc::new(GlobalLockManager::new())).with_clock(clock.clone());
+    assert_eq!(client.hold_duration_histogram().count, 0);
+    let bucket = |histogram: &DurationHistogram, bound: Duration| {
+        histogram
+            .buckets
+            .iter()
+            .find(|(le, _)| *le == bound)
+            .map(|(_, count)| *count)
+            .unwrap()
+    };
+
+    let request = LockRequest::new(create_test_object_key("bucket", "object-hold-time"), LockType::Exclusive, "owner-a");
+    assert!(client.acquire_lock(&request).await.unwrap().success);
+    clock.advance(Duration::from_secs(5));
+    assert!(client.release(&request.lock_id).await.unwrap());
+
+    // A five-second hold lands in the 10s bucket but not the 1s one.
+    let histogram = client.hold_duration_histogram();
+    assert_eq!(histogram.count, 1);
+    assert_eq!(histogram.sum, Duration::from_secs(5));
+    assert_eq!(bucket(&histogram, Duration::from_secs(1)), 0);
+    assert_eq!(bucket(&histogram, Duration::from_secs(10)), 1);
+
+    // Expired leases are recorded when reclaimed.
+    let leaked = LockRequest::new(create_test_object_key("bucket", "object-hold-leaked"), LockType::Shared, "owner-a")
+        .with_ttl(Duration::from_secs(30));
+    assert!(client.acquire_lock(&leaked).await.unwrap().success);
+    clock.advance(Duration::from_secs(120));
+    assert_eq!(client.reclaim_expired().await, 1);
+    let histogram = client.hold_duration_histogram();
+    assert_eq!(histogram.count, 2);
+    assert_eq!(bucket(&histogram, Duration::from_secs(60)), 1);
+    assert_eq!(bucket(&histogram, Duration::from_secs(300)), 2);
+
+    client.reset_stats();
+    assert_eq!(client.hold_duration_histogram().count, 0);
+}
+
+#[tokio::test]
+async fn test_local_client_acquire_if_not_held_by() {
+    let client = LocalClient::with_manager(Arc::new(GlobalLockManager::new()));
+    let resource = create_test_object_key("bucket", "object-conditional");
+    let request =
+        LockRequest::new(resource.clone(), LockType::Exclusive, "owner-a").with_acquire_timeout(Duration::from_millis(50));
+    let uid = request.lock_id.uuid.clone();
+
+    // Fresh acquire.
+    assert!(client.acquire_lock_if_not_held_by(&request, &uid).await.unwrap());
+    assert_eq!(client.held_count().await, 1);
+
+    // Retrying the same request is a no-op success rather than a self-conflict.
+    assert!(client.acquire_lock_if_not_held_by(&request, &uid).await.unwrap());
+    assert_eq!(client.held_count().await, 1);
+
+    // Anyone expecting a different holder still conflicts.
+    let other =
+        LockRequest::new(resource.clone(), LockType::Exclusive, "owner-b").with_acquire_timeout(Duration::from_millis(50));
+    assert!(!client.acquire_lock_if_not_held_by(&other, &other.lock_id.uuid).await.unwrap());
+    assert!(!client.acquire_lock_if_not_held_by(&other, "unknown-uid").await.unwrap());
+    // Naming the holder's uid is not enough; the owner has to match too.
+    assert!(!client.acquire_lock_if_not_held_by(&other, &uid).await.unwrap());
+    assert_eq!(client.get_lock_holder(&resource).await.map(|holder| holder.uid), Some(uid));
+
+    let read = LockRequest::new(resource, LockType::Shared, "owner-b");
+    assert!(client.acquire_lock_if_not_held_by(&read, &read.lock_id.uuid).await.is_err());
+}
+
+#[tokio::test]
+async fn test_local_client_try_lock_classifies_conflicts() {
+    let client = LocalClient::with_manager(Arc::new(GlobalLockManager::new()));
+    let resource = create_test_object_key("bucket", "object-try-lock");
+    let write = |owner: &str| LockRequest::new(resource.clone(), LockType::Exclusive, owner);
+    let read = |owner: &str| LockRequest::new(resource.clone(), LockType::Shared, owner);
+
+    let writer = write("writer");
+    assert_eq!(client.try_lock(&writer).await.unwrap(), LockOutcome::Acquired);
+    assert_eq!(
+        client.try_lock(&write("contender")).await.unwrap(),
+        LockOutcome::BlockedByWriter {
+            owner: "writer".to_string()
+        }
+    );
+    assert!(client.release(&writer.lock_id).await.unwrap());
+
+    for owner in ["reader-1", "reader-2"] {
+        assert!(client.acquire_lock(&read(owner)).await.unwrap().success);
+    }
+    assert_eq!(client.try_lock(&write("contender")).await.unwrap(), LockOutcome::BlockedByReaders(2));
+    assert!(client.try_lock(&read("reader-3")).await.is_err());
+}
+
+#[tokio::test]
+async fn test_local_client_try_rlock_classifies_conflicts() {
+    let client = LocalClient::with_manager(Arc::new(GlobalLockManager::new()));
+    let resource = create_test_object_key("bucket", "object-try-rlock");
+    let write = |owner: &str| LockRequest::new(resource.clone(), LockType::Exclusive, owner);
+    let read = |owner: &str| LockRequest::new(resource.clone(), LockType::Shared, owner);
+
+    let readers = [read("reader-1"), read("reader-2")];
+    for reader in &readers {
+        assert_eq!(client.try_rlock(reader).await.unwrap(), LockOutcome::Acquired);
+    }
+    for reader in &readers {
+        assert!(client.release(&reader.lock_id).await.unwrap());
+    }
+
+    let writer = write("writer");
+    assert!(client.acquire_lock(&writer).await.unwrap().success);
+    assert_eq!(
+        client.try_rlock(&read("reader-3")).await.unwrap(),
+        LockOutcome::BlockedByWriter {
+            owner: "writer".to_string()
+        }
+    );
+    assert!(client.try_rlock(&write("writer-2")).await.is_err());
+}
+
+#[tokio::test]
+async fn test_local_client_max_locks_rejects_at_cap() {
+    let client = LocalClient::with_manager(Arc::new(GlobalLockManager::new())).with_max_locks(2, CapacityPolicy::default());
+    let request =
+        |i: usize| LockRequest::new(create_test_object_key("bucket", &format!("object-cap-{i}")), LockType::Shared, "owner-a");
+    let first = request(0);
+    assert!(client.acquire_lock(&first).await.unwrap().success);
+    assert!(client.acquire_lock(&request(1)).await.unwrap().success);
+
+    let err = client.acquire_lock(&request(2)).await.unwrap_err();
+    assert!(matches!(err, LockError::Capacity { max_locks: 2 }), "got {err:?}");
+    assert!(err.is_retryable());
+    assert_eq!(client.held_count().await, 2);
+
+    assert!(client.release(&first.lock_id).await.unwrap());
+    assert!(client.acquire_lock(&request(2)).await.unwrap().success);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_local_client_max_locks_counts_acquisitions_in_flight() {
+    let client =
+        Arc::new(LocalClient::with_manager(Arc::new(GlobalLockManager::new())).with_max_locks(4, CapacityPolicy::Reject));
+    let held = LockRequest::new(create_test_object_key("bucket", "object-inflight-held"), LockType::Exclusive, "owner-a");
+    assert!(client.acquire_lock(&held).await.unwrap().success);
+
+    // A refused acquisition hands its slot back.
+    let refused = LockRequest::new(held.resource.clone(), LockType::Exclusive, "owner-b").with_acquire_timeout(Duration::ZERO);
+    for _ in 0..4 {
+        assert!(!client.acquire_lock(&refused).await.unwrap().success);
+    }
+
+    let tasks: Vec<_> = (0..16)
+        .map(|i| {
+            let client = client.clone();
+            tokio::spawn(async move {
+                let request = LockRequest::new(
+                    create_test_object_key("bucket", &format!("object-inflight-{i}")),
+                    LockType::Shared,
+                    "owner-a",
+                );
+                client.acquire_lock(&request).await
+            })
+        })
+        .collect();
+    let mut granted = 0;
+    for task in tasks {
+        match task.await.unwrap() {
+            Ok(response) if response.success => granted += 1,
+            Ok(response) => panic!("unexpected failure: {response:?}"),
+            Err(err) => assert!(matches!(err, LockError::Capacity { max_locks: 4 }), "got {err:?}"),
+        }
+    }
+    assert_eq!(granted, 3);
+    assert_eq!(client.held_count().await, 4);
+}
+
+#[tokio::test]
+async fn test_local_client_max_locks_evicts_least_recently_refreshed() {
+    let clock = Arc::new(crate::clock::ManualClock::new());
+    let client = LocalClient::with_manager(Arc::new(GlobalLockManager::new()))
+        .with_clock(clock.clone())
+        .with_max_locks(2, CapacityPolicy::EvictOldest);
+    let request = |i: usize| {
+        LockRequest::new(
+            create_test_object_key("bucket", &format!("object-evict-{i}")),
+            LockType::Exclusive,
+            "owner-a",
+        )
+    };
+    let (older, newer) = (request(0), request(1));
+    assert!(client.acquire_lock(&older).await.unwrap().success);
+    clock.advance(Duration::from_secs(1));
+    assert!(client.acquire_lock(&newer).await.unwrap().success);
+
+    // Refreshing the first lock makes the second the eviction candidate.
+    clock.advance(Duration::from_secs(1));
+    assert!(client.refresh(&older.lock_id).await.unwrap());
+
+    let third = request(2);
+    assert!(client.acquire_lock(&third).await.unwrap().success);
+    assert_eq!(client.held_count().await, 2);
+    assert!(client.is_locked(&older.resource).await);
+    assert!(!client.is_locked(&newer.resource).await);
+    assert!(client.is_locked(&third.resource).await);
+    // The evicted lock is released in the manager too.
+    assert!(client.acquire_lock(&request(1)).await.unwrap().success);
+}
+
+#[tokio::test]
+async fn test_local_client_stats_count_entries_per_reader() {
+    let client = LocalClient::with_manager(Arc::new(GlobalLockManager::new()));
+    let shared = create_test_object_key("bucket", "object-stats-shared");
+    for owner in ["reader-1", "reader-2", "reader-3"] {
+        assert!(
+            client
+                .acquire_lock(&LockRequest::new(shared.clone(), LockType::Shared, owner))
+                .await
+                .unwrap()
+                .success
+        );
+    }
+    let write = LockRequest::new(create_test_object_key("bucket", "object-stats-write"), LockType::Exclusive, "writer");
+    assert!(client.acquire_lock(&write).await.unwrap().success);
+
+    let stats = client.get_stats().await.unwrap();
+    assert_eq!(stats.total_locks, 4);
+    assert_eq!(stats.locked_resources, 2);
+    assert_eq!((stats.shared_locks, stats.exclusive_locks), (3, 1));
+    assert!(stats.total_locks > stats.locked_resources);
+}
+
+#[tokio::test]
+async fn test_local_client_checkpointer_runs_on_cadence() {
+    let store = Arc::new(MemoryLockStore::default());
+    let client = Arc::new(
+        LocalClient::with_manager(Arc::new(GlobalLockManager::new())).with_store(store.clone(), Duration::from_millis(10)),
+    );
+    assert!(Arc::new(LocalClient::new()).spawn_checkpointer().is_none());
+    let request = LockRequest::new(create_test_object_key("bucket", "object-checkpointer"), LockType::Exclusive, "owner-a");
+    assert!(client.acquire_lock(&request).await.unwrap().success);
+
+    let checkpointer = client.spawn_checkpointer().expect("store configured");
+    tokio::time::timeout(Duration::from_secs(2), async {
+        while store.snapshot.lock().as_ref().is_none_or(|snapshot| snapshot.is_empty()) {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+    })
+    .await
+    .expect("checkpointer should persist the table");
+
+    client.close().await.unwrap();
+    tokio::time::timeout(Duration::from_secs(2), checkpointer)
+        .await
+        .expect("checkpointer stops after close")
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_local_client_rate_limit_per_owner() {
+    let clock = Arc::new(crate::clock::ManualClock::new());
+    let client = LocalClient::with_manager(Arc::new(GlobalLockManager::new()))
+        .with_clock(clock.clone())
+        .with_rate_limit(crate::client::local::RateLimit {
+            locks_per_second: 2,
+            burst: 2,
+        });
+    let request = |owner: &str, i: usize| {
+        LockRequest::new(
+            create_test_object_key("bucket", &format!("object-rate-{owner}-{i}")),
+            LockType::Shared,
+            owner,
+        )
+    };
+
+    assert!(client.acquire_lock(&request("noisy", 0)).await.unwrap().success);
+    assert!(client.acquire_lock(&request("noisy", 1)).await.unwrap().success);
+    let err = client.acquire_lock(&request("noisy", 2)).await.unwrap_err();
+    assert!(matches!(err, LockError::RateLimited { ref owner } if owner == "noisy"), "got {err:?}");
+    assert!(err.is_retryable());
+
+    // Budgets are per owner.
+    assert!(client.acquire_lock(&request("quiet", 0)).await.unwrap().success);
+
+    // Half a second refills one token at two per second.
+    clock.advance(Duration::from_millis(500));
+    assert!(client.acquire_lock(&request("noisy", 3)).await.unwrap().success);
+    assert!(matches!(
+        client.acquire_lock(&request("noisy", 4)).await,
+        Err(LockError::RateLimited { .. })
+    ));
+}
+
+#[derive(Debug, Default)]
+struct CollectingAudit {
+    events: parking_lot::Mutex<Vec<crate::LockEvent>>,
+}
+
+impl crate::LockAudit for CollectingAudit {
+    fn on_event(&self, event: crate::LockEvent) {
+        self.events.lock().push(event);
+    }
+}
+
+#[tokio::test]
+async fn test_local_client_audits_lock_lifecycle() {
+    use crate::LockEventKind;
+
+    let audit = Arc::new(CollectingAudit::default());
+    let client = LocalClient::with_manager(Arc::new(GlobalLockManager::new())).with_audit(audit.clone());
+    let write = LockRequest::new(create_test_object_key("bucket", "object-audit-write"), LockType::Exclusive, "owner-a")
+        .with_metadata(crate::LockMetadata::new().with_client_info("node-1"));
+    let read = LockRequest::new(create_test_object_key("bucket", "object-audit-read"), LockType::Shared, "owner-b");
+
+    assert!(client.acquire_lock(&write).await.unwrap().success);
+    assert!(client.refresh(&write.lock_id).await.unwrap());
+    assert!(client.release(&write.lock_id).await.unwrap());
+    assert!(client.acquire_lock(&read).await.unwrap().success);
+    assert!(client.release(&read.lock_id).await.unwrap());
+    assert!(client.acquire_lock(&read).await.unwrap().success);
+    assert!(client.force_release(&read.lock_id).await.unwrap());
+    // Nothing is reported for a release that found nothing.
+    assert!(!client.release(&write.lock_id).await.unwrap());
+
+    let events = audit.events.lock().clone();
+    let trail: Vec<_> = events
+        .iter()
+        .map(|event| (event.kind, event.uid.as_str(), event.owner.as_str()))
+        .collect();
+    let (write_uid, read_uid) = (write.lock_id.uuid.as_str(), read.lock_id.uuid.as_str());
+    assert_eq!(
+        trail,
+        vec![
+            (LockEventKind::Lock, write_uid, "owner-a"),
+            (LockEventKind::Refresh, write_uid, "owner-a"),
+            (LockEventKind::Unlock, write_uid, "owner-a"),
+            (LockEventKind::RLock, read_uid, "owner-b"),
+            (LockEventKind::RUnlock, read_uid, "owner-b"),
+            (LockEventKind::RLock, read_uid, "owner-b"),
+            (LockEventKind::ForceUnlock, read_uid, "owner-b"),
+        ]
+    );
+    assert!(events[..3].iter().all(|event| event.source.as_deref() == Some("node-1")));
+    assert!(events.iter().all(|event| event.resources.len() == 1));
+    assert!(events.windows(2).all(|pair| pair[0].timestamp <= pair[1].timestamp));
+}
+
+#[tokio::test]
+async fn test_local_client_rejects_unlock_from_superseded_epoch() {
+    let client = LocalClient::with_manager(Arc::new(GlobalLockManager::new()));
+    let stale = LockRequest::new(create_test_object_key("bucket", "object-epoch"), LockType::Exclusive, "node-a").with_epoch(1);
+    assert!(client.acquire_lock(&stale).await.unwrap().success);
+
+    // The owner comes back under a new epoch after a leadership change.
+    let current =
+        LockRequest::new(create_test_object_key("bucket", "object-epoch-next"), LockType::Exclusive, "node-a").with_epoch(2);
+    assert!(client.acquire_lock(&current).await.unwrap().success);
+
+    let err = client.release_with_epoch(&stale.lock_id, 1).await.unwrap_err();
+    assert!(
+        matches!(
+            err,
+            LockError::StaleEpoch {
+                epoch: 1,
+                current: 2,
+                ..
+            }
+        ),
+        "got {err:?}"
+    );
+    assert!(client.is_locked(&stale.resource).await, "a stale unlock must not release the lock");
+
+    let retry =
+        LockRequest::new(create_test_object_key("bucket", "object-epoch-retry"), LockType::Exclusive, "node-a").with_epoch(1);
+    assert!(matches!(client.acquire_lock(&retry).await, Err(LockError::StaleEpoch { .. })));
+    assert!(!client.is_locked(&retry.resource).await);
+
+    // Epochs are tracked per owner, and the current epoch may still unlock.
+    let other =
+        LockRequest::new(create_test_object_key("bucket", "object-epoch-other"), LockType::Exclusive, "node-b").with_epoch(1);
+    assert!(client.acquire_lock(&other).await.unwrap().success);
+    assert!(client.release_with_epoch(&stale.lock_id, 2).await.unwrap());
+    assert!(!client.is_locked(&stale.resource).await);
+}
+
+#[tokio::test]
+async fn test_local_client_begin_drain_wakes_parked_waiter() {
+    let client = Arc::new(LocalClient::with_manager(Arc::new(GlobalLockManager::new())));
+    let resource = create_test_object_key("bucket", "object-drain");
+    let holder = LockRequest::new(resource.clone(), LockType::Exclusive, "holder");
+    assert!(client.acquire_lock(&holder).await.unwrap().success);
+
+    let waiter = tokio::spawn({
+        let client = client.clone();
+        let request =
+            LockRequest::new(resource.clone(), LockType::Exclusive, "waiter").with_acquire_timeout(Duration::from_secs(30));
+        async move { client.acquire_lock(&request).await }
+    });
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert!(!waiter.is_finished(), "waiter should be parked behind the holder");
+
+    client.begin_drain();
+    let result = tokio::time::timeout(Duration::from_secs(1), waiter)
+        .await
+        .expect("drain should wake the parked waiter")
+        .unwrap();
+    assert!(matches!(result, Err(LockError::Draining)), "got {result:?}");
+
+    // New work is refused, but the existing holder can still refresh and release.
+    let late = LockRequest::new(create_test_object_key("bucket", "object-drain-late"), LockType::Shared, "late");
+    assert!(matches!(client.acquire_lock(&late).await, Err(LockError::Draining)));
+    assert!(client.refresh(&holder.lock_id).await.unwrap());
+    assert!(client.release(&holder.lock_id).await.unwrap());
+    assert!(!client.is_locked(&resource).await);
+}
+
+#[tokio::test]
+async fn test_local_client_key_normalization_collapses_equivalent_names() {
+    let client = LocalClient::with_manager(Arc::new(GlobalLockManager::new()))
+        .with_key_normalization(crate::client::local::KeyNormalization::default());
+    let leading = LockRequest::new(create_test_object_key("bucket", "/a/b"), LockType::Exclusive, "first");
+    assert!(client.acquire_lock(&leading).await.unwrap().success);
+
+    let doubled = LockRequest::new(create_test_object_key("bucket", "a//b"), LockType::Exclusive, "second")
+        .with_acquire_timeout(Duration::ZERO);
+    assert!(!client.acquire_lock(&doubled).await.unwrap().success, "a//b and /a/b must conflict");
+    assert!(client.is_locked(&create_test_object_key("bucket", "a/b")).await);
+    assert_eq!(
+        client.try_lock(&doubled).await.unwrap(),
+        LockOutcome::BlockedByWriter {
+            owner: "first".to_string()
+        }
+    );
+
+    // A trailing `/` names a different object and does not conflict.
+    let directory = LockRequest::new(create_test_object_key("bucket", "a/b/"), LockType::Exclusive, "third");
+    assert!(client.acquire_lock(&directory).await.unwrap().success);
+
+    // The caller's original spelling still releases the lock.
+    assert!(client.release(&leading.lock_id).await.unwrap());
+    assert!(client.acquire_lock(&doubled).await.unwrap().success);
+
+    // Without normalization the two spellings are distinct resources.
+    let plain = LocalClient::with_manager(Arc::new(GlobalLockManager::new()));
+    assert!(plain.acquire_lock(&leading).await.unwrap().success);
+    assert!(plain.acquire_lock(&doubled).await.unwrap().success);
+
+    let folding = LocalClient::with_manager(Arc::new(GlobalLockManager::new()))
+        .with_key_normalization(crate::client::local::KeyNormalization { fold_case: true });
+    let upper = LockRequest::new(create_test_object_key("Bucket", "A/B"), LockType::Exclusive, "upper");
+    assert!(folding.acquire_lock(&upper).await.unwrap().success);
+    let lower = LockRequest::new(create_test_object_key("bucket", "/a//b"), LockType::Shared, "lower")
+        .with_acquire_timeout(Duration::ZERO);
+    assert!(!folding.acquire_lock(&lower).await.unwrap().success);
+}
+
+#[tokio::test]
+async fn test_local_client_release_group_rejects_subset_unlock() {
+    let client = LocalClient::with_manager(Arc::new(GlobalLockManager::new()));
+    let uid = "group-uid".to_string();
+    let resources: Vec<_> = (0..3)
+        .map(|i| create_test_object_key("bucket", &format!("object-group-{i}")))
+        .collect();
+    for resource in &resources {
+        let request = LockRequest::new(resource.clone(), LockType::Exclusive, "owner").with_lock_id(LockId {
+            resource: resource.clone(),
+            uuid: uid.clone(),
+        });
+        assert!(client.acquire_lock(&request).await.unwrap().success);
+    }
+
+    let err = client.release_group(&uid, &resources[..2]).await.unwrap_err();
+    assert!(matches!(err, LockError::InvalidRequest { .. }), "got {err:?}");
+    let swapped = [
+        resources[0].clone(),
+        resources[1].clone(),
+        create_test_object_key("bucket", "object-other"),
+    ];
+    assert!(client.release_group(&uid, &swapped).await.is_err());
+    for resource in &resources {
+        assert!(client.is_locked(resource).await, "a rejected unlock must release nothing");
+    }
+
+    assert!(client.release_group(&uid, &resources).await.unwrap());
+    for resource in &resources {
+        assert!(!client.is_locked(resource).await);
+    }
+    assert!(!client.release_group(&uid, &resources).await.unwrap());
+}
+
+#[tokio::test]
+async fn test_local_client_wait_time_report_buckets_blocked_waits() {
+    let client = Arc::new(LocalClient::with_manager(Arc::new(GlobalLockManager::new())));
+    let resource = create_test_object_key("bucket", "object-wait-time");
+    let holder = LockRequest::new(resource.clone(), LockType::Exclusive, "holder");
+    assert!(client.acquire_lock(&holder).await.unwrap().success);
+
+    let report = client.wait_time_report();
+    assert_eq!(report.histogram.count, 1);
+    assert_eq!(
+        report.histogram.buckets[1],
+        (Duration::from_millis(10), 1),
+        "an uncontended lock barely waits"
+    );
+
+    let waiter = tokio::spawn({
+        let client = client.clone();
+        let request =
+            LockRequest::new(resource.clone(), LockType::Exclusive, "waiter").with_acquire_timeout(Duration::from_secs(5));
+        async move { client.acquire_lock(&request).await }
+    });
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    assert!(client.release(&holder.lock_id).await.unwrap());
+    assert!(waiter.await.unwrap().unwrap().success);
+
+    let report = client.wait_time_report();
+    assert_eq!(report.histogram.count, 2);
+    // The blocked waiter lands in the (100ms, 1s] bucket.
+    assert_eq!(report.histogram.buckets[2], (Duration::from_millis(100), 1));
+    assert_eq!(report.histogram.buckets[3], (Duration::from_secs(1), 2));
+    assert!(report.max >= Duration::from_millis(200), "max wait {:?}", report.max);
+
+    client.reset_stats();
+    let report = client.wait_time_report();
+    assert_eq!((report.histogram.count, report.max), (0, Duration::ZERO));
+}
+
+#[tokio::test]
+async fn test_local_client_force_release_owned_requires_matching_owner() {
+    let client = LocalClient::with_manager(Arc::new(GlobalLockManager::new()));
+    let request = LockRequest::new(create_test_object_key("bucket", "object-force-owned"), LockType::Exclusive, "tenant-a");
+    assert!(client.acquire_lock(&request).await.unwrap().success);
+
+    assert!(!client.force_release_owned(&request.lock_id, "tenant-b").await.unwrap());
+    assert!(client.is_locked(&request.resource).await, "another owner's lock must survive");
+
+    assert!(client.force_release_owned(&request.lock_id, "tenant-a").await.unwrap());
+    assert!(!client.is_locked(&request.resource).await);
+    assert!(!client.force_release_owned(&request.lock_id, "tenant-a").await.unwrap());
+}
+
+#[tokio::test]
+async fn test_local_client_locks_by_source_groups_held_locks() {
+    let client = LocalClient::with_manager(Arc::new(GlobalLockManager::new()));
+    let mut held = Vec::new();
+    for (i, source) in ["heal", "heal", "put-object", "heal"].into_iter().enumerate() {
+        let request = LockRequest::new(
+            create_test_object_key("bucket", &format!("object-source-{i}")),
+            LockType::Exclusive,
+            "owner",
+        )
+        .with_metadata(crate::types::LockMetadata::new().with_client_info(source));
+        assert!(client.acquire_lock(&request).await.unwrap().success);
+        held.push(request);
+    }
+    let anonymous = LockRequest::new(create_test_object_key("bucket", "object-source-none"), LockType::Shared, "owner");
+    assert!(client.acquire_lock(&anonymous).await.unwrap().success);
+
+    let by_source = client.locks_by_source().await;
+    assert_eq!(by_source.len(), 2);
+    assert_eq!(by_source["heal"], 3);
+    assert_eq!(by_source["put-object"], 1);
+
+    let info = client.check_status(&held[2].lock_id).await.unwrap().unwrap();
+    assert_eq!(info.metadata.client_info.as_deref(), Some("put-object"));
+}
+
+#[tokio::test]
+async fn test_local_client_cas_metadata_only_replaces_expected_value() {
+    let client = LocalClient::with_manager(Arc::new(GlobalLockManager::new()));
+    let request = LockRequest::new(create_test_object_key("bucket", "object-cas"), LockType::Exclusive, "owner");
+    assert!(client.acquire_lock(&request).await.unwrap().success);
+    assert_eq!(client.lock_metadata(&request.lock_id).await, None);
+
+    assert!(
+        client
+            .cas_metadata(&request.lock_id, None, b"cursor-1".to_vec())
+            .await
+            .unwrap()
+    );
+    assert!(
+        client
+            .cas_metadata(&request.lock_id, Some(b"cursor-1"), b"cursor-2".to_vec())
+            .await
+            .unwrap()
+    );
+
+    // A stale expectation fails and leaves the value alone.
+    assert!(
+        !client
+            .cas_metadata(&request.lock_id, Some(b"cursor-1"), b"cursor-3".to_vec())
+            .await
+            .unwrap()
+    );
+    assert!(
+        !client
+            .cas_metadata(&request.lock_id, None, b"cursor-3".to_vec())
+            .await
+            .unwrap()
+    );
+    assert_eq!(client.lock_metadata(&request.lock_id).await.as_deref(), Some(&b"cursor-2"[..]));
+
+    // Only the holding uid can update it.
+    let other = LockId::new_unique(&request.resource);
+    assert!(
+        !client
+            .cas_metadata(&other, Some(b"cursor-2"), b"cursor-3".to_vec())
+            .await
+            .unwrap()
+    );
+    assert!(client.release(&request.lock_id).await.unwrap());
+    assert!(
+        !client
+            .cas_metadata(&request.lock_id, Some(b"cursor-2"), b"cursor-3".to_vec())
+            .await
+            .unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_local_client_checkpoint_log_compacts_and_restores_live_set() {
+    let store = Arc::new(MemoryLockStore::default());
+    let manager = Arc::new(GlobalLockManager::new());
+    let client = LocalClient::with_manager(manager.clone())
+        .with_store(store.clone(), Duration::from_secs(30))
+        .with_checkpoint_log(3);
+    let request = |name: &str, lock_type| LockRequest::new(create_test_object_key("bucket", name), lock_type, "owner");
+    let (a, b, c, d) = (
+        request("object-log-a", LockType::Exclusive),
+        request("object-log-b", LockType::Shared),
+        request("object-log-c", LockType::Exclusive),
+        request("object-log-d", LockType::Shared),
+    );
+
+    assert!(client.acquire_lock(&a).await.unwrap().success);
+    assert!(client.acquire_lock(&b).await.unwrap().success);
+    assert!(client.checkpoint().await.unwrap());
+    assert!(store.snapshot.lock().is_none(), "two changes fit in the log");
+    assert_eq!(store.log.lock().len(), 2);
+
+    // Two more changes would take the log past three records, so it is compacted.
+    assert!(client.acquire_lock(&c).await.unwrap().success);
+    assert!(client.release(&a.lock_id).await.unwrap());
+    assert!(client.checkpoint().await.unwrap());
+    assert_eq!(store.snapshot.lock().as_ref().map(crate::LockSnapshot::len), Some(2));
+    assert!(store.log.lock().is_empty());
+
+    assert!(client.acquire_lock(&d).await.unwrap().success);
+    let d_acquired_at = client.check_status(&d.lock_id).await.unwrap().unwrap().acquired_at;
+    assert!(client.release(&b.lock_id).await.unwrap());
+    assert!(client.checkpoint().await.unwrap());
+    assert_eq!(
+        *store.log.lock(),
+        vec![
+            crate::LockLogRecord::Acquired(crate::LockSnapshotEntry {
+                lock_id: d.lock_id.clone(),
+                lock_type: LockType::Shared,
+                owner: "owner".to_string(),
+                source: None,
+                ttl: d.ttl,
+                remaining: d.ttl,
+                age: Duration::ZERO,
+                acquired_at: Some(d_acquired_at),
+            }),
+            crate::LockLogRecord::Released(b.lock_id.clone()),
+        ]
+    );
+
+    // Entries dropped by repair are logged as released like any other removal.
+    let fast = manager.as_fast_lock_manager().expect("fast lock manager");
+    assert_eq!(fast.force_unlock(&c.resource), 1);
+    assert_eq!(client.repair().await, RepairReport { released: 0, stale: 1 });
+    assert!(client.checkpoint().await.unwrap());
+    assert_eq!(store.log.lock().last(), Some(&crate::LockLogRecord::Released(c.lock_id.clone())));
+
+    let live_ids =
+        |snapshot: &crate::LockSnapshot| snapshot.entries.iter().map(|entry| entry.lock_id.clone()).collect::<Vec<_>>();
+    let expected = live_ids(&client.snapshot().await);
+    assert_eq!(expected.len(), 1);
+    drop(client);
+
+    let restarted = LocalClient::with_manager(Arc::new(GlobalLockManager::new())).with_store(store, Duration::from_secs(30));
+    assert_eq!(restarted.load_from_store().await.unwrap(), 1);
+    assert_eq!(live_ids(&restarted.snapshot().await), expected);
+    assert!(!restarted.is_locked(&c.resource).await && restarted.is_locked(&d.resource).await);
+}
+
+#[tokio::test]
+async fn test_local_client_each_reader_releases_only_its_own_hold() {
+    let client = LocalClient::with_manager(Arc::new(GlobalLockManager::new()));
+    let resource = create_test_object_key("bucket", "object-many-readers");
+    let readers: Vec<_> = (0..200)
+        .map(|i| LockRequest::new(resource.clone(), LockType::Shared, format!("reader-{i}")))
+        .collect();
+    for reader in &readers {
+        assert!(client.acquire_lock(reader).await.unwrap().success);
+    }
+    assert_eq!(client.get_lock_holders(&resource).await.len(), readers.len());
+
+    // Release out of arrival order; each release drops exactly that reader.
+    let mut remaining: std::collections::BTreeSet<_> = readers.iter().map(|reader| reader.lock_id.uuid.clone()).collect();
+    for reader in readers.iter().step_by(2).chain(readers.iter().skip(1).step_by(2)).rev() {
+        assert!(client.release(&reader.lock_id).await.unwrap());
+        assert!(!client.release(&reader.lock_id).await.unwrap(), "a reader releases once");
+        remaining.remove(&reader.lock_id.uuid);
+        if remaining.len() % 50 == 0 {
+            let holders: std::collections::BTreeSet<_> = client
+                .get_lock_holders(&resource)
+                .await
+                .into_iter()
+                .map(|holder| holder.uid)
+                .collect();
+            assert_eq!(holders, remaining);
+        }
+    }
+    assert!(!client.is_locked(&resource).await);
+}
+
+#[tokio::test]
+async fn test_local_client_resource_state_reports_holders_and_ages() {
+    let clock = Arc::new(crate::clock::ManualClock::new());
+    let client = LocalClient::with_manager(Arc::new(GlobalLockManager::new())).with_clock(clock.clone());
+    let written = create_test_object_key("bucket", "object-state-write");
+    let read = create_test_object_key("bucket", "object-state-read");
+    assert!(client.resource_state(&written).await.is_none());
+
+    let writer = LockRequest::new(written.clone(), LockType::Exclusive, "writer");
+    assert!(client.acquire_lock(&writer).await.unwrap().success);
+    let first = LockRequest::new(read.clone(), LockType::Shared, "reader-1");
+    assert!(client.acquire_lock(&first).await.unwrap().success);
+    clock.advance(Duration::from_secs(5));
+    let second = LockRequest::new(read.clone(), LockType::Shared, "reader-2");
+    assert!(client.acquire_lock(&second).await.unwrap().success);
+    clock.advance(Duration::from_secs(2));
+    assert!(client.refresh(&first.lock_id).await.unwrap());
+    clock.advance(Duration::from_secs(1));
+
+    let state = client.resource_state(&written).await.expect("write-locked");
+    assert_eq!(state.kind, crate::HoldKind::ExclusiveWrite);
+    assert_eq!(state.holders.len(), 1);
+    assert_eq!(
+        (state.holders[0].uid.as_str(), state.holders[0].owner.as_str()),
+        (writer.lock_id.uuid.as_str(), "writer")
+    );
+    assert_eq!(state.oldest_age, Duration::from_secs(8));
+    assert_eq!(state.since_last_refresh, Duration::from_secs(8));
+
+    let state = client.resource_state(&read).await.expect("read-locked");
+    assert_eq!(state.kind, crate::HoldKind::Read);
+    let mut owners: Vec<_> = state.holders.iter().map(|holder| holder.owner.as_str()).collect();
+    owners.sort_unstable();
+    assert_eq!(owners, ["reader-1", "reader-2"]);
+    assert_eq!(state.oldest_age, Duration::from_secs(8));
+    // reader-1 refreshed a second ago; reader-2 has not refreshed in three.
+    assert_eq!(state.since_last_refresh, Duration::from_secs(3));
+
+    // The query is read-only: leases are not touched.
+    assert_eq!(client.resource_state(&read).await.unwrap().since_last_refresh, Duration::from_secs(3));
+}
+
+#[tokio::test]
+async fn test_local_client_on_expire_fires_for_reclaimed_locks() {
+    let clock = Arc::new(crate::clock::ManualClock::new());
+    let expired = Arc::new(parking_lot::Mutex::new(Vec::new()));
+    let client = LocalClient::with_manager(Arc::new(GlobalLockManager::new()))
+        .with_clock(clock.clone())
+        .with_on_expire({
+            let expired = expired.clone();
+            move |lock: &crate::client::local::ExpiredLock| expired.lock().push(lock.clone())
+        });
+    let lapsed = LockRequest::new(create_test_object_key("bucket", "object-expire-lapsed"), LockType::Exclusive, "stuck")
+        .with_ttl(Duration::from_secs(1));
+    let live = LockRequest::new(create_test_object_key("bucket", "object-expire-live"), LockType::Shared, "live")
+        .with_ttl(Duration::from_secs(60));
+    assert!(client.acquire_lock(&lapsed).await.unwrap().success);
+    assert!(client.acquire_lock(&live).await.unwrap().success);
+
+    clock.advance(Duration::from_secs(2));
+    assert!(client.release(&lapsed.lock_id).await.is_ok());
+    assert!(expired.lock().is_empty(), "releases are not expiries");
+
+    let relapsed = LockRequest::new(lapsed.resource.clone(), LockType::Exclusive, "stuck").with_ttl(Duration::from_secs(1));
+    assert!(client.acquire_lock(&relapsed).await.unwrap().success);
+    clock.advance(Duration::from_secs(3));
+    assert_eq!(client.reclaim_expired().await, 1);
+
+    let expired = expired.lock();
+    assert_eq!(expired.len(), 1);
+    assert_eq!(expired[0].resource, relapsed.resource);
+    assert_eq!(expired[0].uid, relapsed.lock_id.uuid);
+    assert_eq!(expired[0].owner, "stuck");
+    assert_eq!(expired[0].age, Duration::from_secs(3));
+}
+
+#[tokio::test]
+async fn test_local_client_refresh_count_reports_partial_group_refresh() {
+    let client = LocalClient::with_manager(Arc::new(GlobalLockManager::new()));
+    let uid = "refresh-group-uid".to_string();
+    let resources: Vec<_> = (0..3)
+        .map(|i| create_test_object_key("bucket", &format!("object-refresh-group-{i}")))
+        .collect();
+    for resource in &resources {
+        let request = LockRequest::new(resource.clone(), LockType::Exclusive, "owner").with_lock_id(LockId {
+            resource: resource.clone(),
+            uuid: uid.clone(),
+        });
+        assert!(client.acquire_lock(&request).await.unwrap().success);
+    }
+    assert_eq!(client.refresh_count(&uid, &resources).await.unwrap(), (3, 3));
+
+    let removed = LockId {
+        resource: resources[1].clone(),
+        uuid: uid.clone(),
+    };
+    assert!(client.force_release(&removed).await.unwrap());
+    assert_eq!(client.refresh_count(&uid, &resources).await.unwrap(), (2, 3));
+    assert_eq!(client.refresh_count("unknown-uid", &resources).await.unwrap(), (0, 3));
+}
+
+#[tokio::test]
+async fn test_local_client_acquire_refuses_to_replace_entry_for_same_lock_id() {
+    let client = LocalClient::with_manager(Arc::new(GlobalLockManager::new()));
+    let request = LockRequest::new(create_test_object_key("bucket", "object-same-id"), LockType::Shared, "owner")
+        .with_acquire_timeout(Duration::ZERO);
+    assert!(client.acquire_lock(&request).await.unwrap().success);
+    assert!(
+        client
+            .cas_metadata(&request.lock_id, None, b"original".to_vec())
+            .await
+            .unwrap()
+    );
+
+    let again = client.acquire_lock(&request).await.unwrap();
+    assert!(!again.success, "a second grant under the same lock id must not clobber the first");
+    assert_eq!(client.lock_metadata(&request.lock_id).await.as_deref(), Some(&b"original"[..]));
+    assert_eq!(client.held_count().await, 1);
+
+    // The refused grant was handed back, so a single release frees the object.
+    assert!(client.release(&request.lock_id).await.unwrap());
+    let writer = LockRequest::new(request.resource.clone(), LockType::Exclusive, "writer").with_acquire_timeout(Duration::ZERO);
+    assert!(client.acquire_lock(&writer).await.unwrap().success);
+}
+
+#[tokio::test]
+async fn test_local_client_force_unlock_reporting_describes_removed_locks() {
+    let clock = Arc::new(crate::clock::ManualClock::new());
+    let client = LocalClient::with_manager(Arc::new(GlobalLockManager::new())).with_clock(clock.clone());
+    let resource = create_test_object_key("bucket", "object-force-report");
+    let stuck = LockRequest::new(resource.clone(), LockType::Exclusive, "stuck-owner")
+        .with_metadata(crate::types::LockMetadata::new().with_client_info("node-3"));
+    let other = LockRequest::new(
+        create_test_object_key("bucket", "object-force-report-other"),
+        LockType::Shared,
+        "bystander",
+    );
+    assert!(client.acquire_lock(&stuck).await.unwrap().success);
+    assert!(client.acquire_lock(&other).await.unwrap().success);
+    clock.advance(Duration::from_secs(42));
+
+    let report = client.force_unlock_reporting(&resource).await;
+    assert_eq!(
+        report,
+        vec![crate::client::local::ForceUnlocked {
+            lock_id: stuck.lock_id.clone(),
+            lock_type: LockType::Exclusive,
+            owner: "stuck-owner".to_string(),
+            source: Some("node-3".to_string()),
+            age: Duration::from_secs(42),
+        }]
+    );
+    assert!(!client.is_locked(&resource).await);
+    assert!(client.is_locked(&other.resource).await, "other resources are untouched");
+    assert!(client.force_unlock_reporting(&resource).await.is_empty());
+}
+
+#[tokio::test]
+async fn test_local_client_weight_budget_rejects_owner_past_budget() {
+    let client = LocalClient::with_manager(Arc::new(GlobalLockManager::new())).with_weight_budget(10);
+    let heavy =
+        LockRequest::new(create_test_object_key("bucket", "object-weight-1"), LockType::Exclusive, "tenant").with_weight(6);
+    let medium = LockRequest::new(create_test_object_key("bucket", "object-weight-2"), LockType::Shared, "tenant").with_weight(4);
+    assert!(client.acquire_lock(&heavy).await.unwrap().success);
+    assert!(client.acquire_lock(&medium).await.unwrap().success);
+    assert_eq!(client.owner_weights().await.get("tenant"), Some(&10));
+    assert_eq!(client.get_stats().await.unwrap().held_weight, 10);
+
+    let next = LockRequest::new(create_test_object_key("bucket", "object-weight-3"), LockType::Exclusive, "tenant");
+    let err = client.acquire_lock(&next).await.unwrap_err();
+    assert!(matches!(
+        err,
+        LockError::WeightExceeded {
+            requested: 1,
+            held: 10,
+            budget: 10,
+            ..
+        }
+    ));
+    assert!(!client.is_locked(&next.resource).await);
+
+    // Other owners have their own budget, and releasing frees weight.
+    let other = LockRequest::new(next.resource.clone(), LockType::Exclusive, "other-tenant").with_weight(10);
+    assert!(client.acquire_lock(&other).await.unwrap().success);
+    assert!(client.release(&other.lock_id).await.unwrap());
+    assert!(client.release(&medium.lock_id).await.unwrap());
+    assert!(client.acquire_lock(&next).await.unwrap().success);
+    assert_eq!(client.owner_weights().await.get("tenant"), Some(&7));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_local_client_weight_budget_holds_under_concurrent_acquisitions() {
+    let client = Arc::new(LocalClient::with_manager(Arc::new(GlobalLockManager::new())).with_weight_budget(10));
+    let held =
+        LockRequest::new(create_test_object_key("bucket", "object-weight-held"), LockType::Exclusive, "tenant").with_weight(4);
+    assert!(client.acquire_lock(&held).await.unwrap().success);
+
+    // A refused acquisition gives its weight back.
+    let refused = LockRequest::new(held.resource.clone(), LockType::Exclusive, "tenant")
+        .with_weight(6)
+        .with_acquire_timeout(Duration::ZERO);
+    assert!(!client.acquire_lock(&refused).await.unwrap().success);
+
+    let tasks: Vec<_> = (0..12)
+        .map(|i| {
+            let client = client.clone();
+            tokio::spawn(async move {
+                let request = LockRequest::new(
+                    create_test_object_key("bucket", &format!("object-weight-inflight-{i}")),
+                    LockType::Shared,
+                    "tenant",
+                )
+                .with_weight(2);
+                client.acquire_lock(&request).await
+            })
+        })
+        .collect();
+    let mut granted = 0;
+    for task in tasks {
+        match task.await.unwrap() {
+            Ok(response) if response.success => granted += 1,
+            Ok(response) => panic!("unexpected failure: {response:?}"),
+            Err(err) => assert!(matches!(err, LockError::WeightExceeded { .. }), "got {err:?}"),
+        }
+    }
+    assert_eq!(granted, 3);
+    assert_eq!(client.owner_weights().await.get("tenant"), Some(&10));
+}
+
+#[tokio::test]
+async fn test_namespace_lock_dropping_one_read_guard_keeps_other_reader() {
+    let client = Arc::new(LocalClient::with_manager(Arc::new(GlobalLockManager::new())));
+    let clients: Vec<Arc<dyn LockClient>> = vec![client.clone()];
+    let lock = NamespaceLock::with_clients("read-guards".to_string(), clients);
+    let resource = create_test_object_key("bucket", "object-read-guards");
+
+    let guard_a = lock
+        .get_read_lock(resource.clone(), "reader-a", Duration::from_secs(1))
+        .await
+        .unwrap();
+    let guard_b = lock
+        .get_read_lock(resource.clone(), "reader-b", Duration::from_secs(1))
+        .await
+        .unwrap();
+    assert_eq!(client.get_lock_holders(&resource).await.len(), 2);
+
+    // Dropping a guard schedules the release of its own uid only.
+    drop(guard_a);
+    let deadline = tokio::time::Instant::now() + Duration::from_secs(2);
+    while client.get_lock_holders(&resource).await.len() > 1 && tokio::time::Instant::now() < deadline {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    let holders = client.get_lock_holders(&resource).await;
+    assert_eq!(holders.len(), 1);
+    assert_eq!(holders[0].owner, "reader-b");
+    assert!(
+        lock.get_write_lock(resource.clone(), "writer", Duration::from_millis(50))
+            .await
+            .is_err(),
+        "the remaining reader still holds the resource"
+    );
+
+    drop(guard_b);
+    let writer = lock
+        .get_write_lock(resource.clone(), "writer", Duration::from_secs(2))
+        .await
+        .expect("writer acquires once both readers are gone");
+    drop(writer);
+}
+
+#[tokio::test]
+async fn test_local_client_subscribe_events_delivers_lock_and_unlock_in_order() {
+    let client = LocalClient::with_manager(Arc::new(GlobalLockManager::new()));
+    let mut events = client.subscribe_events();
+    let request = LockRequest::new(create_test_object_key("bucket", "object-events"), LockType::Exclusive, "owner");
+    assert!(client.acquire_lock(&request).await.unwrap().success);
+    assert!(client.release(&request.lock_id).await.unwrap());
+
+    let acquired = events.recv().await.unwrap();
+    let released = events.recv().await.unwrap();
+    assert_eq!(
+        (acquired.kind, released.kind),
+        (crate::audit::LockEventKind::Lock, crate::audit::LockEventKind::Unlock)
+    );
+    assert!(acquired.uid == request.lock_id.uuid && released.uid == request.lock_id.uuid);
+    assert_eq!(released.resources, vec![request.resource.clone()]);
+    assert!(events.try_recv().is_err(), "nothing else was published");
+}
+
+#[tokio::test]
+async fn test_local_client_event_subscriber_lags_instead_of_blocking() {
+    let clock = Arc::new(crate::clock::ManualClock::new());
+    let client = LocalClient::with_manager(Arc::new(GlobalLockManager::new()))
+        .with_clock(clock.clone())
+        .with_event_capacity(2);
+    let mut events = client.subscribe_events();
+    for i in 0..3 {
+        let request = LockRequest::new(create_test_object_key("bucket", &format!("object-lag-{i}")), LockType::Shared, "owner")
+            .with_ttl(Duration::from_secs(1));
+        assert!(client.acquire_lock(&request).await.unwrap().success);
+    }
+    assert!(matches!(events.recv().await, Err(tokio::sync::broadcast::error::RecvError::Lagged(1))));
+    assert_eq!(events.recv().await.unwrap().kind, crate::audit::LockEventKind::RLock);
+
+    clock.advance(Duration::from_secs(2));
+    assert_eq!(client.reclaim_expired().await, 3);
+    let expired: Vec<_> = std::iter::from_fn(|| events.try_recv().ok())
+        .map(|event| event.kind)
+        .collect();
+    assert!(expired.iter().all(|kind| *kind == crate::audit::LockEventKind::Expire), "{expired:?}");
+}
+
+#[tokio::test]
+async fn test_local_client_reports_wall_clock_acquired_at_while_expiring_on_monotonic_clock() {
+    let clock = Arc::new(crate::clock::ManualClock::new());
+    let client = LocalClient::with_manager(Arc::new(GlobalLockManager::new())).with_clock(clock.clone());
+    let request = LockRequest::new(create_test_object_key("bucket", "object-wall-clock"), LockType::Exclusive, "owner")
+        .with_ttl(Duration::from_secs(30));
+    let before = std::time::SystemTime::now();
+    let response = client.acquire_lock(&request).await.unwrap();
+    let after = std::time::SystemTime::now();
+    assert!(response.success);
+
+    let info = client.check_status(&request.lock_id).await.unwrap().unwrap();
+    assert!(before <= info.acquired_at && info.acquired_at <= after);
+    assert_eq!(Some(info.acquired_at), response.lock_info().map(|granted| granted.acquired_at));
+    let snapshot = client.snapshot().await;
+    assert_eq!(snapshot.entries[0].acquired_at, Some(info.acquired_at));
+
+    // Only the monotonic clock moved, yet the lease has run out.
+    clock.advance(Duration::from_secs(31));
+    let expired = client.check_status(&request.lock_id).await.unwrap().unwrap();
+    assert_eq!(expired.status, crate::LockStatus::Expired);
+    assert_eq!(expired.acquired_at, info.acquired_at);
+    assert_eq!(client.reclaim_expired().await, 1);
+}
+
+#[tokio::test]
+async fn test_local_client_force_unlock_uids_purges_only_listed_uids() {
+    let client = LocalClient::with_manager(Arc::new(GlobalLockManager::new()));
+    let uid_lock = |uid: &str, object: &str, owner: &str| {
+        let resource = create_test_object_key("bucket", object);
+        LockRequest::new(resource.clone(), LockType::Exclusive, owner).with_lock_id(LockId {
+            resource,
+            uuid: uid.to_string(),
+        })
+    };
+    let crashed_a = [
+        uid_lock("uid-a", "object-purge-1", "crashed"),
+        uid_lock("uid-a", "object-purge-2", "crashed"),
+    ];
+    let crashed_b = uid_lock("uid-b", "object-purge-3", "crashed");
+    let survivor = uid_lock("uid-c", "object-purge-4", "alive");
+    let same_uid_other_owner = uid_lock("uid-b", "object-purge-5", "alive");
+    for request in crashed_a.iter().chain([&crashed_b, &survivor, &same_uid_other_owner]) {
+        assert!(client.acquire_lock(request).await.unwrap().success);
+    }
+
+    let uids = ["uid-a".to_string(), "uid-b".to_string()];
+    assert_eq!(client.force_unlock_uids(&uids, Some("crashed")).await, 3);
+    for request in crashed_a.iter().chain([&crashed_b]) {
+        assert!(!client.is_locked(&request.resource).await);
+    }
+    assert!(client.is_locked(&survivor.resource).await);
+    assert!(
+        client.is_locked(&same_uid_other_owner.resource).await,
+        "the owner filter spares other owners"
+    );
+
+    assert_eq!(client.force_unlock_uids(&uids, None).await, 1);
+    assert!(!client.is_locked(&same_uid_other_owner.resource).await);
+    assert_eq!(client.held_count().await, 1);
+}
+
+#[tokio::test]
+async fn test_namespace_lock_stats_count_offline_participants() {
+    let clients: Vec<Arc<dyn LockClient>> = vec![
+        Arc::new(LocalClient::with_manager(Arc::new(GlobalLockManager::new()))),
+        Arc::new(LocalClient::with_manager(Arc::new(GlobalLockManager::new()))),
+        Arc::new(FailingClient),
+    ];
+    let lock = NamespaceLock::with_clients("degraded".to_string(), clients);
+
+    let stats = lock.get_stats().await;
+    assert_eq!(stats.total_participants, 3);
+    assert_eq!(stats.healthy_participants, stats.total_participants - 1);
+}
+
+#[tokio::test]
+async fn test_local_client_drain_into_hands_every_lock_to_a_replacement() {
+    let manager = Arc::new(GlobalLockManager::new());
+    let source = LocalClient::with_manager(manager.clone());
+    let requests: Vec<_> = (0..4)
+        .map(|i| {
+            let lock_type = if i % 2 == 0 { LockType::Exclusive } else { LockType::Shared };
+            LockRequest::new(
+                create_test_object_key("bucket", &format!("object-handoff-{i}")),
+                lock_type,
+                format!("owner-{i}"),
+            )
+            .with_ttl(Duration::from_secs(60))
+        })
+        .collect();
+    for request in &requests {
+        assert!(source.acquire_lock(request).await.unwrap().success);
+    }
+
+    let snapshot = source.drain_into().await;
+    assert_eq!(snapshot.len(), requests.len());
+    for request in &requests {
+        let entry = snapshot
+            .entries
+            .iter()
+            .find(|entry| entry.lock_id == request.lock_id)
+            .expect("every held lock is in the snapshot");
+        assert_eq!((entry.lock_type, entry.owner.as_str()), (request.lock_type, request.owner.as_str()));
+        assert!(!entry.remaining.is_zero() && entry.remaining <= request.ttl);
+    }
+    assert_eq!(source.held_count().await, 0);
+    let late = LockRequest::new(create_test_object_key("bucket", "object-handoff-late"), LockType::Exclusive, "late");
+    assert!(matches!(source.acquire_lock(&late).await, Err(LockError::Draining)));
+
+    let target = LocalClient::with_manager(manager);
+    assert_eq!(target.restore(&snapshot).await.unwrap(), requests.len());
+    for request in &requests {
+        assert!(target.check_status(&request.lock_id).await.unwrap().is_some());
+    }
+}
+
+#[tokio::test]
+async fn test_local_client_restore_ages_leases_by_snapshot_age() {
+    let source = LocalClient::with_manager(Arc::new(GlobalLockManager::new()));
+    let request = |name: &str, ttl| {
+        LockRequest::new(create_test_object_key("bucket", name), LockType::Exclusive, "owner").with_ttl(Duration::from_secs(ttl))
+    };
+    let (long, short) = (request("object-aged-long", 60), request("object-aged-short", 10));
+    assert!(source.acquire_lock(&long).await.unwrap().success);
+    assert!(source.acquire_lock(&short).await.unwrap().success);
+
+    // The snapshot sat on disk for 20 seconds before the restart.
+    let mut snapshot = source.snapshot().await;
+    snapshot.taken_at -= Duration::from_secs(20);
+
+    let target = LocalClient::with_manager(Arc::new(GlobalLockManager::new()));
+    assert_eq!(
+        target.restore(&snapshot).await.unwrap(),
+        1,
+        "the short lease ran out while the snapshot aged"
+    );
+    assert!(target.check_status(&short.lock_id).await.unwrap().is_none());
+
+    let restored = target.snapshot().await;
+    let entry = restored
+        .entries
+        .iter()
+        .find(|entry| entry.lock_id == long.lock_id)
+        .expect("the long lease outlived the snapshot's age");
+    assert!(entry.remaining <= Duration::from_secs(40), "restored with {:?} left", entry.remaining);
+    assert_eq!(entry.ttl, long.ttl, "later refreshes still grant the full ttl");
+}
+
+#[tokio::test]
+async fn test_local_client_group_members_lists_resources_under_one_uid() {
+    let client = LocalClient::with_manager(Arc::new(GlobalLockManager::new()));
+    let resources: Vec<_> = ["object-group-b", "object-group-a", "object-group-c"]
+        .into_iter()
+        .map(|object| create_test_object_key("bucket", object))
+        .collect();
+    for resource in &resources {
+        let request = LockRequest::new(resource.clone(), LockType::Exclusive, "owner").with_lock_id(LockId {
+            resource: resource.clone(),
+            uuid: "group-uid".to_string(),
+        });
+        assert!(client.acquire_lock(&request).await.unwrap().success);
+    }
+    let single = LockRequest::new(create_test_object_key("bucket", "object-group-solo"), LockType::Shared, "owner");
+    assert!(client.acquire_lock(&single).await.unwrap().success);
+
+    let mut expected = resources.clone();
+    expected.sort();
+    assert_eq!(client.group_members("group-uid").await, expected);
+    assert_eq!(client.group_members(&single.lock_id.uuid).await, vec![single.resource.clone()]);
+    assert!(client.group_members("unknown").await.is_empty());
+
+    assert!(client.get_lock_holders(&resources[0]).await[0].group);
+    assert!(!client.get_lock_holders(&single.resource).await[0].group);
+    assert!(client.resource_state(&resources[1]).await.unwrap().holders[0].group);
+}
+
+#[tokio::test]
+async fn test_local_client_release_as_lenient_releases_mismatched_type() {
+    let client = LocalClient::with_manager(Arc::new(GlobalLockManager::new()));
+    let read = LockRequest::new(create_test_object_key("bucket", "object-release-lenient"), LockType::Shared, "reader");
+    assert!(client.acquire_lock(&read).await.unwrap().success);
+
+    assert!(client.release_as(&read.lock_id, LockType::Exclusive).await.unwrap());
+    assert!(!client.is_locked(&read.resource).await);
+    assert!(!client.release_as(&read.lock_id, LockType::Shared).await.unwrap());
+}
+
+#[tokio::test]
+async fn test_local_client_release_as_strict_rejects_mismatched_type() {
+    let client =
+        LocalClient::with_manager(Arc::new(GlobalLockManager::new())).with_release_type_policy(ReleaseTypePolicy::Strict);
+    let read = LockRequest::new(create_test_object_key("bucket", "object-release-strict"), LockType::Shared, "reader");
+    assert!(client.acquire_lock(&read).await.unwrap().success);
+
+    let err = client.release_as(&read.lock_id, LockType::Exclusive).await.unwrap_err();
+    assert!(
+        matches!(&err, LockError::InvalidRequest { reason } if reason.contains("object-release-strict")),
+        "got {err:?}"
+    );
+    assert!(client.is_locked(&read.resource).await, "a rejected release keeps the lock");
+    assert!(client.release_as(&read.lock_id, LockType::Shared).await.unwrap());
+}
+
+#[tokio::test]
+async fn test_local_client_expire_older_than_uses_separate_read_and_write_ages() {
+    let clock = Arc::new(crate::clock::ManualClock::new());
+    let client = LocalClient::with_manager(Arc::new(GlobalLockManager::new())).with_clock(clock.clone());
+    let read = LockRequest::new(create_test_object_key("bucket", "object-reap-read"), LockType::Shared, "scanner");
+    let write = LockRequest::new(create_test_object_key("bucket", "object-reap-write"), LockType::Exclusive, "writer");
+    assert!(client.acquire_lock(&read).await.unwrap().success);
+    assert!(client.acquire_lock(&write).await.unwrap().success);
+
+    clock.advance(Duration::from_secs(10));
+    assert_eq!(
+        client
+            .expire_older_than(Duration::from_secs(5), Duration::from_secs(60))
+            .await,
+        1
+    );
+    assert!(!client.is_locked(&read.resource).await);
+    assert!(client.is_locked(&write.resource).await, "the write lock is within its longer age");
+
+    clock.advance(Duration::from_secs(60));
+    assert_eq!(
+        client
+            .expire_older_than(Duration::from_secs(5), Duration::from_secs(60))
+            .await,
+        1
+    );
+    assert!(!client.is_locked(&write.resource).await);
+}
+
+#[derive(Debug)]
+struct DenyOwner(&'static str);
+
+impl crate::client::local::OwnerValidator for DenyOwner {
+    fn authorize(&self, owner: &str, _resource: &crate::ObjectKey) -> bool {
+        owner != self.0
+    }
+}
+
+#[tokio::test]
+async fn test_local_client_owner_validator_refuses_unauthorized_force_release() {
+    let client =
+        LocalClient::with_manager(Arc::new(GlobalLockManager::new())).with_owner_validator(Arc::new(DenyOwner("intruder")));
+    let request = LockRequest::new(create_test_object_key("bucket", "object-validated"), LockType::Exclusive, "intruder");
+    assert!(client.acquire_lock(&request).await.unwrap().success);
+
+    let err = client.force_release_owned(&request.lock_id, "intruder").await.unwrap_err();
+    assert!(matches!(err, LockError::PermissionDenied { .. }), "got {err:?}");
+    assert!(client.is_locked(&request.resource).await);
+
+    let other = LockRequest::new(create_test_object_key("bucket", "object-validated-2"), LockType::Exclusive, "tenant");
+    assert!(client.acquire_lock(&other).await.unwrap().success);
+    assert!(client.force_release_owned(&other.lock_id, "tenant").await.unwrap());
+}
+
+#[tokio::test]
+async fn test_local_client_refresh_health_reports_last_interval_and_margin() {
+    let clock = Arc::new(crate::clock::ManualClock::new());
+    let client = LocalClient::with_manager(Arc::new(GlobalLockManager::new())).with_clock(clock.clone());
+    let request = LockRequest::new(create_test_object_key("bucket", "object-cadence"), LockType::Exclusive, "owner")
+        .with_ttl(Duration::from_secs(30));
+    assert!(client.acquire_lock(&request).await.unwrap().success);
+    assert!(client.refresh_health().await.is_empty(), "nothing refreshed yet");
+
+    clock.advance(Duration::from_secs(10));
+    assert!(client.refresh(&request.lock_id).await.unwrap());
+    clock.advance(Duration::from_secs(25));
+    assert!(client.refresh(&request.lock_id).await.unwrap());
+
+    let health = client.refresh_health().await;
+    assert_eq!(health.len(), 1);
+    assert_eq!(health[0].uid, request.lock_id.uuid);
+    assert_eq!(health[0].owner, "owner");
+    assert_eq!(health[0].last_interval, Duration::from_secs(25));
+    assert_eq!(health[0].margin, Duration::from_secs(5));
+}
+
+#[tokio::test]
+async fn test_local_client_lock_best_effort_skips_held_resources() {
+    let client = LocalClient::with_manager(Arc::new(GlobalLockManager::new()));
+    let busy = create_test_object_key("bucket", "object-best-effort-busy");
+    let free_a = create_test_object_key("bucket", "object-best-effort-a");
+    let free_b = create_test_object_key("bucket", "object-best-effort-b");
+    let holder = LockRequest::new(busy.clone(), LockType::Exclusive, "other");
+    assert!(client.acquire_lock(&holder).await.unwrap().success);
+
+    let request = LockRequest::new(free_a.clone(), LockType::Exclusive, "owner").with_acquire_timeout(Duration::from_millis(50));
+    let outcomes = client
+        .lock_best_effort(&request, &[free_a.clone(), busy.clone(), free_b.clone()])
+        .await
+        .unwrap();
+    assert_eq!(outcomes, vec![(free_a.clone(), true), (busy.clone(), false), (free_b.clone(), true)]);
+    assert_eq!(client.get_lock_holder(&busy).await.unwrap().owner, "other");
+
+    assert!(
+        client
+            .release_group(&request.lock_id.uuid, &[free_a.clone(), free_b.clone()])
+            .await
+            .unwrap()
+    );
+    assert!(!client.is_locked(&free_a).await);
+    assert!(!client.is_locked(&free_b).await);
+}
+
+#[tokio::test]
+async fn test_local_client_refresh_keeps_only_the_refreshing_reader_alive() {
+    let clock = Arc::new(crate::clock::ManualClock::new());
+    let client = LocalClient::with_manager(Arc::new(GlobalLockManager::new())).with_clock(clock.clone());
+    let resource = create_test_object_key("bucket", "object-two-readers");
+    let reader_a = LockRequest::new(resource.clone(), LockType::Shared, "reader-a").with_ttl(Duration::from_secs(10));
+    let reader_b = LockRequest::new(resource.clone(), LockType::Shared, "reader-b").with_ttl(Duration::from_secs(10));
+    assert!(client.acquire_lock(&reader_a).await.unwrap().success);
+    assert!(client.acquire_lock(&reader_b).await.unwrap().success);
+
+    clock.advance(Duration::from_secs(8));
+    assert!(client.refresh(&reader_a.lock_id).await.unwrap());
+    clock.advance(Duration::from_secs(4));
+
+    assert_eq!(client.reclaim_expired().await, 1, "only the reader that never refreshed expires");
+    assert!(client.check_status(&reader_a.lock_id).await.unwrap().is_some());
+    assert!(client.check_status(&reader_b.lock_id).await.unwrap().is_none());
+    let holders = client.get_lock_holders(&resource).await;
+    assert_eq!(holders.len(), 1);
+    assert_eq!(holders[0].owner, "reader-a");
+}
+
+#[tokio::test]
+async fn test_local_client_goes_offline_when_draining_or_closed() {
+    let draining = LocalClient::with_manager(Arc::new(GlobalLockManager::new()));
+    assert!(draining.is_online().await);
+    draining.begin_drain();
+    assert!(!draining.is_online().await);
+
+    let closed = LocalClient::with_manager(Arc::new(GlobalLockManager::new()));
+    assert!(closed.is_online().await);
+    closed.close().await.unwrap();
+    assert!(!closed.is_online().await);
+}
+
+#[tokio::test]
+async fn test_local_client_memory_estimate_grows_with_locks() {
+    let client = LocalClient::with_manager(Arc::new(GlobalLockManager::new()));
+    assert_eq!(client.estimated_memory_bytes().await, 0);
+
+    let first = LockRequest::new(create_test_object_key("bucket", "object-mem-1"), LockType::Exclusive, "owner");
+    assert!(client.acquire_lock(&first).await.unwrap().success);
+    let one = client.estimated_memory_bytes().await;
+    assert!(one > first.lock_id.uuid.len() + "bucket".len() + "object-mem-1".len());
+
+    let second = LockRequest::new(create_test_object_key("bucket", "object-mem-2-longer-name"), LockType::Shared, "owner");
+    assert!(client.acquire_lock(&second).await.unwrap().success);
+    assert!(client.estimated_memory_bytes().await > one);
+
+    assert!(client.release(&first.lock_id).await.unwrap());
+    assert!(client.release(&second.lock_id).await.unwrap());
+    assert_eq!(client.estimated_memory_bytes().await, 0);
+}
+
+#[tokio::test]
+async fn test_local_client_restore_from_encoded_rejects_corrupted_snapshot() {
+    let source = LocalClient::with_manager(Arc::new(GlobalLockManager::new()));
+    let request = LockRequest::new(create_test_object_key("bucket", "object-encoded"), LockType::Exclusive, "owner");
+    assert!(source.acquire_lock(&request).await.unwrap().success);
+    let encoded = source.snapshot().await.encode().unwrap();
+    drop(source);
+
+    let mut corrupted = encoded.clone();
+    let last = corrupted.len() - 2;
+    corrupted[last] ^= 0x01;
+    let target = LocalClient::with_manager(Arc::new(GlobalLockManager::new()));
+    let err = target.restore_from_encoded(&corrupted, &[]).await.unwrap_err();
+    assert!(matches!(err, LockError::Deserialization { .. }), "got {err:?}");
+    let cause = std::error::Error::source(&err).and_then(|source| source.downcast_ref::<crate::snapshot::SnapshotFormatError>());
+    assert!(
+        matches!(cause, Some(crate::snapshot::SnapshotFormatError::ChecksumMismatch { .. })),
+        "got {err:?}"
+    );
+    assert!(
+        !target.is_locked(&request.resource).await,
+        "nothing is restored from a corrupted snapshot"
+    );
+    assert!(crate::LockSnapshot::decode(&encoded[..4]).is_err());
+
+    assert_eq!(target.restore_from_encoded(&encoded, &[]).await.unwrap(), 1);
+    assert!(target.check_status(&request.lock_id).await.unwrap().is_some());
+}
+
+#[tokio::test]
+async fn test_local_client_iter_locks_yields_only_matching_locks() {
+    let client = LocalClient::with_manager(Arc::new(GlobalLockManager::new()));
+    let wanted = [
+        LockRequest::new(create_test_object_key("bucket", "object-iter-1"), LockType::Exclusive, "owner-a"),
+        LockRequest::new(create_test_object_key("bucket", "object-iter-2"), LockType::Exclusive, "owner-a"),
+    ];
+    let others = [
+        LockRequest::new(create_test_object_key("bucket", "object-iter-3"), LockType::Shared, "owner-a"),
+        LockRequest::new(create_test_object_key("bucket", "object-iter-4"), LockType::Exclusive, "owner-b"),
+    ];
+    for request in wanted.iter().chain(&others) {
+        assert!(client.acquire_lock(request).await.unwrap().success);
+    }
+    let mut found: Vec<_> = client
+        .iter_locks(|info| info.lock_type == LockType::Exclusive && info.owner == "owner-a")
+        .await
+        .map(|info| info.id)
+        .collect();
+    found.sort_by(|a, b| a.resource.cmp(&b.resource));
+    assert_eq!(found, vec![wanted[0].lock_id.clone(), wanted[1].lock_id.clone()]);
+
+    assert!(client.refresh_health().await.is_empty(), "iterating does not refresh");
+    assert_eq!(client.iter_locks(|_| true).await.count(), 4);
+}
+
+#[tokio::test]
+async fn test_local_client_verify_ownership_reports_held_expired_and_taken() {
+    let clock = Arc::new(crate::clock::ManualClock::new());
+    let client = LocalClient::with_manager(Arc::new(GlobalLockManager::new())).with_clock(clock.clone());
+    let resource = create_test_object_key("bucket", "object-ownership");
+    let request = LockRequest::new(resource.clone(), LockType::Exclusive, "owner-a").with_ttl(Duration::from_secs(5));
+    let uid = request.lock_id.uuid.clone();
+    assert!(client.acquire_lock(&request).await.unwrap().success);
+
+    assert_eq!(
+        client
+            .verify_ownership("owner-a", &uid, std::slice::from_ref(&resource))
+            .await,
+        OwnershipStatus::Held
+    );
+
+    clock.advance(Duration::from_secs(6));
+    assert_eq!(
+        client
+            .verify_ownership("owner-a", &uid, std::slice::from_ref(&resource))
+            .await,
+        OwnershipStatus::Expired
+    );
+
+    assert_eq!(client.reclaim_expired().await, 1);
+    assert_eq!(
+        client
+            .verify_ownership("owner-a", &uid, std::slice::from_ref(&resource))
+            .await,
+        OwnershipStatus::Expired,
+        "a reclaimed lock nobody took over is reported expired"
+    );
+
+    // The same owner holding the resource again under a new uid is not another holder.
+    let regranted = LockRequest::new(resource.clone(), LockType::Exclusive, "owner-a");
+    assert!(client.acquire_lock(&regranted).await.unwrap().success);
+    assert_eq!(
+        client
+            .verify_ownership("owner-a", &uid, std::slice::from_ref(&resource))
+            .await,
+        OwnershipStatus::Expired
+    );
+    assert!(client.release(&regranted.lock_id).await.unwrap());
+
+    let successor = LockRequest::new(resource.clone(), LockType::Exclusive, "owner-b");
+    assert!(client.acquire_lock(&successor).await.unwrap().success);
+    assert_eq!(
+        client
+            .verify_ownership("owner-a", &uid, std::slice::from_ref(&resource))
+            .await,
+        OwnershipStatus::HeldByOther {
+            owner: "owner-b".to_string()
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_local_client_high_priority_waiter_beats_low_priority_arrival() {
+    let manager = Arc::new(GlobalLockManager::new());
+    let fast = manager.as_fast_lock_manager().expect("fast lock manager");
+    let client = Arc::new(LocalClient::with_manager(manager));
+    let resource = create_test_object_key("bucket", "object-priority");
+    let holder = LockRequest::new(resource.clone(), LockType::Exclusive, "holder");
+    assert!(client.acquire_lock(&holder).await.unwrap().success);
+
+    let order = Arc::new(parking_lot::Mutex::new(Vec::new()));
+    let acquire = |owner: &'static str, priority: crate::types::LockPriority| {
+        let client = client.clone();
+        let order = order.clone();
+        let request = LockRequest::new(resource.clone(), LockType::Exclusive, owner)
+            .with_priority(priority)
+            .with_acquire_timeout(Duration::from_secs(5));
+        async move {
+            assert!(client.acquire_lock(&request).await.unwrap().success);
+            order.lock().push(owner);
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            assert!(client.release(&request.lock_id).await.unwrap());
+        }
+    };
+
+    // The urgent request queues behind the holder.
+    let high = tokio::spawn(acquire("high", crate::types::LockPriority::High));
+    tokio::time::timeout(Duration::from_secs(2), async {
+        while fast
+            .list_locks_detailed(true)
+            .first()
+            .map_or(0, |report| report.waiters.len())
+            < 1
+        {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+    })
+    .await
+    .expect("high-priority waiter should register");
+
+    // A routine request arriving as the resource frees must leave it to the queued one.
+    assert!(client.release(&holder.lock_id).await.unwrap());
+    acquire("low", crate::types::LockPriority::Low).await;
+    high.await.unwrap();
+    assert_eq!(*order.lock(), ["high", "low"]);
+}
+
+#[tokio::test]
+async fn test_local_client_honors_lock_policy_from_manager_config() {
+    use crate::fast_lock::{LockConfig, LockPolicy};
+
+    let manager = Arc::new(GlobalLockManager::with_config(
+        LockConfig::default().with_lock_policy(LockPolicy::WritePreferring { after_denials: 0 }),
+    ));
+    let fast = manager.as_fast_lock_manager().expect("fast lock manager");
+    let client = Arc::new(LocalClient::with_manager(manager));
+    let resource = create_test_object_key("bucket", "object-write-preferring");
+    let reader = LockRequest::new(resource.clone(), LockType::Shared, "reader-1");
+    assert!(client.acquire_lock(&reader).await.unwrap().success);
+
+    let writer = {
+        let client = client.clone();
+        let request =
+            LockRequest::new(resource.clone(), LockType::Exclusive, "writer").with_acquire_timeout(Duration::from_secs(5));
+        tokio::spawn(async move {
+            assert!(client.acquire_lock(&request).await.unwrap().success);
+            assert!(client.release(&request.lock_id).await.unwrap());
+        })
+    };
+    tokio::time::timeout(Duration::from_secs(2), async {
+        while fast.queue_report().total_queued < 1 {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+    })
+    .await
+    .expect("writer should register as a waiter");
+
+    // Under the default read-preferring policy this reader would share the object.
+    let late = LockRequest::new(resource, LockType::Shared, "reader-2").with_acquire_timeout(Duration::from_millis(100));
+    assert!(!client.acquire_lock(&late).await.unwrap().success, "new readers wait behind the writer");
+
+    assert!(client.release(&reader.lock_id).await.unwrap());
+    writer.await.unwrap();
+}
@@ -0,0 +1,187 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Consistent-hash routing of resources to lockers
+//!
+//! A [`LockerRing`] places each peer at many points on a hash ring and
+//! assigns a resource to the peers found walking clockwise from the
+//! resource's own hash. Every node that builds the ring from the same peer
+//! list agrees on the assignment, and adding or removing a peer only moves
+//! the resources on the arcs that peer gains or gives up.
+//!
+//! The hash is a fixed FNV-1a with a 64-bit finalizer rather than
+//! `DefaultHasher`, so assignments stay identical across builds during a
+//! rolling upgrade.
+
+use crate::ObjectKey;
+
+/// Ring points per peer when none are specified
+pub const DEFAULT_VIRTUAL_NODES: usize = 128;
+
+/// Consistent-hash ring over a fixed list of peers
+#[derive(Debug, Clone)]
+pub struct LockerRing {
+    peers: Vec<String>,
+    /// (ring point, peer index), sorted by point
+    points: Vec<(u64, usize)>,
+}
+
+impl LockerRing {
+    /// Build a ring with [`DEFAULT_VIRTUAL_NODES`] points per peer
+    pub fn new<I, S>(peers: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self::with_virtual_nodes(peers, DEFAULT_VIRTUAL_NODES)
+    }
+
+    /// Build a ring with `virtual_nodes` points per peer (at least one)
+    pub fn with_virtual_nodes<I, S>(peers: I, virtual_nodes: usize) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let peers: Vec<String> = peers.into_iter().map(Into::into).collect();
+        let virtual_nodes = virtual_nodes.max(1);
+        let mut points = Vec::with_capacity(peers.len() * virtual_nodes);
+        for (idx, peer) in peers.iter().enumerate() {
+            for vnode in 0..virtual_nodes {
+                points.push((ring_hash(format!("{peer}#{vnode}").as_bytes()), idx));
+            }
+        }
+        // Sorting by (point, index) keeps the rare colliding points in a deterministic order.
+        points.sort_unstable();
+        Self { peers, points }
+    }
+
+    /// Peers in the order given at construction
+    pub fn peers(&self) -> &[String] {
+        &self.peers
+    }
+
+    pub fn len(&self) -> usize {
+        self.peers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.peers.is_empty()
+    }
+
+    /// Indices into [`peers`](Self::peers) of the first `count` distinct
+    /// peers responsible for `resource`, primary first
+    pub fn locker_indices(&self, resource: &ObjectKey, count: usize) -> Vec<usize> {
+        let wanted = count.min(self.peers.len());
+        let mut chosen = Vec::with_capacity(wanted);
+        if wanted == 0 {
+            return chosen;
+        }
+
+        let hash = ring_hash(resource.to_string().as_bytes());
+        let start = self.points.partition_point(|(point, _)| *point < hash);
+        for offset in 0..self.points.len() {
+            let (_, idx) = self.points[(start + offset) % self.points.len()];
+            if !chosen.contains(&idx) {
+                chosen.push(idx);
+                if chosen.len() == wanted {
+                    break;
+                }
+            }
+        }
+        chosen
+    }
+
+    /// Names of the first `count` distinct peers responsible for `resource`, primary first
+    pub fn lockers(&self, resource: &ObjectKey, count: usize) -> Vec<&str> {
+        self.locker_indices(resource, count)
+            .into_iter()
+            .map(|idx| self.peers[idx].as_str())
+            .collect()
+    }
+}
+
+/// FNV-1a followed by the murmur3 64-bit finalizer to spread nearby inputs around the ring
+fn ring_hash(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in bytes {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(0xff51_afd7_ed55_8ccd);
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(0xc4ce_b9fe_1a85_ec53);
+    hash ^ (hash >> 33)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resources() -> Vec<ObjectKey> {
+        (0..2000).map(|i| ObjectKey::new("bucket", format!("object-{i}"))).collect()
+    }
+
+    fn peers(count: usize) -> Vec<String> {
+        (0..count).map(|i| format!("node-{i}:9000")).collect()
+    }
+
+    #[test]
+    fn test_locker_ring_assignment_is_stable_and_distinct() {
+        let ring = LockerRing::new(peers(5));
+        let rebuilt = LockerRing::new(peers(5));
+        for resource in resources().iter().take(200) {
+            let lockers = ring.lockers(resource, 3);
+            assert_eq!(lockers, rebuilt.lockers(resource, 3));
+            assert_eq!(lockers.len(), 3);
+            assert!(lockers[0] != lockers[1] && lockers[1] != lockers[2] && lockers[0] != lockers[2]);
+        }
+        assert_eq!(ring.locker_indices(&ObjectKey::new("bucket", "object"), 10).len(), 5);
+        assert!(
+            LockerRing::new(Vec::<String>::new())
+                .locker_indices(&ObjectKey::new("b", "o"), 3)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_locker_ring_adding_or_removing_a_peer_moves_few_resources() {
+        let resources = resources();
+        let before = LockerRing::new(peers(5));
+        let grown = LockerRing::new(peers(6));
+        let new_peer = grown.peers()[5].as_str();
+
+        let mut moved = 0;
+        for resource in &resources {
+            let old_primary = before.lockers(resource, 1)[0];
+            let new_primary = grown.lockers(resource, 1)[0];
+            if old_primary != new_primary {
+                assert_eq!(new_primary, new_peer, "resources only move onto the added peer");
+                moved += 1;
+            }
+        }
+        // An even share for the new peer is 1/6 of the resources.
+        assert!(moved > 0 && moved < resources.len() / 3, "moved {moved} of {}", resources.len());
+
+        // Removing node-2 only reassigns resources it was primary for.
+        let remaining: Vec<String> = peers(5).into_iter().filter(|peer| peer != "node-2:9000").collect();
+        let shrunk = LockerRing::new(remaining);
+        for resource in &resources {
+            let old_primary = before.lockers(resource, 1)[0];
+            if old_primary != "node-2:9000" {
+                assert_eq!(shrunk.lockers(resource, 1)[0], old_primary);
+            }
+        }
+    }
+}
@@ -0,0 +1,263 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Point-in-time copies of a client's lock table and where they are kept
+//!
+//! A [`LockSnapshot`] records every lock a client holds with lease times
+//! relative to when it was taken, so it stays meaningful after a restart.
+//! Persistence policy lives behind [`LockStore`]; the client only decides
+//! when to checkpoint. Between full snapshots a client can log changes as
+//! [`LockLogRecord`]s instead, and [`LockSnapshot::replay`] folds that log
+//! back into a snapshot.
+//!
+//! Stores that write snapshots to disk should use [`LockSnapshot::encode`]
+//! and [`LockSnapshot::decode`], which frame the snapshot as:
+//!
+//! | bytes | content                                           |
+//! |-------|---------------------------------------------------|
+//! | 0..4  | magic `RLSN`                                      |
+//! | 4     | format version, currently 1                       |
+//! | 5..9  | CRC-32 (IEEE) of the payload, little-endian       |
+//! | 9..   | payload: the snapshot as JSON                     |
+//!
+//! so a corrupted or truncated file is rejected instead of restoring
+//! garbage locks.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Debug;
+use std::time::{Duration, SystemTime};
+
+use crate::{LockError, LockId, LockType, ObjectKey, Result};
+
+/// One held lock in a [`LockSnapshot`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockSnapshotEntry {
+    pub lock_id: LockId,
+    pub lock_type: LockType,
+    pub owner: String,
+    pub source: Option<String>,
+    /// Lease length granted on each refresh
+    pub ttl: Duration,
+    /// Lease time left when the snapshot was taken
+    pub remaining: Duration,
+    /// Time since the lock was acquired or last refreshed
+    #[serde(default)]
+    pub age: Duration,
+    /// Wall-clock time the lock was first acquired. Leases are restored
+    /// from `remaining`; this only dates grants recorded in the log
+    #[serde(default)]
+    pub acquired_at: Option<SystemTime>,
+}
+
+/// One change to a client's lock table, logged since the last full snapshot
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LockLogRecord {
+    /// A lock was granted; its lease is recorded as new
+    Acquired(LockSnapshotEntry),
+    /// A lock was released, force-released or reclaimed after expiring
+    Released(LockId),
+}
+
+/// Every lock a client held at `taken_at`, ordered by lock id
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockSnapshot {
+    pub taken_at: SystemTime,
+    pub entries: Vec<LockSnapshotEntry>,
+}
+
+/// Leading bytes of an encoded snapshot
+const SNAPSHOT_MAGIC: &[u8; 4] = b"RLSN";
+/// Version of the encoded snapshot layout
+const SNAPSHOT_FORMAT_VERSION: u8 = 1;
+const SNAPSHOT_HEADER_LEN: usize = SNAPSHOT_MAGIC.len() + 1 + 4;
+
+/// Why [`LockSnapshot::decode`] rejected its input
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum SnapshotFormatError {
+    #[error("snapshot is shorter than its {SNAPSHOT_HEADER_LEN}-byte header")]
+    Truncated,
+    #[error("snapshot does not start with the expected magic bytes")]
+    BadMagic,
+    #[error("unsupported snapshot format version {0}")]
+    UnsupportedVersion(u8),
+    #[error("snapshot checksum mismatch: header says {expected:#010x}, payload hashes to {actual:#010x}")]
+    ChecksumMismatch { expected: u32, actual: u32 },
+}
+
+impl LockSnapshot {
+    /// This snapshot framed with a checksum header, for writing to disk
+    pub fn encode(&self) -> Result<Vec<u8>> {
+        let payload = serde_json::to_vec(self)?;
+        let mut bytes = Vec::with_capacity(SNAPSHOT_HEADER_LEN + payload.len());
+        bytes.extend_from_slice(SNAPSHOT_MAGIC);
+        bytes.push(SNAPSHOT_FORMAT_VERSION);
+        bytes.extend_from_slice(&crc32(&payload).to_le_bytes());
+        bytes.extend_from_slice(&payload);
+        Ok(bytes)
+    }
+
+    /// Read back a snapshot written by [`Self::encode`].
+    ///
+    /// The checksum is verified before the payload is parsed; any damage is
+    /// reported as [`LockError::Deserialization`] carrying a [`SnapshotFormatError`].
+    pub fn decode(bytes: &[u8]) -> Result<LockSnapshot> {
+        let rejected = |err: SnapshotFormatError| LockError::deserialization("invalid lock snapshot", err);
+        if bytes.len() < SNAPSHOT_HEADER_LEN {
+            return Err(rejected(SnapshotFormatError::Truncated));
+        }
+        let (header, payload) = bytes.split_at(SNAPSHOT_HEADER_LEN);
+        if &header[..4] != SNAPSHOT_MAGIC {
+            return Err(rejected(SnapshotFormatError::BadMagic));
+        }
+        if header[4] != SNAPSHOT_FORMAT_VERSION {
+            return Err(rejected(SnapshotFormatError::UnsupportedVersion(header[4])));
+        }
+        let expected = u32::from_le_bytes([header[5], header[6], header[7], header[8]]);
+        let actual = crc32(payload);
+        if expected != actual {
+            return Err(rejected(SnapshotFormatError::ChecksumMismatch { expected, actual }));
+        }
+        Ok(serde_json::from_slice(payload)?)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// This snapshot with `log` applied in order, ordered by lock id.
+    ///
+    /// Locks acquired in the log are restated relative to `taken_at`: their
+    /// full lease runs from when they were granted. The log does not track
+    /// refreshes, so a lock refreshed since then comes back with less.
+    pub fn replay(&self, log: &[LockLogRecord]) -> LockSnapshot {
+        let mut live: HashMap<LockId, LockSnapshotEntry> = self
+            .entries
+            .iter()
+            .map(|entry| (entry.lock_id.clone(), entry.clone()))
+            .collect();
+        for record in log {
+            match record {
+                LockLogRecord::Acquired(entry) => {
+                    let mut entry = entry.clone();
+                    if let Some(granted_after) = entry.acquired_at.and_then(|at| at.duration_since(self.taken_at).ok()) {
+                        entry.remaining = entry.remaining.saturating_add(granted_after);
+                    }
+                    live.insert(entry.lock_id.clone(), entry);
+                }
+                LockLogRecord::Released(lock_id) => {
+                    live.remove(lock_id);
+                }
+            }
+        }
+        let mut entries: Vec<_> = live.into_values().collect();
+        entries.sort_by(|a, b| (&a.lock_id.resource, &a.lock_id.uuid).cmp(&(&b.lock_id.resource, &b.lock_id.uuid)));
+        LockSnapshot {
+            taken_at: self.taken_at,
+            entries,
+        }
+    }
+
+    /// Holders of each resource as (uid, owner, lock type), sorted
+    fn holders_by_resource(&self) -> BTreeMap<&ObjectKey, Vec<(&str, &str, LockType)>> {
+        let mut holders: BTreeMap<_, Vec<_>> = BTreeMap::new();
+        for entry in &self.entries {
+            holders.entry(&entry.lock_id.resource).or_default().push((
+                entry.lock_id.uuid.as_str(),
+                entry.owner.as_str(),
+                entry.lock_type,
+            ));
+        }
+        for resource_holders in holders.values_mut() {
+            resource_holders.sort_by(|a, b| (a.0, a.1).cmp(&(b.0, b.1)));
+        }
+        holders
+    }
+}
+
+/// CRC-32 (IEEE, as used by zlib) of `bytes`
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut hasher = crc_fast::Digest::new(crc_fast::CrcAlgorithm::Crc32IsoHdlc);
+    hasher.update(bytes);
+    hasher.finalize() as u32
+}
+
+/// Resources whose locking changed between two snapshots, each list sorted
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockDiff {
+    /// Locked in the new snapshot but not in the old one
+    pub added: Vec<ObjectKey>,
+    /// Locked in the old snapshot but not in the new one
+    pub removed: Vec<ObjectKey>,
+    /// Locked in both, but by a different set of holders
+    pub holder_changed: Vec<ObjectKey>,
+}
+
+impl LockDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.holder_changed.is_empty()
+    }
+}
+
+/// Compare the lock tables recorded in `old` and `new`.
+///
+/// Lease times are ignored, so a lock that was only refreshed in between is
+/// not reported.
+pub fn diff(old: &LockSnapshot, new: &LockSnapshot) -> LockDiff {
+    let old_holders = old.holders_by_resource();
+    let new_holders = new.holders_by_resource();
+    let mut result = LockDiff::default();
+    for (resource, holders) in &new_holders {
+        match old_holders.get(resource) {
+            None => result.added.push((*resource).clone()),
+            Some(previous) if previous != holders => result.holder_changed.push((*resource).clone()),
+            Some(_) => {}
+        }
+    }
+    result.removed = old_holders
+        .keys()
+        .filter(|resource| !new_holders.contains_key(*resource))
+        .map(|resource| (*resource).clone())
+        .collect();
+    result
+}
+
+/// Durable home for lock snapshots: a file, a local KV store or a remote service
+#[async_trait::async_trait]
+pub trait LockStore: Send + Sync + Debug {
+    /// Replace the stored snapshot with `snapshot`, discarding any log
+    /// appended before it
+    async fn persist(&self, snapshot: &LockSnapshot) -> Result<()>;
+
+    /// The most recently persisted snapshot, if any
+    async fn load(&self) -> Result<Option<LockSnapshot>>;
+
+    /// Append `records` to the log kept since the last persisted snapshot.
+    ///
+    /// Only clients configured with a checkpoint log call this; stores that
+    /// keep no log can leave the default, which refuses.
+    async fn append(&self, records: &[LockLogRecord]) -> Result<()> {
+        let _ = records;
+        Err(LockError::internal("lock store does not keep a change log"))
+    }
+
+    /// Records appended since the last persisted snapshot, oldest first
+    async fn load_log(&self) -> Result<Vec<LockLogRecord>> {
+        Ok(Vec::new())
+    }
+}
@@ -101,6 +101,53 @@ impl LockInfo {
     }
 }
 
+/// How a holder has a resource
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HoldKind {
+    /// Shared read lock
+    Read,
+    /// Write lock shared by cooperating uids of one owner
+    SharedWrite,
+    /// Ordinary exclusive write lock
+    ExclusiveWrite,
+}
+
+/// Current holder of a resource, as reported by read-only queries
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockHolder {
+    /// Mode the resource is held in
+    pub kind: HoldKind,
+    /// Unique ID of the held lock (the lock ID's uuid)
+    pub uid: String,
+    /// Lock owner
+    pub owner: String,
+    /// Where the request came from, taken from the request's `client_info`
+    pub source: Option<String>,
+    /// Whether the same uid also holds other resources, as part of a lock group
+    #[serde(default)]
+    pub group: bool,
+}
+
+impl LockHolder {
+    /// Whether the holder has the resource for writing, alone or as a shared-write group
+    pub fn is_write_lock(&self) -> bool {
+        self.kind != HoldKind::Read
+    }
+}
+
+/// Everything known about how a resource is locked, for "who is locking this?" views
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResourceState {
+    /// Mode the resource is held in; a write hold wins over any stale readers
+    pub kind: HoldKind,
+    /// Every holder, ordered by uid
+    pub holders: Vec<LockHolder>,
+    /// Time since the earliest of the current holds was acquired
+    pub oldest_age: Duration,
+    /// Time since the least recently refreshed holder last refreshed its lease
+    pub since_last_refresh: Duration,
+}
+
 /// Lock ID type
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct LockId {
@@ -234,6 +281,16 @@ pub struct LockRequest {
     /// (which never encoded this field) deserializable.
     #[serde(default)]
     pub refresh_interval: Option<Duration>,
+    /// Owner epoch, bumped on every leadership change of the owner; 0 opts out of epoch checks
+    #[serde(default)]
+    pub epoch: u64,
+    /// Share of the owner's weight budget this lock takes while held
+    #[serde(default = "default_lock_weight")]
+    pub weight: u32,
+}
+
+fn default_lock_weight() -> u32 {
+    1
 }
 
 impl LockRequest {
@@ -251,6 +308,8 @@ impl LockRequest {
             deadlock_detection: false,
             suppress_contention_logs: false,
             refresh_interval: None,
+            epoch: 0,
+            weight: 1,
         }
     }
 
@@ -295,6 +354,53 @@ impl LockRequest {
         self.refresh_interval = Some(interval);
         self
     }
+
+    /// Tag the request with the owner's current epoch
+    pub fn with_epoch(mut self, epoch: u64) -> Self {
+        self.epoch = epoch;
+        self
+    }
+
+    /// Count this lock as `weight` against the owner's weight budget
+    pub fn with_weight(mut self, weight: u32) -> Self {
+        self.weight = weight;
+        self
+    }
+
+    /// Use a caller-chosen lock ID (uid) instead of a generated one
+    pub fn with_lock_id(mut self, lock_id: LockId) -> Self {
+        self.lock_id = lock_id;
+        self
+    }
+
+    /// Check the request is well formed.
+    ///
+    /// Rejects empty bucket or object names, a zero TTL, and a lock ID that
+    /// names a different resource than the request.
+    pub fn validate(&self) -> crate::Result<()> {
+        if self.resource.bucket.is_empty() {
+            return Err(crate::LockError::invalid_request("empty bucket name"));
+        }
+        if self.resource.object.is_empty() {
+            return Err(crate::LockError::invalid_request("empty object name"));
+        }
+        if self.ttl.is_zero() {
+            return Err(crate::LockError::invalid_request("ttl must be non-zero"));
+        }
+        if self.lock_id.resource != self.resource {
+            return Err(crate::LockError::invalid_request(format!(
+                "lock id is for {} but the request locks {}",
+                self.lock_id.resource, self.resource
+            )));
+        }
+        Ok(())
+    }
+
+    /// Finish building: return the request if [`Self::validate`] accepts it
+    pub fn validated(self) -> crate::Result<Self> {
+        self.validate()?;
+        Ok(self)
+    }
 }
 
 /// Lock response structure
@@ -372,6 +478,10 @@ impl LockResponse {
 pub struct LockStats {
     /// Total number of locks
     pub total_locks: usize,
+    /// Number of distinct resources locked. Lower than `total_locks` when
+    /// several readers share a resource, since each holder is its own lock.
+    #[serde(default)]
+    pub locked_resources: usize,
     /// Number of exclusive locks
     pub exclusive_locks: usize,
     /// Number of shared locks
@@ -400,12 +510,24 @@ pub struct LockStats {
     pub successful_acquires: usize,
     /// Failed acquires
     pub failed_acquires: usize,
+    /// Summed weight of the counted locks
+    #[serde(default)]
+    pub held_weight: u64,
+    /// Participants of a composite lock reporting themselves online.
+    /// Only composites fill this and `total_participants`; single clients
+    /// leave both at 0.
+    #[serde(default)]
+    pub healthy_participants: usize,
+    /// Participants of a composite lock, online or not
+    #[serde(default)]
+    pub total_participants: usize,
 }
 
 impl Default for LockStats {
     fn default() -> Self {
         Self {
             total_locks: 0,
+            locked_resources: 0,
             exclusive_locks: 0,
             shared_locks: 0,
             waiting_locks: 0,
@@ -420,6 +542,9 @@ impl Default for LockStats {
             avg_wait_time: Duration::ZERO,
             successful_acquires: 0,
             failed_acquires: 0,
+            held_weight: 0,
+            healthy_participants: 0,
+            total_participants: 0,
         }
     }
 }
@@ -502,12 +627,22 @@ pub struct HealthInfo {
     pub connected_nodes: usize,
     /// Total nodes count
     pub total_nodes: usize,
+    /// Nodes that must grant an exclusive lock
+    #[serde(default = "default_quorum")]
+    pub write_quorum: usize,
+    /// Nodes that must grant a shared lock
+    #[serde(default = "default_quorum")]
+    pub read_quorum: usize,
     /// Lock statistics
     pub lock_stats: LockStats,
     /// Error message (if any)
     pub error_message: Option<String>,
 }
 
+fn default_quorum() -> usize {
+    1
+}
+
 impl Default for HealthInfo {
     fn default() -> Self {
         Self {
@@ -516,6 +651,8 @@ impl Default for HealthInfo {
             last_heartbeat: SystemTime::now(),
             connected_nodes: 1,
             total_nodes: 1,
+            write_quorum: default_quorum(),
+            read_quorum: default_quorum(),
             lock_stats: LockStats::default(),
             error_message: None,
         }
@@ -606,3 +743,44 @@ impl WaitQueueItem {
         self.wait_start_time.elapsed().unwrap_or_default()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lock_request_defaults_with_only_uid_and_resource() {
+        let resource = ObjectKey::new("bucket", "object");
+        let lock_id = LockId::new_unique(&resource);
+        let request = LockRequest::new(resource.clone(), LockType::Exclusive, "")
+            .with_lock_id(lock_id.clone())
+            .validated()
+            .expect("minimal request is valid");
+
+        assert_eq!(request.lock_id, lock_id);
+        assert_eq!(request.resource, resource);
+        assert_eq!(request.owner, "");
+        assert_eq!(request.metadata.client_info, None);
+        assert_eq!(request.acquire_timeout, Duration::from_secs(10));
+        assert_eq!(request.ttl, Duration::from_secs(30));
+        assert_eq!(request.priority, LockPriority::Normal);
+        assert!(!request.deadlock_detection);
+        assert!(!request.suppress_contention_logs);
+        assert_eq!(request.refresh_interval, None);
+    }
+
+    #[test]
+    fn test_lock_request_validation() {
+        let resource = ObjectKey::new("bucket", "object");
+        let invalid = [
+            LockRequest::new(ObjectKey::new("", "object"), LockType::Shared, "owner"),
+            LockRequest::new(ObjectKey::new("bucket", ""), LockType::Shared, "owner"),
+            LockRequest::new(resource.clone(), LockType::Shared, "owner").with_ttl(Duration::ZERO),
+            LockRequest::new(resource, LockType::Shared, "owner")
+                .with_lock_id(LockId::new_unique(&ObjectKey::new("bucket", "other"))),
+        ];
+        for request in invalid {
+            assert!(matches!(request.validated(), Err(crate::LockError::InvalidRequest { .. })));
+        }
+    }
+}
@@ -0,0 +1,222 @@
+// Copyright 2024 RustFS Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Lock/unlock invariants under random operation sequences.
+//!
+//! Random sequences of lock, rlock, unlock, runlock and refresh calls are
+//! replayed against a `LocalClient` and a simple model of who should hold
+//! what. After every step the client, its backing manager and the model must
+//! agree, and no resource may be read- and write-locked at once. Operations
+//! pick resources, owners and held-lock slots by small indices, so failing
+//! sequences shrink to short, readable cases.
+
+use proptest::prelude::*;
+use rustfs_lock::client::local::LocalClient;
+use rustfs_lock::{GlobalLockManager, LockClient, LockId, LockRequest, LockType, ObjectKey};
+use std::sync::Arc;
+use std::time::Duration;
+
+const RESOURCES: usize = 3;
+const OWNERS: usize = 3;
+
+#[derive(Debug, Clone)]
+enum Op {
+    Lock { resource: usize, owner: usize },
+    RLock { resource: usize, owner: usize },
+    Unlock { slot: usize },
+    RUnlock { slot: usize },
+    Refresh { slot: usize },
+}
+
+#[derive(Debug)]
+struct Held {
+    lock_id: LockId,
+    resource: usize,
+    owner: usize,
+}
+
+/// Expected lock table: every held write and read lock, in acquisition order
+#[derive(Debug, Default)]
+struct Model {
+    writes: Vec<Held>,
+    reads: Vec<Held>,
+    released: Vec<LockId>,
+}
+
+impl Model {
+    fn write_locked(&self, resource: usize) -> bool {
+        self.writes.iter().any(|held| held.resource == resource)
+    }
+
+    fn read_locked(&self, resource: usize) -> bool {
+        self.reads.iter().any(|held| held.resource == resource)
+    }
+
+    fn all_held(&self) -> impl Iterator<Item = &Held> {
+        self.writes.iter().chain(self.reads.iter())
+    }
+}
+
+fn resource_key(resource: usize) -> ObjectKey {
+    ObjectKey::new("invariants", format!("object-{resource}"))
+}
+
+fn owner_name(owner: usize) -> String {
+    format!("owner-{owner}")
+}
+
+fn op_strategy() -> impl Strategy<Value = Op> {
+    prop_oneof![
+        (0..RESOURCES, 0..OWNERS).prop_map(|(resource, owner)| Op::Lock { resource, owner }),
+        (0..RESOURCES, 0..OWNERS).prop_map(|(resource, owner)| Op::RLock { resource, owner }),
+        (0..8usize).prop_map(|slot| Op::Unlock { slot }),
+        (0..8usize).prop_map(|slot| Op::RUnlock { slot }),
+        (0..8usize).prop_map(|slot| Op::Refresh { slot }),
+    ]
+}
+
+async fn apply(client: &LocalClient, model: &mut Model, op: &Op) {
+    match *op {
+        Op::Lock { resource, owner } | Op::RLock { resource, owner } => {
+            let exclusive = matches!(op, Op::Lock { .. });
+            let lock_type = if exclusive { LockType::Exclusive } else { LockType::Shared };
+            let request = LockRequest::new(resource_key(resource), lock_type, owner_name(owner))
+                .with_acquire_timeout(Duration::from_millis(1))
+                .with_ttl(Duration::from_secs(300))
+                .with_suppress_contention_logs(true);
+            let expected = if exclusive {
+                !model.write_locked(resource) && !model.read_locked(resource)
+            } else {
+                !model.write_locked(resource)
+            };
+
+            let acquired = client
+                .acquire_lock(&request)
+                .await
+                .expect("local acquire never errors")
+                .success;
+            assert_eq!(acquired, expected, "{op:?} against {model:?}");
+            if acquired {
+                let held = Held {
+                    lock_id: request.lock_id,
+                    resource,
+                    owner,
+                };
+                if exclusive {
+                    model.writes.push(held);
+                } else {
+                    model.reads.push(held);
+                }
+            }
+        }
+        Op::Unlock { slot } | Op::RUnlock { slot } => {
+            let held = if matches!(op, Op::Unlock { .. }) {
+                &mut model.writes
+            } else {
+                &mut model.reads
+            };
+            if held.is_empty() {
+                // Releasing something that was never granted is a no-op.
+                let stray = LockId::new(resource_key(slot % RESOURCES));
+                assert!(!client.release(&stray).await.unwrap(), "{op:?}: stray release");
+                return;
+            }
+            let held = held.remove(slot % held.len());
+            assert!(client.release(&held.lock_id).await.unwrap(), "{op:?}: release of {held:?}");
+            model.released.push(held.lock_id);
+        }
+        Op::Refresh { slot } => {
+            let held: Vec<&Held> = model.all_held().collect();
+            if held.is_empty() {
+                if let Some(released) = model.released.last() {
+                    assert!(!client.refresh(released).await.unwrap(), "{op:?}: refresh after release");
+                }
+                return;
+            }
+            let held = held[slot % held.len()];
+            assert!(client.refresh(&held.lock_id).await.unwrap(), "{op:?}: refresh of {held:?}");
+        }
+    }
+}
+
+async fn assert_consistent(client: &LocalClient, manager: &GlobalLockManager, model: &Model) {
+    let fast = manager.as_fast_lock_manager().expect("fast lock manager");
+    for resource in 0..RESOURCES {
+        let key = resource_key(resource);
+        let holders = client.get_lock_holders(&key).await;
+        let writers = holders.iter().filter(|holder| holder.writer).count();
+        let readers = holders.len() - writers;
+
+        // (a) never read- and write-locked at once, and at most one writer.
+        assert!(writers <= 1, "{key}: {writers} writers");
+        assert!(writers == 0 || readers == 0, "{key}: {writers} writers alongside {readers} readers");
+
+        // The client and the manager agree with the model.
+        let expected_writers = model.writes.iter().filter(|held| held.resource == resource).count();
+        let expected_readers = model.reads.iter().filter(|held| held.resource == resource).count();
+        assert_eq!((writers, readers), (expected_writers, expected_readers), "{key}: client holders");
+
+        let mut held_owners: Vec<String> = fast
+            .list_locks()
+            .into_iter()
+            .filter(|info| info.key == key)
+            .map(|info| info.owner.to_string())
+            .collect();
+        held_owners.sort();
+        let mut expected_owners: Vec<String> = model
+            .all_held()
+            .filter(|held| held.resource == resource)
+            .map(|held| owner_name(held.owner))
+            .collect();
+        expected_owners.sort();
+        expected_owners.dedup();
+        assert_eq!(held_owners, expected_owners, "{key}: manager holders");
+    }
+
+    // (b) every tracked lock id still maps to a held resource, and released ones are gone.
+    for held in model.all_held() {
+        let status = client.check_status(&held.lock_id).await.unwrap();
+        assert!(status.is_some_and(|info| info.resource == resource_key(held.resource)), "{held:?} lost");
+    }
+    for released in &model.released {
+        assert!(client.check_status(released).await.unwrap().is_none(), "{released:?} still tracked");
+    }
+}
+
+fn run_sequence(ops: &[Op]) {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("build tokio runtime");
+    runtime.block_on(async {
+        let manager = Arc::new(GlobalLockManager::new());
+        let client = LocalClient::with_manager(manager.clone());
+        let mut model = Model::default();
+        for op in ops {
+            apply(&client, &mut model, op).await;
+            assert_consistent(&client, &manager, &model).await;
+        }
+    });
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    /// Any sequence of lock operations keeps the client, the manager and the
+    /// model in agreement, with no resource both read- and write-locked.
+    #[test]
+    fn lock_operations_preserve_invariants(ops in proptest::collection::vec(op_strategy(), 1..40)) {
+        run_sequence(&ops);
+    }
+}
@@ -21,9 +21,11 @@ mod runtime_sources;
 
 use proto_gen::node_service::node_service_client::NodeServiceClient;
 use rustfs_common::{cache_connection, cached_connection, evict_connection_with_log_level};
+use rustfs_tls_runtime::OutboundTlsMaterial;
 use std::{
     collections::HashMap,
     error::Error,
+    hash::{DefaultHasher, Hash, Hasher},
     sync::LazyLock,
     sync::atomic::{AtomicU8, AtomicUsize, Ordering},
     time::{Duration, Instant},
@@ -745,7 +747,33 @@ pub async fn get_channel_for_class(addr: &str, class: ChannelClass) -> Result<Ch
         debug!("Using cached bulk gRPC channel {} for: {}", idx, addr);
         return Ok(channel);
     }
-    build_channel(addr, &cache_key).await
+    build_channel(addr, &cache_key, None).await
+}
+
+/// Connection-cache key for a channel to `addr` dialed with its own TLS material. Distinct
+/// material gets a distinct key, and the NUL separator keeps it apart from the control key.
+fn tls_cache_key(addr: &str, tls: &OutboundTlsMaterial) -> String {
+    let mut hasher = DefaultHasher::new();
+    tls.root_ca_pem.hash(&mut hasher);
+    if let Some(identity) = &tls.mtls_identity {
+        identity.cert_pem.hash(&mut hasher);
+        identity.key_pem.hash(&mut hasher);
+    }
+    format!("{addr}\u{0}tls\u{0}{:016x}", hasher.finish())
+}
+
+/// Acquire a cached-or-newly-dialed control channel to `addr` that trusts `tls.root_ca_pem`
+/// and presents `tls.mtls_identity` instead of the global outbound TLS state.
+///
+/// A failed TLS handshake fails the dial and counts toward the peer's offline threshold like
+/// any other dial failure.
+pub async fn get_channel_with_tls(addr: &str, tls: &OutboundTlsMaterial) -> Result<Channel, Box<dyn Error>> {
+    let cache_key = tls_cache_key(addr, tls);
+    if let Some(channel) = cached_connection(&cache_key).await {
+        debug!("Using cached TLS gRPC channel for: {}", addr);
+        return Ok(channel);
+    }
+    build_channel(addr, &cache_key, Some(tls)).await
 }
 
 /// Creates a new gRPC channel with optimized keepalive settings for cluster resilience.
@@ -759,7 +787,7 @@ pub async fn get_channel_for_class(addr: &str, class: ChannelClass) -> Result<Ch
 /// - RPC timeout: `DEFAULT_INTERNODE_RPC_TIMEOUT_SECS` (10s)
 pub async fn create_new_channel(addr: &str) -> Result<Channel, Box<dyn Error>> {
     // The control channel is cached under the bare address, preserving the legacy key.
-    build_channel(addr, addr).await
+    build_channel(addr, addr, None).await
 }
 
 /// Dial a new gRPC channel to `dial_addr` and cache it under `cache_key`.
@@ -767,8 +795,13 @@ pub async fn create_new_channel(addr: &str) -> Result<Channel, Box<dyn Error>> {
 /// `dial_addr` is the real peer URL used for the TCP/TLS connection and hostname verification;
 /// `cache_key` is the connection-cache key. They are identical for control channels and differ
 /// for isolated bulk channels (see [`bulk_cache_key`]), letting several physically distinct
-/// channels to the same peer be cached independently.
-async fn build_channel(dial_addr: &str, cache_key: &str) -> Result<Channel, Box<dyn Error>> {
+/// channels to the same peer be cached independently. `tls_override` replaces the global
+/// outbound root CA and mTLS identity for this channel only.
+async fn build_channel(
+    dial_addr: &str,
+    cache_key: &str,
+    tls_override: Option<&OutboundTlsMaterial>,
+) -> Result<Channel, Box<dyn Error>> {
     debug!("Creating new gRPC channel to: {} (cache key: {})", dial_addr, cache_key);
     let dial_started_at = Instant::now();
     let connect_timeout = internode_connect_timeout();
@@ -803,7 +836,11 @@ async fn build_channel(dial_addr: &str, cache_key: &str) -> Result<Channel, Box<
         connector = connector.initial_connection_window_size(conn_window);
     }
 
-    let outbound_tls = runtime_sources::outbound_tls_state().await;
+    let mut outbound_tls = runtime_sources::outbound_tls_state().await;
+    if let Some(tls) = tls_override {
+        outbound_tls.root_ca_pem = (!tls.root_ca_pem.is_empty()).then(|| tls.root_ca_pem.clone());
+        outbound_tls.mtls_identity = tls.mtls_identity.clone();
+    }
     let generation = outbound_tls.generation.0;
     let mut stale_generation = false;
     {
@@ -931,6 +968,19 @@ pub async fn evict_failed_connection_with_log_level(addr: &str, log_level: Conne
     }
 }
 
+/// Evict the channel cached by [`get_channel_with_tls`] after a failure, together with the
+/// peer's other channels, counting the failure toward the peer's offline threshold.
+pub async fn evict_failed_tls_connection_with_log_level(
+    addr: &str,
+    tls: &OutboundTlsMaterial,
+    log_level: ConnectionEvictionLogLevel,
+) {
+    let cache_key = tls_cache_key(addr, tls);
+    evict_connection_with_log_level(&cache_key, ConnectionEvictionLogLevel::Debug).await;
+    TLS_GENERATION_CACHE.lock().await.remove(&cache_key);
+    evict_failed_connection_with_log_level(addr, log_level).await;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;